@@ -0,0 +1,136 @@
+//! 의미 분석 벤치마크: 큰 생성 프로그램을 한 번 파싱한 뒤 분석 시간을 측정
+//!
+//! cargo bench --bench analyze [함수 개수]
+
+use rustc_tape4::{Lexer, Parser, SemanticAnalyzer};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 10;
+
+/// 분석 중 할당량을 재기 위한 할당자 (누적 할당 바이트, 최대 사용량)
+struct Counting;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static IN_USE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        let in_use = IN_USE.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK.fetch_max(in_use, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        IN_USE.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn mib(bytes: usize) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0)
+}
+
+/// 지역 변수, 중첩 블록, 반복문, 배열, 포인터, 호출이 섞인 함수 n 개 (함수당 약 25 줄)
+fn generate(functions: usize) -> String {
+    let mut src = String::from("int total;\nint table[16];\n");
+    for i in 0..functions {
+        let callee = if i == 0 {
+            String::from("n")
+        } else {
+            format!("f{}(n - 1)", i - 1)
+        };
+        write!(
+            src,
+            r#"
+int f{i}(int n) {{
+    int sum = 0;
+    int *p = &sum;
+    char c = 'a';
+    for (int k = 0; k < n; k++) {{
+        int x = k * 2;
+        if (x % 3 == 0) {{
+            sum += x;
+        }} else {{
+            int y = x + c;
+            sum = sum - y;
+        }}
+    }}
+    while (sum > 100) {{
+        sum = sum / 2;
+        {{
+            int sum = 1;
+            *p += sum;
+        }}
+    }}
+    table[n % 16] = sum;
+    total = total + table[n % 16];
+    return {callee} + *p;
+}}
+"#
+        )
+        .unwrap();
+    }
+    src.push_str("int main() {\n    return f0(3);\n}\n");
+    src
+}
+
+fn main() {
+    let functions = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(2000);
+    let source = generate(functions);
+    let lines = source.lines().count();
+
+    let tokens = Lexer::new(&source).collect_spanned_tokens();
+    let program = Parser::new(tokens)
+        .parse_program()
+        .expect("생성한 프로그램 파싱 실패");
+
+    // 할당량은 첫 분석 한 번으로 측정 (프로그램 자체가 차지하는 메모리는 제외)
+    let (allocated, base) = (
+        ALLOCATED.load(Ordering::Relaxed),
+        IN_USE.load(Ordering::Relaxed),
+    );
+    PEAK.store(base, Ordering::Relaxed);
+    let model = SemanticAnalyzer::new(&program)
+        .analyze()
+        .expect("생성한 프로그램 분석 실패");
+    let allocated = ALLOCATED.load(Ordering::Relaxed) - allocated;
+    let peak = PEAK.load(Ordering::Relaxed) - base;
+    drop(model);
+
+    let mut best = Duration::MAX;
+    let mut total = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        let model = SemanticAnalyzer::new(&program)
+            .analyze()
+            .expect("생성한 프로그램 분석 실패");
+        let elapsed = start.elapsed();
+        std::hint::black_box(model);
+        best = best.min(elapsed);
+        total += elapsed;
+    }
+
+    let mean = total / ITERATIONS;
+    println!(
+        "analyze: {lines} lines, {functions} functions: best {:.2} ms, mean {:.2} ms ({:.0} lines/s)",
+        best.as_secs_f64() * 1e3,
+        mean.as_secs_f64() * 1e3,
+        lines as f64 / mean.as_secs_f64()
+    );
+    println!(
+        "memory: {:.1} MiB allocated, {:.1} MiB peak",
+        mib(allocated),
+        mib(peak)
+    );
+}
@@ -2,7 +2,10 @@
 pub enum Expr {
     Ident(String), // variable or function name
     IntLiteral(i64),
+    FloatLiteral(f64),  // 2.0f
+    DoubleLiteral(f64), // 1.5
     CharLiteral(char),
+    StringLiteral(String), // "hello"
 
     // 단항연산자
     UnaryPrefixOp {
@@ -33,9 +36,21 @@ pub enum Expr {
         left: Box<Expr>,
         op: AssignOp,
         right: Box<Expr>,
+        parenthesized: bool, // (x = y) 처럼 괄호로 감쌌는지 (조건 속 대입 경고를 끄는 관례)
     }, // 할당 x = y, x += 1.
 }
 
+/// 의미 분석 결과에서 식 노드를 가리키는 식별자
+/// 분석 대상 Program 을 빌리고 있는 동안 노드 주소는 변하지 않으므로 주소를 그대로 사용
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExprId(usize);
+
+impl Expr {
+    pub fn id(&self) -> ExprId {
+        ExprId(self as *const Expr as usize)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum PrefixOp {
     Address, // &
@@ -84,3 +99,20 @@ pub enum AssignOp {
     BitOrAssign,  // |=
     BitXorAssign, // ^=
 }
+
+impl AssignOp {
+    /// 복합 대입이 계산하는 이항 연산 (x += y -> x + y), 단순 대입은 None
+    pub fn binary_op(&self) -> Option<BinaryOp> {
+        match self {
+            AssignOp::Assign => None,
+            AssignOp::PlusAssign => Some(BinaryOp::Add),
+            AssignOp::MinusAssign => Some(BinaryOp::Sub),
+            AssignOp::MulAssign => Some(BinaryOp::Mul),
+            AssignOp::DivAssign => Some(BinaryOp::Div),
+            AssignOp::RemAssign => Some(BinaryOp::Rem),
+            AssignOp::BitAndAssign => Some(BinaryOp::BitAnd),
+            AssignOp::BitOrAssign => Some(BinaryOp::BitOr),
+            AssignOp::BitXorAssign => Some(BinaryOp::BitXor),
+        }
+    }
+}
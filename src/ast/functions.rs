@@ -1,24 +1,46 @@
-use crate::ast::stmt::Block;
-use crate::ast::ty::TypeSpecifier;
+use crate::ast::program::Pos;
+use crate::ast::stmt::{Block, DeclId};
+use crate::ast::ty::{StorageClass, TypeSpecifier};
 
 #[derive(Debug, Clone)]
 pub struct Parameter {
-    pub name: String,
+    pub name: Option<String>, // 프로토타입에서는 이름 생략 가능
     pub ty: TypeSpecifier,
+    pub pos: Pos, // 이름 (생략되었다면 타입) 의 위치
 }
 
 #[derive(Debug, Clone)]
 pub struct Function {
+    pub storage: Option<StorageClass>,
     pub name: String,
     pub return_ty: TypeSpecifier,
     pub params: Vec<Parameter>,
-    pub body: Block,
+    pub is_variadic: bool,   // 마지막 매개변수 뒤 '...'
+    pub body: Option<Block>, // None 이면 프로토타입(선언)
+    pub pos: Pos,            // 이름의 위치
+}
+
+impl Function {
+    pub fn is_definition(&self) -> bool {
+        self.body.is_some()
+    }
+
+    pub fn id(&self) -> DeclId {
+        DeclId::of(self)
+    }
+}
+
+impl Parameter {
+    pub fn id(&self) -> DeclId {
+        DeclId::of(self)
+    }
 }
 
-// 함수 정의: 반환 타입, 함수 이름, 매개변수 목록, 함수 본문
+// 함수 선언/정의: 반환 타입, 함수 이름, 매개변수 목록, (정의라면) 함수 본문
+// function_declaration  ::= type_specifier identifier "(" parameter_list? ")" ";"
 // function_definition   ::= type_specifier identifier "(" parameter_list? ")" block
 
 // 매개변수 목록: 첫 매개변수 + 쉼표로 구분된 추가 매개변수 0개 이상
-// parameter_list        ::= parameter ( "," parameter )*
-// 단일 매개변수: 타입 + 이름
-// parameter             ::= type_specifier identifier
+// parameter_list        ::= parameter ( "," parameter )* ( "," "..." )?
+// 단일 매개변수: 타입 + 이름 (프로토타입에서는 이름 생략 가능)
+// parameter             ::= base_type declarator | base_type abstract_declarator
@@ -1,11 +1,13 @@
 pub mod expr;
 pub mod functions;
 pub mod program;
+pub mod source_map;
 pub mod stmt;
 pub mod ty;
 
-pub use expr::Expr;
+pub use expr::{Expr, ExprId};
 pub use functions::Function;
-pub use program::Program;
-pub use stmt::Stmt;
-pub use ty::TypeSpecifier;
+pub use program::{ExternalDecl, Pos, Program};
+pub use source_map::SourceMap;
+pub use stmt::{DeclId, Stmt, StmtId};
+pub use ty::{Qualifiers, StorageClass, TypeSpecifier};
@@ -1,8 +1,21 @@
-use crate::ast::Function;
+use crate::ast::{Function, Stmt};
+use std::fmt;
 
 #[derive(Debug, Clone)]
 pub struct Program {
     pub functions: Vec<Function>,
+    pub globals: Vec<Stmt>,       // 전역 변수 선언 (Stmt::Declaration)
+    pub order: Vec<ExternalDecl>, // 원본에 나타난 순서
+    // 파서가 노드를 완성한 순서(후위 순회)대로 기록한 식/문장의 위치 (SourceMap 이 노드와 짝지음)
+    pub expr_positions: Vec<Pos>,
+    pub stmt_positions: Vec<Pos>,
+}
+
+/// 외부 선언 하나: functions / globals 안의 위치
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalDecl {
+    Function(usize),
+    Global(usize),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -17,7 +30,7 @@ impl Span {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Pos {
     pub line: usize,
     pub column: usize,
@@ -29,5 +42,11 @@ impl Pos {
     }
 }
 
-// 프로그램 전체: 0개 이상의 함수 정의
-// program               ::= function_definition*
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+// 프로그램 전체: 0개 이상의 함수 정의 / 전역 변수 선언
+// program               ::= ( function | declaration_statement )*
@@ -0,0 +1,177 @@
+use crate::ast::program::Pos;
+use crate::ast::stmt::Block;
+use crate::ast::{DeclId, Expr, ExprId, ExternalDecl, Program, Stmt, StmtId};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+/// 노드 식별자 -> 원본 위치
+///
+/// ExprId/DeclId/StmtId 는 노드 주소이므로 지도를 만든 Program 을 빌리는 동안에만 씀
+#[derive(Debug, Default)]
+pub struct SourceMap<'p> {
+    exprs: HashMap<ExprId, Pos>,
+    stmts: HashMap<StmtId, Pos>,
+    decls: HashMap<DeclId, Pos>,
+    program: PhantomData<&'p Program>,
+}
+
+impl<'p> SourceMap<'p> {
+    /// 파서가 기록한 위치를 같은 순서(후위 순회)로 노드와 짝지음
+    /// (위치 기록이 없는 손으로 만든 Program 이면 그 종류의 위치는 비워 둠)
+    pub fn new(program: &'p Program) -> Self {
+        let mut walk = Walk::default();
+        for decl in &program.order {
+            match *decl {
+                ExternalDecl::Global(index) => walk.stmt(&program.globals[index]),
+                ExternalDecl::Function(index) => {
+                    let func = &program.functions[index];
+                    walk.decls.insert(func.id(), func.pos);
+                    for param in &func.params {
+                        walk.decls.insert(param.id(), param.pos);
+                    }
+                    if let Some(body) = &func.body {
+                        walk.block(body);
+                    }
+                }
+            }
+        }
+        let exprs = walk.exprs.len() == program.expr_positions.len();
+        let stmts = walk.stmts.len() == program.stmt_positions.len();
+        // 파서가 기록한 Program 에서 수가 어긋나면 파서와 Walk 의 순회 순서가 달라진 것
+        debug_assert!(
+            exprs || program.expr_positions.is_empty(),
+            "expression positions out of sync: {} nodes, {} positions",
+            walk.exprs.len(),
+            program.expr_positions.len()
+        );
+        debug_assert!(
+            stmts || program.stmt_positions.is_empty(),
+            "statement positions out of sync: {} nodes, {} positions",
+            walk.stmts.len(),
+            program.stmt_positions.len()
+        );
+        SourceMap {
+            exprs: zip_if(exprs, walk.exprs, &program.expr_positions),
+            stmts: zip_if(stmts, walk.stmts, &program.stmt_positions),
+            decls: walk.decls,
+            program: PhantomData,
+        }
+    }
+
+    pub fn expr(&self, id: ExprId) -> Option<Pos> {
+        self.exprs.get(&id).copied()
+    }
+
+    pub fn stmt(&self, id: StmtId) -> Option<Pos> {
+        self.stmts.get(&id).copied()
+    }
+
+    pub fn decl(&self, id: DeclId) -> Option<Pos> {
+        self.decls.get(&id).copied()
+    }
+}
+
+/// 노드 수와 기록 수가 같을 때만 짝지음 (다르면 어느 노드가 바뀌었는지 알 수 없음)
+fn zip_if<K: std::hash::Hash + Eq>(
+    matched: bool,
+    ids: Vec<K>,
+    positions: &[Pos],
+) -> HashMap<K, Pos> {
+    if !matched {
+        return HashMap::new();
+    }
+    ids.into_iter().zip(positions.iter().copied()).collect()
+}
+
+/// 파서가 노드를 완성하는 순서대로 노드를 모음
+#[derive(Default)]
+struct Walk {
+    exprs: Vec<ExprId>,
+    stmts: Vec<StmtId>,
+    decls: HashMap<DeclId, Pos>,
+}
+
+impl Walk {
+    fn block(&mut self, block: &Block) {
+        for stmt in &block.statements {
+            self.stmt(stmt);
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Block(block) => self.block(block),
+            Stmt::If {
+                cond,
+                then_branch,
+                else_branch,
+            } => {
+                self.expr(cond);
+                self.stmt(then_branch);
+                if let Some(else_branch) = else_branch {
+                    self.stmt(else_branch);
+                }
+            }
+            Stmt::While { cond, body } => {
+                self.expr(cond);
+                self.stmt(body);
+            }
+            Stmt::For {
+                init,
+                cond,
+                step,
+                body,
+            } => {
+                if let Some(init) = init {
+                    self.stmt(init);
+                }
+                cond.iter().chain(step).for_each(|expr| self.expr(expr));
+                self.stmt(body);
+            }
+            Stmt::Return(expr) | Stmt::ExprStmt(expr) => expr.iter().for_each(|e| self.expr(e)),
+            Stmt::Declaration { declarators, .. } => {
+                for decl in declarators {
+                    self.decls.insert(decl.id(), decl.pos);
+                    if let Some(init) = &decl.init {
+                        self.expr(init);
+                    }
+                }
+            }
+            Stmt::Break | Stmt::Continue => {}
+        }
+        self.stmts.push(stmt.id());
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::UnaryPrefixOp { rhs: inner, .. } | Expr::UnaryPostfixOp { lhs: inner, .. } => {
+                self.expr(inner)
+            }
+            Expr::BinaryOp { lhs, rhs, .. }
+            | Expr::Assignment {
+                left: lhs,
+                right: rhs,
+                ..
+            }
+            | Expr::ArrayIndex {
+                array: lhs,
+                index: rhs,
+            } => {
+                self.expr(lhs);
+                self.expr(rhs);
+            }
+            Expr::Call { func, args } => {
+                self.expr(func);
+                args.iter().for_each(|arg| self.expr(arg));
+            }
+            Expr::InitializerList(items) => items.iter().for_each(|item| self.expr(item)),
+            Expr::Ident(_)
+            | Expr::IntLiteral(_)
+            | Expr::FloatLiteral(_)
+            | Expr::DoubleLiteral(_)
+            | Expr::CharLiteral(_)
+            | Expr::StringLiteral(_) => {}
+        }
+        self.exprs.push(expr.id());
+    }
+}
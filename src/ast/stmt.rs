@@ -1,5 +1,6 @@
-use crate::ast::TypeSpecifier;
 use crate::ast::expr::Expr;
+use crate::ast::program::Pos;
+use crate::ast::{StorageClass, TypeSpecifier};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Block {
@@ -28,6 +29,7 @@ pub enum Stmt {
     Break,
     Continue,
     Declaration {
+        storage: Option<StorageClass>,
         ty: TypeSpecifier,
         declarators: Vec<Declarator>,
     },
@@ -37,8 +39,36 @@ pub enum Stmt {
 #[derive(Debug, Clone, PartialEq)]
 pub struct Declarator {
     pub name: String,
-    pub array_size: Option<i64>,
+    pub ty: TypeSpecifier, // 기본 타입에 선언자를 적용한 전체 타입
     pub init: Option<Expr>,
+    pub pos: Pos, // 이름의 위치
+}
+
+/// 의미 분석 결과에서 선언(변수 선언자, 매개변수, 함수)을 가리키는 식별자
+/// ExprId 와 같이 분석 대상 Program 을 빌리는 동안의 노드 주소를 사용
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DeclId(usize);
+
+impl DeclId {
+    pub fn of<T>(node: &T) -> DeclId {
+        DeclId(node as *const T as usize)
+    }
+}
+
+/// 문장을 가리키는 식별자 (ExprId 와 같이 노드 주소)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StmtId(usize);
+
+impl Stmt {
+    pub fn id(&self) -> StmtId {
+        StmtId(self as *const Stmt as usize)
+    }
+}
+
+impl Declarator {
+    pub fn id(&self) -> DeclId {
+        DeclId::of(self)
+    }
 }
 
 // 문장: 구문들
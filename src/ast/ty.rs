@@ -1,10 +1,148 @@
+/// 타입 한정자 (const, volatile)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Qualifiers {
+    pub is_const: bool,
+    pub is_volatile: bool,
+}
+
+impl Qualifiers {
+    pub fn is_empty(&self) -> bool {
+        !self.is_const && !self.is_volatile
+    }
+
+    /// other 의 한정자를 모두 포함하는지
+    pub fn contains(&self, other: Qualifiers) -> bool {
+        (self.is_const || !other.is_const) && (self.is_volatile || !other.is_volatile)
+    }
+}
+
+/// 저장 클래스 지정자
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StorageClass {
+    Static, // 내부 링크 (전역) / 정적 저장 기간 (지역)
+    Extern, // 외부 링크
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TypeSpecifier {
     Int,
     Char,
     Void,
+    Float,
+    Double,
     Pointer(Box<TypeSpecifier>),
+    Array(Box<TypeSpecifier>, Option<usize>), // 크기 생략 시 None (int a[])
+    Function {
+        ret: Box<TypeSpecifier>,
+        params: Vec<TypeSpecifier>,
+        is_variadic: bool,
+    }, // 함수 포인터의 대상 타입 ex) int (*cb)(int, char)
+    Qualified(Qualifiers, Box<TypeSpecifier>), // const int, char * const
+}
+
+impl TypeSpecifier {
+    /// 한정자를 덧붙인 타입 (빈 한정자면 그대로)
+    pub fn qualified(self, quals: Qualifiers) -> TypeSpecifier {
+        if quals.is_empty() {
+            return self;
+        }
+        match self {
+            TypeSpecifier::Qualified(inner_quals, inner) => TypeSpecifier::Qualified(
+                Qualifiers {
+                    is_const: inner_quals.is_const || quals.is_const,
+                    is_volatile: inner_quals.is_volatile || quals.is_volatile,
+                },
+                inner,
+            ),
+            other => TypeSpecifier::Qualified(quals, Box::new(other)),
+        }
+    }
+
+    /// 최상위 한정자
+    pub fn qualifiers(&self) -> Qualifiers {
+        match self {
+            TypeSpecifier::Qualified(quals, _) => *quals,
+            _ => Qualifiers::default(),
+        }
+    }
+
+    /// 최상위 한정자를 벗긴 타입
+    pub fn unqualified(&self) -> &TypeSpecifier {
+        match self {
+            TypeSpecifier::Qualified(_, inner) => inner,
+            other => other,
+        }
+    }
+
+    /// 객체 크기 (바이트): void, 함수, 크기 없는 배열은 None
+    /// int/float 4, char 1, double/포인터 8 (64비트 대상)
+    pub fn size(&self) -> Option<usize> {
+        match self {
+            TypeSpecifier::Char => Some(1),
+            TypeSpecifier::Int | TypeSpecifier::Float => Some(4),
+            TypeSpecifier::Double | TypeSpecifier::Pointer(_) => Some(8),
+            TypeSpecifier::Array(elem, Some(len)) => elem.size().map(|size| size * len),
+            TypeSpecifier::Qualified(_, inner) => inner.size(),
+            TypeSpecifier::Void
+            | TypeSpecifier::Array(_, None)
+            | TypeSpecifier::Function { .. } => None,
+        }
+    }
+
+    /// 호환되는 두 타입의 합성 타입 (호환되지 않으면 None)
+    /// 크기 없는 배열은 같은 원소의 크기 있는 배열과 호환되고 합성 타입은 크기를 가짐
+    /// ex) extern int a[]; int a[10];  -> int[10]
+    pub fn composite(&self, other: &TypeSpecifier) -> Option<TypeSpecifier> {
+        use TypeSpecifier::*;
+        match (self, other) {
+            (Pointer(a), Pointer(b)) => Some(Pointer(Box::new(a.composite(b)?))),
+            (Array(a, n), Array(b, m)) => {
+                if n.is_some() && m.is_some() && n != m {
+                    return None;
+                }
+                Some(Array(Box::new(a.composite(b)?), n.or(*m)))
+            }
+            (
+                Function {
+                    ret: a,
+                    params: a_params,
+                    is_variadic: a_variadic,
+                },
+                Function {
+                    ret: b,
+                    params: b_params,
+                    is_variadic: b_variadic,
+                },
+            ) if a_params.len() == b_params.len() && a_variadic == b_variadic => Some(Function {
+                ret: Box::new(a.composite(b)?),
+                params: composite_all(a_params, b_params)?,
+                is_variadic: *a_variadic,
+            }),
+            (Qualified(a_quals, a), Qualified(b_quals, b)) if a_quals == b_quals => {
+                Some(Qualified(*a_quals, Box::new(a.composite(b)?)))
+            }
+            _ if self == other => Some(self.clone()),
+            _ => None,
+        }
+    }
+
+    /// 정렬 (바이트): 배열은 원소의 정렬, 그 외에는 크기와 같음
+    pub fn align(&self) -> Option<usize> {
+        match self {
+            TypeSpecifier::Array(elem, _) => elem.align(),
+            TypeSpecifier::Qualified(_, inner) => inner.align(),
+            other => other.size(),
+        }
+    }
+}
+
+/// 같은 위치의 타입끼리 합성 (하나라도 호환되지 않으면 None)
+pub fn composite_all(a: &[TypeSpecifier], b: &[TypeSpecifier]) -> Option<Vec<TypeSpecifier>> {
+    a.iter().zip(b).map(|(a, b)| a.composite(b)).collect()
 }
 
-// 타입 지정자: 기본 타입(int|char|void) + 0개 이상 포인터
-// type_specifier        ::= ( "int" | "char" | "void" ) "*"*
+// 타입 지정자: 한정자 + 기본 타입(int|char|void|float|double) + 0개 이상 포인터
+// type_specifier        ::= base_type ( "*" type_qualifier* )*
+// base_type             ::= type_qualifier* ( "int" | "char" | "void" | "float" | "double" ) type_qualifier*
+// type_qualifier        ::= "const" | "volatile"
+// 배열/포인터-배열 등은 선언자(declarator)가 기본 타입 위에 쌓아 올림
@@ -0,0 +1,81 @@
+use crate::ast::Expr;
+use crate::ast::expr::{BinaryOp, PrefixOp};
+use crate::consteval::error::ConstError;
+
+pub type ConstResult<T> = Result<T, ConstError>;
+
+/// 문자 상수의 값: char 는 부호 있는 8 비트이므로 0x80 이상은 음수 ('ÿ' (0xff) 는 -1)
+pub fn char_value(c: char) -> i32 {
+    c as u8 as i8 as i32
+}
+
+/// 정수 상수식 평가 (int 는 32 비트 2 의 보수)
+/// - 정수·문자 리터럴, 단항 - / !, 산술·비트·비교·논리 연산
+/// - 오버플로는 감싼 값을 담아 Overflow 로, 0 으로 나누기는 DivisionByZero 로 보고
+/// - &&, || 는 C 처럼 단락 평가하므로 평가되지 않는 쪽의 0 나누기는 문제 되지 않음
+///
+/// 배열 크기, 전역 초기화식 (이후 case 레이블, #if) 에서 사용
+pub fn eval_int(expr: &Expr) -> ConstResult<i32> {
+    match expr {
+        Expr::IntLiteral(n) => i32::try_from(*n).map_err(|_| ConstError::Overflow {
+            op: None,
+            wrapped: *n as i32,
+        }),
+        Expr::CharLiteral(c) => Ok(char_value(*c)),
+        Expr::FloatLiteral(_) | Expr::DoubleLiteral(_) | Expr::StringLiteral(_) => {
+            Err(ConstError::NotInteger)
+        }
+        Expr::UnaryPrefixOp { op, rhs } => {
+            let value = eval_int(rhs)?;
+            match op {
+                PrefixOp::Neg => checked(None, value.overflowing_neg()),
+                PrefixOp::Not => Ok((value == 0) as i32),
+                _ => Err(ConstError::NotConstant),
+            }
+        }
+        Expr::BinaryOp { lhs, op, rhs } => {
+            let l = eval_int(lhs)?;
+            // 단락 평가
+            match op {
+                BinaryOp::And if l == 0 => return Ok(0),
+                BinaryOp::Or if l != 0 => return Ok(1),
+                _ => {}
+            }
+            let r = eval_int(rhs)?;
+            eval_binary(op, l, r)
+        }
+        _ => Err(ConstError::NotConstant),
+    }
+}
+
+fn eval_binary(op: &BinaryOp, l: i32, r: i32) -> ConstResult<i32> {
+    let op_name = Some(op.clone());
+    match op {
+        BinaryOp::Add => checked(op_name, l.overflowing_add(r)),
+        BinaryOp::Sub => checked(op_name, l.overflowing_sub(r)),
+        BinaryOp::Mul => checked(op_name, l.overflowing_mul(r)),
+        // INT_MIN / -1 은 오버플로, 나머지는 0 방향 절삭 (C99)
+        BinaryOp::Div | BinaryOp::Rem if r == 0 => Err(ConstError::DivisionByZero),
+        BinaryOp::Div => checked(op_name, l.overflowing_div(r)),
+        BinaryOp::Rem => checked(op_name, l.overflowing_rem(r)),
+        BinaryOp::BitAnd => Ok(l & r),
+        BinaryOp::BitOr => Ok(l | r),
+        BinaryOp::BitXor => Ok(l ^ r),
+        BinaryOp::Eq => Ok((l == r) as i32),
+        BinaryOp::Ne => Ok((l != r) as i32),
+        BinaryOp::Lt => Ok((l < r) as i32),
+        BinaryOp::Le => Ok((l <= r) as i32),
+        BinaryOp::Gt => Ok((l > r) as i32),
+        BinaryOp::Ge => Ok((l >= r) as i32),
+        // 단락되지 않은 경우 결과는 오른쪽 값으로 정해짐
+        BinaryOp::And | BinaryOp::Or => Ok((r != 0) as i32),
+    }
+}
+
+fn checked(op: Option<BinaryOp>, (value, overflowed): (i32, bool)) -> ConstResult<i32> {
+    if overflowed {
+        Err(ConstError::Overflow { op, wrapped: value })
+    } else {
+        Ok(value)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::ast::expr::BinaryOp;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstError {
+    // 상수가 아닌 식 (변수, 호출, 대입 등)
+    NotConstant,
+    // 정수가 아닌 상수 (실수, 문자열)
+    NotInteger,
+    DivisionByZero,
+    // 32 비트 부호 있는 범위를 넘음: wrapped 는 2 의 보수로 감싼 값
+    Overflow { op: Option<BinaryOp>, wrapped: i32 },
+}
@@ -0,0 +1,5 @@
+pub mod consteval;
+pub mod error;
+
+pub use consteval::{ConstResult, char_value, eval_int};
+pub use error::ConstError;
@@ -244,6 +244,31 @@ impl Lexer {
             Some('[') => Token::LBracket,
             Some(']') => Token::RBracket,
 
+            // 가변 인자 '...'
+            Some('.')
+                if self.peek_char() == Some('.')
+                    && self.input.get(self.read_pos + 1) == Some(&'.') =>
+            {
+                self.read_char();
+                self.read_char();
+                Token::Ellipsis
+            }
+
+            Some('"') => {
+                return match self.read_string_literal() {
+                    Ok(s) => SpannedToken {
+                        kind: Token::StringLiteral(s),
+                        line,
+                        column,
+                    },
+                    Err(e) => SpannedToken {
+                        kind: Token::Error(e),
+                        line,
+                        column,
+                    },
+                };
+            }
+
             Some('\'') => {
                 return match self.read_char_literal() {
                     Ok(ch) => SpannedToken {
@@ -270,11 +295,19 @@ impl Lexer {
 
             Some(c) if c.is_numeric() => {
                 return match self.read_number() {
-                    Ok(num) => SpannedToken {
-                        kind: Token::IntLiteral(num),
+                    Ok(kind) => SpannedToken { kind, line, column },
+                    Err(e) => SpannedToken {
+                        kind: Token::Error(e),
                         line,
                         column,
                     },
+                };
+            }
+
+            // .5 처럼 정수부 없이 시작하는 실수 리터럴
+            Some('.') if self.peek_char().is_some_and(|c| c.is_ascii_digit()) => {
+                return match self.read_number() {
+                    Ok(kind) => SpannedToken { kind, line, column },
                     Err(e) => SpannedToken {
                         kind: Token::Error(e),
                         line,
@@ -287,11 +320,12 @@ impl Lexer {
             Some(c) => Token::Illegal(c),
         };
 
+        // 위치는 토큰의 첫 글자 (마지막 글자를 소비하기 전에 잡아 둔 값)
         self.read_char();
         SpannedToken {
             kind: tok,
-            line: self.line,
-            column: self.column,
+            line,
+            column,
         }
     }
 
@@ -306,20 +340,61 @@ impl Lexer {
         self.input[start..self.pos].iter().collect()
     }
 
-    fn read_number(&mut self) -> Result<i64, LexError> {
+    /// 정수 리터럴 또는 실수 리터럴 (1.5, .5, 1e-3, 2.0f)
+    fn read_number(&mut self) -> Result<Token, LexError> {
         let start = self.pos;
-        while let Some(c) = self.ch {
-            if !c.is_numeric() {
-                break;
+        let mut is_floating = false;
+
+        self.skip_digits();
+        // 소수부
+        if self.ch == Some('.') {
+            is_floating = true;
+            self.read_char();
+            self.skip_digits();
+        }
+        // 지수부
+        if matches!(self.ch, Some('e' | 'E')) {
+            is_floating = true;
+            self.read_char();
+            if matches!(self.ch, Some('+' | '-')) {
+                self.read_char();
             }
-            self.read_char()
+            if !self.ch.is_some_and(|c| c.is_ascii_digit()) {
+                let lit: String = self.input[start..self.pos].iter().collect();
+                return Err(LexError::InvalidNumericLiteral(lit));
+            }
+            self.skip_digits();
         }
 
         let lit: String = self.input[start..self.pos].iter().collect();
+        // 접미사 f/F 는 float, 그 외 실수는 double
+        if is_floating && matches!(self.ch, Some('f' | 'F')) {
+            self.read_char();
+            let value = lit
+                .parse::<f64>()
+                .map_err(|_| LexError::InvalidNumericLiteral(lit))?;
+            return Ok(Token::FloatLiteral(value));
+        }
+        if is_floating {
+            let value = lit
+                .parse::<f64>()
+                .map_err(|_| LexError::InvalidNumericLiteral(lit))?;
+            return Ok(Token::DoubleLiteral(value));
+        }
+
         let value = lit
             .parse::<i64>()
             .map_err(|_| LexError::InvalidNumericLiteral(lit))?;
-        Ok(value)
+        Ok(Token::IntLiteral(value))
+    }
+
+    fn skip_digits(&mut self) {
+        while let Some(c) = self.ch {
+            if !c.is_numeric() {
+                break;
+            }
+            self.read_char()
+        }
     }
 
     fn read_char_literal(&mut self) -> Result<char, LexError> {
@@ -327,17 +402,7 @@ impl Lexer {
         self.read_char();
 
         let lit = match self.ch {
-            Some('\\') => {
-                self.read_char();
-                match self.ch {
-                    Some('0') => '\0',
-                    Some('n') => '\n',
-                    Some('t') => '\t',
-                    Some('\\') => '\\',
-                    Some('\'') => '\'',
-                    _ => self.ch.unwrap_or('\0'),
-                }
-            }
+            Some('\\') => self.read_escape(),
             Some(c) => c,
             None => '\0',
         };
@@ -351,4 +416,36 @@ impl Lexer {
         self.read_char();
         Ok(lit)
     }
+
+    fn read_string_literal(&mut self) -> Result<String, LexError> {
+        // current ch == Some(") 일때,
+        self.read_char();
+
+        let mut lit = String::new();
+        loop {
+            match self.ch {
+                Some('"') => break,
+                Some('\\') => lit.push(self.read_escape()),
+                Some('\n') | None => return Err(LexError::UnterminatedStringLiteral),
+                Some(c) => lit.push(c),
+            }
+            self.read_char();
+        }
+        // 닫는 큰따옴표 소비
+        self.read_char();
+        Ok(lit)
+    }
+
+    /// 이스케이프 시퀀스 해석 (current ch == Some(\\) 일때)
+    fn read_escape(&mut self) -> char {
+        self.read_char();
+        match self.ch {
+            Some('0') => '\0',
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('\\') => '\\',
+            Some('\'') => '\'',
+            _ => self.ch.unwrap_or('\0'),
+        }
+    }
 }
@@ -3,6 +3,8 @@
 pub enum LexError {
     // 닫는 따옴표 없이 끝난 문자 리터럴
     UnterminatedCharLiteral,
+    // 닫는 큰따옴표 없이 끝난 문자열 리터럴
+    UnterminatedStringLiteral,
     // 잘못된 정수 리터럴 (파싱 실패)
     InvalidNumericLiteral(String),
 }
@@ -27,8 +29,11 @@ pub enum Token {
     Ident(String),
 
     // literals
-    IntLiteral(i64),   // 123
-    CharLiteral(char), // 'a', '\n', '\0'
+    IntLiteral(i64),       // 123
+    FloatLiteral(f64),     // 2.0f, 1e-3f
+    DoubleLiteral(f64),    // 1.5, 1e-3
+    CharLiteral(char),     // 'a', '\n', '\0'
+    StringLiteral(String), // "hello\n"
 
     // Operators
     Plus,     // +
@@ -54,6 +59,7 @@ pub enum Token {
 
     Semicolon, // ;
     Comma,     // ,
+    Ellipsis,  // ...
 
     LParen,   // (
     RParen,   // )
@@ -75,6 +81,16 @@ pub enum Token {
     Int,
     Char,
     Void,
+    Float,
+    Double,
+
+    // Qualifiers
+    Const,
+    Volatile,
+
+    // Storage classes
+    Static,
+    Extern,
 
     Increment,      // ++
     Decrement,      // --
@@ -95,6 +111,12 @@ pub fn lookup_ident(ident: &str) -> Token {
         "int" => Token::Int,
         "char" => Token::Char,
         "void" => Token::Void,
+        "float" => Token::Float,
+        "double" => Token::Double,
+        "const" => Token::Const,
+        "volatile" => Token::Volatile,
+        "static" => Token::Static,
+        "extern" => Token::Extern,
         "if" => Token::If,
         "else" => Token::Else,
         "while" => Token::While,
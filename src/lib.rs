@@ -1,8 +1,13 @@
+#![allow(clippy::module_inception)]
+
 pub mod ast;
+pub mod consteval;
 pub mod lexer;
+pub mod linker;
 pub mod parser;
 pub mod semantic;
 
 pub use lexer::Lexer;
+pub use linker::Linker;
 pub use parser::Parser;
 pub use semantic::Analyzer as SemanticAnalyzer;
@@ -0,0 +1,19 @@
+#[derive(Debug)]
+pub enum LinkError {
+    MultipleDefinition {
+        name: String,
+        first: String, // 먼저 정의한 번역 단위
+        second: String,
+    },
+    SignatureMismatch {
+        name: String,
+        first: String, // 먼저 선언한 번역 단위
+        second: String,
+    },
+    UnresolvedSymbol {
+        name: String,
+        unit: String, // 참조한 번역 단위
+    },
+    // 어느 단위에도 main 정의가 없음
+    MissingEntryPoint,
+}
@@ -0,0 +1,115 @@
+use crate::linker::LinkError;
+use crate::semantic::SemanticModel;
+use crate::semantic::entry_point::EntryPoint;
+use crate::semantic::resolver::prelude::is_runtime_function;
+use crate::semantic::symbol::symbol::{Linkage, Symbol, SymbolTable};
+use std::collections::HashMap;
+
+pub type LinkResult<T> = Result<T, LinkError>;
+
+/// 따로 분석한 번역 단위의 외부 심볼
+struct Unit {
+    name: String,
+    symbols: Vec<(String, Symbol)>, // 외부 링크 선언·정의
+    externals: Vec<String>,         // 단위 안에서 정의되지 않은 참조
+}
+
+/// 번역 단위들의 외부 심볼 테이블을 합치는 링크 단계
+#[derive(Default)]
+pub struct Linker {
+    units: Vec<Unit>,
+    pub entry: EntryPoint, // None 이 아니면 main 정의가 있어야 함
+}
+
+impl Linker {
+    pub fn new() -> Self {
+        Linker {
+            units: Vec::new(),
+            entry: EntryPoint::default(),
+        }
+    }
+
+    /// 분석이 끝난 번역 단위 추가 (unit 은 보통 파일 이름)
+    pub fn add_unit(&mut self, unit: &str, model: &SemanticModel<'_>) {
+        // static 이름은 단위 밖으로 보이지 않음
+        let mut symbols: Vec<(String, Symbol)> = model
+            .symbols
+            .globals()
+            .filter(|(_, symbol)| symbol.linkage == Linkage::External)
+            .map(|(name, symbol)| (name.clone(), symbol.clone()))
+            .collect();
+        // 오류 보고 순서를 고정
+        symbols.sort_by(|a, b| a.0.cmp(&b.0));
+
+        self.units.push(Unit {
+            name: unit.to_string(),
+            symbols,
+            externals: model.externals.clone(),
+        });
+    }
+
+    /// 외부 심볼을 하나의 테이블로 합치고 다중 정의, 시그니처 불일치, 미해결 참조,
+    /// 진입점 누락을 검사
+    pub fn link(&self) -> LinkResult<SymbolTable> {
+        let mut table = SymbolTable::new();
+        // 이름 -> (처음 선언한 단위, 정의한 단위)
+        let mut origins: HashMap<String, (String, Option<String>)> = HashMap::new();
+
+        for unit in &self.units {
+            for (name, symbol) in &unit.symbols {
+                let Some(id) = table.resolve_global(name) else {
+                    let defined_in = symbol.is_defined.then(|| unit.name.clone());
+                    origins.insert(name.clone(), (unit.name.clone(), defined_in));
+                    table
+                        .declare(name.clone(), symbol.clone())
+                        .expect("처음 보는 이름");
+                    continue;
+                };
+                let existing = table.symbol_mut(id);
+
+                let (declared_in, defined_in) = origins.get_mut(name).unwrap();
+                let Some((ty, kind)) = existing.composite(symbol) else {
+                    return Err(LinkError::SignatureMismatch {
+                        name: name.clone(),
+                        first: declared_in.clone(),
+                        second: unit.name.clone(),
+                    });
+                };
+                (existing.ty, existing.kind) = (ty, kind);
+                if symbol.is_defined {
+                    if let Some(first) = defined_in {
+                        return Err(LinkError::MultipleDefinition {
+                            name: name.clone(),
+                            first: first.clone(),
+                            second: unit.name.clone(),
+                        });
+                    }
+                    *defined_in = Some(unit.name.clone());
+                    existing.is_defined = true;
+                }
+            }
+        }
+
+        // 어느 단위에서도 정의되지 않은 참조 (런타임 라이브러리 함수는 제외)
+        for unit in &self.units {
+            for name in &unit.externals {
+                if !table.lookup(name).is_some_and(|symbol| symbol.is_defined)
+                    && !is_runtime_function(name)
+                {
+                    return Err(LinkError::UnresolvedSymbol {
+                        name: name.clone(),
+                        unit: unit.name.clone(),
+                    });
+                }
+            }
+        }
+
+        if self.entry != EntryPoint::None
+            && !table.lookup("main").is_some_and(|symbol| symbol.is_defined)
+        {
+            return Err(LinkError::MissingEntryPoint);
+        }
+
+        Ok(table)
+    }
+}
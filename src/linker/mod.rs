@@ -0,0 +1,5 @@
+pub mod error;
+pub mod linker;
+
+pub use error::LinkError;
+pub use linker::{LinkResult, Linker};
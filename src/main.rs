@@ -1,16 +1,147 @@
-use rustc_tape4::{Lexer, Parser, SemanticAnalyzer};
+use rustc_tape4::ast::SourceMap;
+use rustc_tape4::semantic::call_graph::CallGraph;
+use rustc_tape4::semantic::entry_point::EntryPoint;
+use rustc_tape4::semantic::lint::LintConfig;
+use rustc_tape4::{Lexer, Linker, Parser, SemanticAnalyzer};
+use std::process;
+
+/// rustc_tape4 a.c b.c -o prog [-Wall] [-Wextra] [-W<name>] [-Wno-<name>] [-Werror]
+///                              [-fentry=none|hosted|strict] [-fno-prelude]
+///                              [-fcall-graph=text|dot]
+struct Options {
+    inputs: Vec<String>,
+    output: Option<String>,
+    lints: LintConfig,
+    entry: EntryPoint,
+    prelude: bool,
+    call_graph: Option<CallGraphFormat>,
+}
+
+/// 호출 그래프 출력 형식
+#[derive(Clone, Copy)]
+enum CallGraphFormat {
+    Text,
+    Dot,
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut inputs = Vec::new();
+    let mut output = None;
+    let mut lints = LintConfig::default();
+    let mut entry = EntryPoint::default();
+    let mut prelude = true;
+    let mut call_graph = None;
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        if arg == "-o" {
+            output = Some(args.next().ok_or("missing file name after '-o'")?);
+        } else if arg.starts_with("-W") {
+            lints
+                .apply_flag(&arg)
+                .map_err(|err| format!("invalid warning option '{arg}': {:?}", err))?;
+        } else if let Some(mode) = arg.strip_prefix("-fentry=") {
+            entry = EntryPoint::from_name(mode)
+                .ok_or_else(|| format!("invalid entry point mode '{mode}'"))?;
+        } else if arg == "-fno-prelude" {
+            prelude = false;
+        } else if let Some(format) = arg.strip_prefix("-fcall-graph=") {
+            call_graph = Some(match format {
+                "text" => CallGraphFormat::Text,
+                "dot" => CallGraphFormat::Dot,
+                _ => return Err(format!("invalid call graph format '{format}'")),
+            });
+        } else if arg.starts_with('-') {
+            return Err(format!("unknown option '{arg}'"));
+        } else {
+            inputs.push(arg);
+        }
+    }
+    // 입력이 없으면 예제 파일 분석
+    if inputs.is_empty() {
+        inputs.push("tests/fixtures/sample.c".to_string());
+    }
+    Ok(Options {
+        inputs,
+        output,
+        lints,
+        entry,
+        prelude,
+        call_graph,
+    })
+}
 
 fn main() {
-    let source = std::fs::read_to_string("tests/fixtures/sample.c").expect("could not read file");
+    let options = parse_args(std::env::args().skip(1)).unwrap_or_else(|message| {
+        eprintln!("error: {message}");
+        process::exit(2);
+    });
+
+    // 번역 단위마다 따로 분석한 뒤 링크 단계에서 합침
+    let mut linker = Linker::new();
+    linker.entry = options.entry;
+    for path in &options.inputs {
+        let source = std::fs::read_to_string(path).unwrap_or_else(|err| {
+            eprintln!("error: could not read {path}: {err}");
+            process::exit(1);
+        });
+
+        // lexing
+        let tokens = Lexer::new(&source).collect_spanned_tokens();
+
+        // parsing
+        let mut parser = Parser::new(tokens);
+        let program = parser.parse_program().unwrap_or_else(|err| {
+            eprintln!("{path}: error: {:?}", err);
+            process::exit(1);
+        });
 
-    // lexing
-    let tokens = Lexer::new(&source).collect_spanned_tokens();
+        // semantic analysis
+        let mut analyzer = SemanticAnalyzer::new(&program);
+        analyzer.lints = options.lints.clone();
+        analyzer.entry = options.entry;
+        analyzer.prelude = options.prelude;
+        let result = analyzer.analyze();
+        // -Werror 로 실패해도 원인이 된 경고를 먼저 출력
+        let severity = if options.lints.werror {
+            "error"
+        } else {
+            "warning"
+        };
+        let source_map = SourceMap::new(&program);
+        for warning in &analyzer.warnings {
+            let location = match warning.pos(&source_map) {
+                Some(pos) => format!("{path}:{pos}"),
+                None => path.clone(),
+            };
+            eprintln!(
+                "{location}: {severity}: {} [-W{}]",
+                warning.message(&source_map),
+                warning.lint().name()
+            );
+        }
+        let model = result.unwrap_or_else(|err| {
+            eprintln!("{path}: error: {:?}", err);
+            process::exit(1);
+        });
+        // 재귀와 main 에서의 최악 스택 깊이
+        if let Some(format) = options.call_graph {
+            let graph = CallGraph::new(&program, &model);
+            match format {
+                CallGraphFormat::Text => print!("{}", graph.to_text("main")),
+                CallGraphFormat::Dot => print!("{}", graph.to_dot()),
+            }
+        }
+        linker.add_unit(path, &model);
+    }
 
-    // parsing
-    let mut parser = Parser::new(tokens);
-    let program = parser.parse_program().expect("Parsing Failed");
+    // linking
+    if let Err(err) = linker.link() {
+        eprintln!("link error: {:?}", err);
+        process::exit(1);
+    }
 
-    // semantic analysis
-    let mut analyzer = SemanticAnalyzer::new(&program);
-    let _result = analyzer.analyze().expect("Analyzing Failed");
+    // 코드 생성 단계가 아직 없으므로 출력 파일은 만들지 않음
+    if let Some(output) = &options.output {
+        eprintln!("note: no code generator yet, '{output}' was not written");
+    }
 }
@@ -0,0 +1,202 @@
+use crate::ast::functions::Parameter;
+use crate::ast::program::Pos;
+use crate::ast::{Qualifiers, TypeSpecifier};
+use crate::consteval::eval_int;
+use crate::lexer::Token;
+use crate::parser::Parser;
+use crate::parser::error::ParserError;
+use crate::parser::parser::ParseResult;
+
+/// 선언자의 이름과 그 위치
+type Name = (String, Pos);
+
+/// 선언자가 기본 타입에 덧씌우는 타입 구성 요소
+enum Derived {
+    Pointer(Qualifiers),
+    Array(Option<usize>),
+    Function(Vec<TypeSpecifier>, bool),
+}
+
+impl Parser {
+    /// declarator ::= "*"* direct_declarator
+    ///
+    /// 기본 타입(base)에 선언자를 적용한 (이름, 전체 타입)을 반환
+    /// ex) int *a[4]          -> Array(Pointer(Int), 4)
+    ///     int (*p)[4]        -> Pointer(Array(Int, 4))
+    ///     int m[3][4]        -> Array(Array(Int, 4), 3)
+    ///     int (*cb)(int)     -> Pointer(Function { ret: Int, params: [Int], .. })
+    pub fn parse_declarator(
+        &mut self,
+        base: TypeSpecifier,
+    ) -> ParseResult<(String, Pos, TypeSpecifier)> {
+        let (name, derived) = self.parse_derived_declarator(false)?;
+        let (name, pos) = name.expect("이름 있는 선언자는 항상 이름을 가짐");
+        Ok((name, pos, apply_derived(base, derived)))
+    }
+
+    /// 이름이 생략될 수 있는 선언자 (함수 타입의 매개변수 등)
+    /// ex) int (*)(int), char *, int []
+    pub fn parse_abstract_declarator(
+        &mut self,
+        base: TypeSpecifier,
+    ) -> ParseResult<(Option<Name>, TypeSpecifier)> {
+        let (name, derived) = self.parse_derived_declarator(true)?;
+        Ok((name, apply_derived(base, derived)))
+    }
+
+    /// direct_declarator ::= ( identifier | "(" declarator ")" ) declarator_suffix*
+    /// declarator_suffix ::= "[" constant_expression? "]" | "(" ( "void" | parameter_type_list )? ")"
+    ///
+    /// 기본 타입에 적용할 순서대로 타입 구성 요소를 모음
+    fn parse_derived_declarator(
+        &mut self,
+        allow_abstract: bool,
+    ) -> ParseResult<(Option<Name>, Vec<Derived>)> {
+        // 앞쪽 '*' 와 각 포인터 레벨의 한정자 (char * const p)
+        let mut pointers = Vec::new();
+        while self.current_token() == &Token::Asterisk {
+            self.next_token();
+            pointers.push(Derived::Pointer(self.parse_type_qualifiers()));
+        }
+
+        // 이름 또는 괄호로 묶인 선언자
+        let (name, inner) = match self.current_token() {
+            Token::LParen if !allow_abstract || self.is_nested_declarator() => {
+                self.next_token(); // '('
+                let inner = self.parse_derived_declarator(allow_abstract)?;
+                self.expect(Token::RParen)?; // ')'
+                inner
+            }
+            Token::Ident(_) => (Some(self.parse_declarator_name()?), Vec::new()),
+            _ if allow_abstract => (None, Vec::new()),
+            _ => (Some(self.parse_declarator_name()?), Vec::new()),
+        };
+
+        // 뒤쪽 배열 첨자 / 함수 매개변수 목록
+        let mut suffixes = Vec::new();
+        loop {
+            match self.current_token() {
+                Token::LBracket => {
+                    self.next_token(); // '['
+                    let size = if self.current_token() == &Token::RBracket {
+                        None
+                    } else {
+                        Some(self.parse_array_size()?)
+                    };
+                    self.expect(Token::RBracket)?; // ']'
+                    suffixes.push(Derived::Array(size));
+                }
+                Token::LParen => {
+                    self.next_token(); // '('
+                    let (params, is_variadic) = self.parse_parameter_list()?;
+                    self.expect(Token::RParen)?; // ')'
+                    // 매개변수의 최상위 한정자는 함수 타입에 포함되지 않음
+                    let params = params
+                        .into_iter()
+                        .map(|param| param.ty.unqualified().clone())
+                        .collect();
+                    suffixes.push(Derived::Function(params, is_variadic));
+                }
+                _ => break,
+            }
+        }
+
+        // 포인터 -> 첨자(안쪽부터) -> 괄호 안 선언자 순으로 적용
+        let mut derived = pointers;
+        derived.extend(suffixes.into_iter().rev());
+        derived.extend(inner);
+        Ok((name, derived))
+    }
+
+    /// 선언자의 이름과 그 위치
+    fn parse_declarator_name(&mut self) -> ParseResult<Name> {
+        let pos = self.start_pos();
+        Ok((self.expect_ident()?, pos))
+    }
+
+    /// 배열 크기: 양수인 정수 상수식 (int a[3 * 4 + 1])
+    fn parse_array_size(&mut self) -> ParseResult<usize> {
+        let (line, col) = self.current_span();
+        // 크기 식은 AST 에 남지 않으므로 기록한 위치도 버림
+        let recorded = self.expr_positions.len();
+        let expr = self.parse_constant_expr()?;
+        self.expr_positions.truncate(recorded);
+        match eval_int(&expr) {
+            Ok(size) if size > 0 => Ok(size as usize),
+            Ok(size) => Err(ParserError::InvalidArraySize { size, line, col }),
+            Err(error) => Err(ParserError::ConstantExpression { error, line, col }),
+        }
+    }
+
+    /// 추상 선언자에서 '(' 가 괄호 선언자인지 함수 매개변수 목록인지 구분
+    fn is_nested_declarator(&self) -> bool {
+        matches!(
+            self.peek_token(),
+            Token::Asterisk | Token::LParen | Token::LBracket
+        )
+    }
+
+    /// parameter_list ::= parameter ( "," parameter )* ( "," "..." )?
+    /// parameter      ::= base_type ( declarator | abstract_declarator )
+    ///
+    /// 함수 정의·프로토타입과 함수 선언자가 함께 씀 ('(' 다음부터 ')' 앞까지)
+    pub fn parse_parameter_list(&mut self) -> ParseResult<(Vec<Parameter>, bool)> {
+        // (void) 또는 () 는 매개변수 없음
+        if self.current_token() == &Token::Void && self.peek_token() == &Token::RParen {
+            self.next_token(); // void 소비
+            return Ok((Vec::new(), false));
+        }
+        let mut params = Vec::new();
+        if self.current_token() == &Token::RParen {
+            return Ok((params, false));
+        }
+
+        loop {
+            let start = self.start_pos();
+            let base_ty = self.parse_base_type()?;
+            let (name, ty) = self.parse_abstract_declarator(base_ty)?;
+            // 배열/함수 매개변수를 포인터로, 이름이 없으면 타입의 위치
+            let ty = adjust_parameter_type(ty);
+            let (name, pos) = match name {
+                Some((name, pos)) => (Some(name), pos),
+                None => (None, start),
+            };
+            params.push(Parameter { name, ty, pos });
+
+            if self.current_token() != &Token::Comma {
+                return Ok((params, false));
+            }
+            self.next_token(); // ','
+            if self.current_token() == &Token::Ellipsis {
+                self.next_token(); // '...'
+                return Ok((params, true));
+            }
+        }
+    }
+}
+
+fn apply_derived(base: TypeSpecifier, derived: Vec<Derived>) -> TypeSpecifier {
+    derived.into_iter().fold(base, |ty, d| match d {
+        Derived::Pointer(quals) => TypeSpecifier::Pointer(Box::new(ty)).qualified(quals),
+        Derived::Array(size) => TypeSpecifier::Array(Box::new(ty), size),
+        Derived::Function(params, is_variadic) => TypeSpecifier::Function {
+            ret: Box::new(ty),
+            params,
+            is_variadic,
+        },
+    })
+}
+
+/// 매개변수 타입 조정: 배열은 원소 포인터로, 함수는 함수 포인터로
+/// (함수 배열은 만들 수 없는 타입이므로 그대로 두어 타입 검사에서 거부)
+pub fn adjust_parameter_type(ty: TypeSpecifier) -> TypeSpecifier {
+    match ty {
+        TypeSpecifier::Array(elem, _)
+            if !matches!(elem.unqualified(), TypeSpecifier::Function { .. }) =>
+        {
+            TypeSpecifier::Pointer(elem)
+        }
+        func @ TypeSpecifier::Function { .. } => TypeSpecifier::Pointer(Box::new(func)),
+        other => other,
+    }
+}
@@ -1,3 +1,4 @@
+use crate::consteval::ConstError;
 use crate::lexer::Token;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -18,4 +19,21 @@ pub enum ParserError {
         line: usize,
         col: usize,
     },
+    MissingParameterName {
+        function: String,
+        line: usize,
+        col: usize,
+    },
+    // 상수식이어야 하는 자리의 평가 실패 (상수가 아님, 0 으로 나누기, 오버플로)
+    ConstantExpression {
+        error: ConstError,
+        line: usize,
+        col: usize,
+    },
+    // 0 이하인 배열 크기
+    InvalidArraySize {
+        size: i32,
+        line: usize,
+        col: usize,
+    },
 }
@@ -10,6 +10,12 @@ impl Parser {
         self.parse_assignment()
     }
 
+    /// constant_expression ::= logical_or
+    /// (배열 크기처럼 컴파일 시간에 평가할 식: 대입과 쉼표는 올 수 없음)
+    pub fn parse_constant_expr(&mut self) -> ParseResult<Expr> {
+        self.parse_logical_or()
+    }
+
     /// assignment ::= logical_or ( ( "="  | "+=" | "-=" | "*=" | "/=" | "%=" | "&=" | "|=" | "^=" ) assignment )?
     fn parse_assignment(&mut self) -> ParseResult<Expr> {
         let mut lhs = self.parse_logical_or()?;
@@ -29,25 +35,21 @@ impl Parser {
             }
         };
 
-        // 할당 연산자 소비
+        // 할당 연산자 소비 (대상이 lvalue 인지는 타입 검사에서 확인)
+        let pos = self.start_pos();
         self.next_token();
-        match &lhs {
-            Expr::Ident(_)
-            | Expr::UnaryPrefixOp {
-                op: PrefixOp::Deref,
-                ..
-            }
-            | Expr::ArrayIndex { .. } => {}
-            _ => return self.unsupported_token(),
-        }
 
         let rhs = self.parse_assignment()?;
 
-        lhs = Expr::Assignment {
-            left: Box::new(lhs),
-            op,
-            right: Box::new(rhs),
-        };
+        lhs = self.expr_at(
+            pos,
+            Expr::Assignment {
+                left: Box::new(lhs),
+                op,
+                right: Box::new(rhs),
+                parenthesized: false,
+            },
+        );
         Ok(lhs)
     }
 
@@ -62,12 +64,16 @@ impl Parser {
             Token::Decrement => PrefixOp::PreDec,
             _ => return self.parse_postfix(),
         };
+        let pos = self.start_pos();
         self.next_token();
         let rhs = self.parse_unary()?;
-        Ok(Expr::UnaryPrefixOp {
-            op,
-            rhs: Box::new(rhs),
-        })
+        Ok(self.expr_at(
+            pos,
+            Expr::UnaryPrefixOp {
+                op,
+                rhs: Box::new(rhs),
+            },
+        ))
     }
 
     /// multiplicative ::= unary ( ( "*" | "/" | "%" ) unary )*
@@ -81,13 +87,17 @@ impl Parser {
                 Token::Percent => BinaryOp::Rem,
                 _ => break,
             };
+            let pos = self.start_pos();
             self.next_token(); // 연산자소비
             let rhs = self.parse_unary()?;
-            expr = Expr::BinaryOp {
-                lhs: Box::new(expr),
-                op,
-                rhs: Box::new(rhs),
-            };
+            expr = self.expr_at(
+                pos,
+                Expr::BinaryOp {
+                    lhs: Box::new(expr),
+                    op,
+                    rhs: Box::new(rhs),
+                },
+            );
         }
 
         Ok(expr)
@@ -103,13 +113,17 @@ impl Parser {
                 Token::Minus => BinaryOp::Sub,
                 _ => break,
             };
+            let pos = self.start_pos();
             self.next_token(); // 연산자소비
             let rhs = self.parse_multiplicative()?;
-            expr = Expr::BinaryOp {
-                lhs: Box::new(expr),
-                op,
-                rhs: Box::new(rhs),
-            };
+            expr = self.expr_at(
+                pos,
+                Expr::BinaryOp {
+                    lhs: Box::new(expr),
+                    op,
+                    rhs: Box::new(rhs),
+                },
+            );
         }
 
         Ok(expr)
@@ -127,13 +141,17 @@ impl Parser {
                 Token::Ge => BinaryOp::Ge,
                 _ => break,
             };
+            let pos = self.start_pos();
             self.next_token(); // 연산자소비
             let rhs = self.parse_additive()?;
-            expr = Expr::BinaryOp {
-                lhs: Box::new(expr),
-                op,
-                rhs: Box::new(rhs),
-            };
+            expr = self.expr_at(
+                pos,
+                Expr::BinaryOp {
+                    lhs: Box::new(expr),
+                    op,
+                    rhs: Box::new(rhs),
+                },
+            );
         }
 
         Ok(expr)
@@ -148,13 +166,17 @@ impl Parser {
                 Token::NotEqual => BinaryOp::Ne,
                 _ => break,
             };
+            let pos = self.start_pos();
             self.next_token(); // 연산자 소비
             let rhs = self.parse_relational()?;
-            expr = Expr::BinaryOp {
-                lhs: Box::new(expr),
-                op,
-                rhs: Box::new(rhs),
-            };
+            expr = self.expr_at(
+                pos,
+                Expr::BinaryOp {
+                    lhs: Box::new(expr),
+                    op,
+                    rhs: Box::new(rhs),
+                },
+            );
         }
 
         Ok(expr)
@@ -165,13 +187,17 @@ impl Parser {
         let mut expr = self.parse_bitwise_or()?;
 
         while self.current_token() == &Token::And {
+            let pos = self.start_pos();
             self.next_token(); // '&&' 소비
             let rhs = self.parse_bitwise_or()?;
-            expr = Expr::BinaryOp {
-                lhs: Box::new(expr),
-                op: BinaryOp::And,
-                rhs: Box::new(rhs),
-            };
+            expr = self.expr_at(
+                pos,
+                Expr::BinaryOp {
+                    lhs: Box::new(expr),
+                    op: BinaryOp::And,
+                    rhs: Box::new(rhs),
+                },
+            );
         }
 
         Ok(expr)
@@ -181,13 +207,17 @@ impl Parser {
     fn parse_bitwise_or(&mut self) -> ParseResult<Expr> {
         let mut expr = self.parse_bitwise_xor()?;
         while self.current_token() == &Token::BitOr {
+            let pos = self.start_pos();
             self.next_token();
             let rhs = self.parse_bitwise_xor()?;
-            expr = Expr::BinaryOp {
-                lhs: Box::new(expr),
-                op: BinaryOp::BitOr,
-                rhs: Box::new(rhs),
-            };
+            expr = self.expr_at(
+                pos,
+                Expr::BinaryOp {
+                    lhs: Box::new(expr),
+                    op: BinaryOp::BitOr,
+                    rhs: Box::new(rhs),
+                },
+            );
         }
         Ok(expr)
     }
@@ -196,13 +226,17 @@ impl Parser {
     fn parse_bitwise_xor(&mut self) -> ParseResult<Expr> {
         let mut expr = self.parse_bitwise_and()?;
         while self.current_token() == &Token::BitXor {
+            let pos = self.start_pos();
             self.next_token();
             let rhs = self.parse_bitwise_and()?;
-            expr = Expr::BinaryOp {
-                lhs: Box::new(expr),
-                op: BinaryOp::BitXor,
-                rhs: Box::new(rhs),
-            };
+            expr = self.expr_at(
+                pos,
+                Expr::BinaryOp {
+                    lhs: Box::new(expr),
+                    op: BinaryOp::BitXor,
+                    rhs: Box::new(rhs),
+                },
+            );
         }
         Ok(expr)
     }
@@ -211,13 +245,17 @@ impl Parser {
     fn parse_bitwise_and(&mut self) -> ParseResult<Expr> {
         let mut expr = self.parse_equality()?;
         while self.current_token() == &Token::Ampersand {
+            let pos = self.start_pos();
             self.next_token();
             let rhs = self.parse_equality()?;
-            expr = Expr::BinaryOp {
-                lhs: Box::new(expr),
-                op: BinaryOp::BitAnd,
-                rhs: Box::new(rhs),
-            };
+            expr = self.expr_at(
+                pos,
+                Expr::BinaryOp {
+                    lhs: Box::new(expr),
+                    op: BinaryOp::BitAnd,
+                    rhs: Box::new(rhs),
+                },
+            );
         }
         Ok(expr)
     }
@@ -227,13 +265,17 @@ impl Parser {
         let mut expr = self.parse_logical_and()?;
 
         while self.current_token() == &Token::Or {
+            let pos = self.start_pos();
             self.next_token(); // '||' 소비
             let rhs = self.parse_logical_and()?;
-            expr = Expr::BinaryOp {
-                lhs: Box::new(expr),
-                op: BinaryOp::Or,
-                rhs: Box::new(rhs),
-            };
+            expr = self.expr_at(
+                pos,
+                Expr::BinaryOp {
+                    lhs: Box::new(expr),
+                    op: BinaryOp::Or,
+                    rhs: Box::new(rhs),
+                },
+            );
         }
 
         Ok(expr)
@@ -242,10 +284,11 @@ impl Parser {
     /// postfix ::= primary postfix_op*
     /// postfix_op ::= "(" argument_list? ")" | "[" expression "]" | "++" | "--"
     fn parse_postfix(&mut self) -> ParseResult<Expr> {
+        let pos = self.start_pos();
         let mut expr = self.parse_primary()?;
 
         loop {
-            expr = match self.current_token() {
+            let postfix = match self.current_token() {
                 Token::LParen => {
                     self.next_token(); // '('
 
@@ -302,28 +345,36 @@ impl Parser {
                 }
                 _ => break,
             };
+            expr = self.expr_at(pos, postfix);
         }
 
         Ok(expr)
     }
 
-    /// primary ::= identifier | int_literal | char_literal | "(" expression ")" | "{" initializer_list? "}"
+    /// primary ::= identifier | int_literal | float_literal | char_literal | string_literal | "(" expression ")" | "{" initializer_list? "}"
     fn parse_primary(&mut self) -> ParseResult<Expr> {
+        let pos = self.start_pos();
         let expr = match self.current_token() {
             Token::Ident(_) => self.parse_identifier()?,
             Token::IntLiteral(_) => self.parse_int_literal()?,
+            Token::FloatLiteral(_) | Token::DoubleLiteral(_) => self.parse_float_literal()?,
             Token::CharLiteral(_) => self.parse_char_literal()?,
+            Token::StringLiteral(_) => self.parse_string_literal()?,
             Token::LParen => {
+                // 괄호 식은 새 노드를 만들지 않으므로 안쪽 식의 위치를 그대로 씀
                 self.next_token(); // '('
-                let e = self.parse_expr()?;
+                let mut e = self.parse_expr()?;
                 self.expect(Token::RParen)?;
-                e
+                if let Expr::Assignment { parenthesized, .. } = &mut e {
+                    *parenthesized = true;
+                }
+                return Ok(e);
             }
-            Token::LBrace => self.parse_initializer_list()?,
+            Token::LBrace => return self.parse_initializer_list(),
             Token::EOF => return self.unexpected_eof("primary expression"),
             _ => return self.unsupported_token(),
         };
-        Ok(expr)
+        Ok(self.expr_at(pos, expr))
     }
 
     /// initializer ::= expression | "{" initializer_list? "}"
@@ -337,6 +388,7 @@ impl Parser {
 
     /// initializer_list ::= initializer ( "," initializer )* ","?
     fn parse_initializer_list(&mut self) -> ParseResult<Expr> {
+        let pos = self.start_pos();
         self.expect(Token::LBrace)?; // '{' 소비
         let mut exprs = Vec::new();
 
@@ -360,7 +412,7 @@ impl Parser {
         }
 
         self.expect(Token::RBrace)?; // '}' 소비
-        Ok(Expr::InitializerList(exprs))
+        Ok(self.expr_at(pos, Expr::InitializerList(exprs)))
     }
     /// char_literal ::= /* CharLiteral(char) */
     fn parse_char_literal(&mut self) -> ParseResult<Expr> {
@@ -374,6 +426,29 @@ impl Parser {
         Ok(Expr::IntLiteral(value))
     }
 
+    /// string_literal ::= /* StringLiteral(String) */+
+    /// 인접한 문자열 리터럴은 하나로 이어 붙임 ("ab" "cd" -> "abcd")
+    fn parse_string_literal(&mut self) -> ParseResult<Expr> {
+        let mut value = String::new();
+        while let Token::StringLiteral(s) = self.current_token() {
+            value.push_str(s);
+            self.next_token();
+        }
+        Ok(Expr::StringLiteral(value))
+    }
+
+    /// float_literal ::= /* FloatLiteral(f64) | DoubleLiteral(f64) */
+    fn parse_float_literal(&mut self) -> ParseResult<Expr> {
+        let expr = match self.current_token() {
+            Token::FloatLiteral(value) => Expr::FloatLiteral(*value),
+            Token::DoubleLiteral(value) => Expr::DoubleLiteral(*value),
+            Token::EOF => return self.unexpected_eof("floating literal"),
+            _ => return self.unsupported_token(),
+        };
+        self.next_token();
+        Ok(expr)
+    }
+
     /// identifier ::= /* Ident(String) */
     fn parse_identifier(&mut self) -> ParseResult<Expr> {
         let string = self.expect_ident()?;
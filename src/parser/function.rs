@@ -1,6 +1,6 @@
 use crate::ast::functions::Parameter;
-use crate::ast::stmt::Block;
-use crate::ast::{Function, TypeSpecifier};
+use crate::ast::program::Pos;
+use crate::ast::{Function, Qualifiers, StorageClass, TypeSpecifier};
 use crate::lexer::Token;
 use crate::parser::Parser;
 use crate::parser::error::ParserError;
@@ -8,31 +8,41 @@ use crate::parser::parser::ParseResult;
 
 impl Parser {
     /// 함수 헤더만 파싱
-    fn parse_function_header(&mut self) -> ParseResult<(TypeSpecifier, String, Vec<Parameter>)> {
+    fn parse_function_header(
+        &mut self,
+    ) -> ParseResult<(TypeSpecifier, String, Pos, Vec<Parameter>, bool)> {
         let return_ty = self.parse_type_specifier()?;
+        let pos = self.start_pos();
         let name = self.expect_ident()?;
         self.expect(Token::LParen)?;
-        let params = self.parse_parameters()?;
+        let (params, is_variadic) = self.parse_parameter_list()?;
         self.expect(Token::RParen)?;
-        Ok((return_ty, name, params))
+        Ok((return_ty, name, pos, params, is_variadic))
     }
 
     /// function ::= function_declaration | function_definition
     pub fn parse_function(&mut self) -> ParseResult<Function> {
-        let (return_ty, name, params) = self.parse_function_header()?;
+        let storage = self.parse_storage_class();
+        let (return_ty, name, pos, params, is_variadic) = self.parse_function_header()?;
 
         // 선언/정의 분기
         let body = match self.current_token() {
             Token::Semicolon => {
-                // 선언: 세미콜론만 소비하고 빈 블록(body) 생성
+                // 선언: 세미콜론만 소비, 본문 없음
                 self.next_token();
-                Block {
-                    statements: Vec::new(),
-                }
+                None
             }
             Token::LBrace => {
+                // 정의: 매개변수 이름은 생략할 수 없음
+                if params.iter().any(|param| param.name.is_none()) {
+                    return Err(ParserError::MissingParameterName {
+                        function: name,
+                        line: self.current_span().0,
+                        col: self.current_span().1,
+                    });
+                }
                 // 정의: 실제 블록 파싱
-                self.parse_block_statement()?
+                Some(self.parse_block_statement()?)
             }
             other => {
                 return Err(ParserError::UnexpectedToken {
@@ -45,90 +55,71 @@ impl Parser {
         };
 
         Ok(Function {
+            storage,
             name,
             return_ty,
             params,
+            is_variadic,
             body,
+            pos,
         })
     }
 
-    fn parse_parameters(&mut self) -> ParseResult<Vec<Parameter>> {
-        // void만 있고 바로 ')' 이면 파라미터 없음
-        if self.current_token() == &Token::Void && self.peek_token() == &Token::RParen {
-            self.next_token(); // void 소비
-            return Ok(Vec::new());
-        }
-        // 아무것도 없으면 빈 벡터
-        if self.current_token() == &Token::RParen {
-            return Ok(Vec::new());
-        }
-        // 진짜 파라미터 목록
-        self.parse_parameter_list()
-    }
-
-    /// type_specifier ::= ( "int" | "char" | "void" ) "*"*
+    /// type_specifier ::= base_type ( "*" type_qualifier* )*
     pub fn parse_type_specifier(&mut self) -> ParseResult<TypeSpecifier> {
-        // 기본 타입(int|char|void) 확인
-        let base_ty = match self.current_token() {
-            Token::Int => TypeSpecifier::Int,
-            Token::Char => TypeSpecifier::Char,
-            Token::Void => TypeSpecifier::Void,
-            _ => return self.unsupported_token(),
-        };
-        self.next_token();
+        let base_ty = self.parse_base_type()?;
 
         // 뒤따르는 "*" 만큼 포인터 레벨 올리기
         let mut ty = base_ty;
         while self.current_token() == &Token::Asterisk {
             self.next_token(); // '*' 소비
-            ty = TypeSpecifier::Pointer(Box::new(ty));
+            let quals = self.parse_type_qualifiers();
+            ty = TypeSpecifier::Pointer(Box::new(ty)).qualified(quals);
         }
 
         Ok(ty)
     }
 
-    /// parameter_list ::= parameter ( "," parameter )*
-    fn parse_parameter_list(&mut self) -> ParseResult<Vec<Parameter>> {
-        let mut params = Vec::new();
+    /// base_type ::= type_qualifier* ( "int" | "char" | "void" | "float" | "double" ) type_qualifier*
+    pub fn parse_base_type(&mut self) -> ParseResult<TypeSpecifier> {
+        let mut quals = self.parse_type_qualifiers();
+        let ty = match self.current_token() {
+            Token::Int => TypeSpecifier::Int,
+            Token::Char => TypeSpecifier::Char,
+            Token::Void => TypeSpecifier::Void,
+            Token::Float => TypeSpecifier::Float,
+            Token::Double => TypeSpecifier::Double,
+            _ => return self.unsupported_token(),
+        };
+        self.next_token();
+        // int const 처럼 뒤에 오는 한정자
+        let trailing = self.parse_type_qualifiers();
+        quals.is_const |= trailing.is_const;
+        quals.is_volatile |= trailing.is_volatile;
+        Ok(ty.qualified(quals))
+    }
 
-        // ')'
-        if self.current_token() == &Token::RParen {
-            return Ok(params);
-        }
+    /// storage_class ::= "static" | "extern"
+    pub fn parse_storage_class(&mut self) -> Option<StorageClass> {
+        let storage = match self.current_token() {
+            Token::Static => StorageClass::Static,
+            Token::Extern => StorageClass::Extern,
+            _ => return None,
+        };
+        self.next_token();
+        Some(storage)
+    }
 
+    /// type_qualifier ::= "const" | "volatile"
+    pub fn parse_type_qualifiers(&mut self) -> Qualifiers {
+        let mut quals = Qualifiers::default();
         loop {
-            // 변수명, 타입
-            let base_ty = self.parse_type_specifier()?;
-            let name = self.expect_ident()?;
-            let ty = if self.current_token() == &Token::LBracket {
-                // '['
-                self.next_token();
-                // 크기 명시(optional)
-                if self.current_token() != &Token::RBracket {
-                    // ex) int arr[10]
-                    let _sz = self.expect_int_literal()?;
-                }
-                // ']' 소비
-                self.expect(Token::RBracket)?;
-                // 배열 파라미터를 포인터로
-                TypeSpecifier::Pointer(Box::new(base_ty))
-            } else {
-                base_ty
-            };
-
-            params.push(Parameter { name, ty });
-
-            // ',' 혹은 ')'
             match self.current_token() {
-                Token::Comma => {
-                    self.next_token();
-                    continue;
-                }
-                Token::RParen => break,
-                _ => return self.unsupported_token(),
+                Token::Const => quals.is_const = true,
+                Token::Volatile => quals.is_volatile = true,
+                _ => return quals,
             }
+            self.next_token();
         }
-
-        Ok(params)
     }
 }
@@ -1,3 +1,4 @@
+mod declarator;
 mod error;
 mod error_helpers;
 mod expression;
@@ -5,5 +6,6 @@ mod function;
 mod parser;
 mod statements;
 mod utils;
+pub use error::ParserError;
 pub use parser::ParseResult;
 pub use parser::Parser;
@@ -1,4 +1,5 @@
-use crate::ast::Program;
+use crate::ast::program::Pos;
+use crate::ast::{ExternalDecl, Program};
 use crate::lexer::{SpannedToken, Token};
 use crate::parser::error::ParserError;
 
@@ -7,21 +8,65 @@ pub type ParseResult<T> = Result<T, ParserError>;
 pub struct Parser {
     pub tokens: Vec<SpannedToken>,
     pub pos: usize,
+    pub expr_positions: Vec<Pos>, // 완성한 식의 위치 (완성한 순서대로)
+    pub stmt_positions: Vec<Pos>, // 완성한 문장의 위치 (완성한 순서대로)
 }
 
 impl Parser {
     pub fn new(tokens: Vec<SpannedToken>) -> Self {
-        Parser { tokens, pos: 0 }
+        Parser {
+            tokens,
+            pos: 0,
+            expr_positions: Vec::new(),
+            stmt_positions: Vec::new(),
+        }
     }
 
-    /// program ::= function_definition*
+    /// program ::= ( function | declaration_statement )*
     pub fn parse_program(&mut self) -> ParseResult<Program> {
         let mut functions = Vec::new();
+        let mut globals = Vec::new();
+        let mut order = Vec::new();
         while self.current_token() != &Token::EOF {
-            // 토큰이 'int'|'char'|'void' 시작이면 함수 정의
-            let func = self.parse_function()?;
-            functions.push(func);
+            // 타입 뒤에 `이름 (` 이 오면 함수, 아니면 전역 변수 선언
+            if self.is_function_ahead() {
+                order.push(ExternalDecl::Function(functions.len()));
+                functions.push(self.parse_function()?);
+            } else {
+                order.push(ExternalDecl::Global(globals.len()));
+                let pos = self.start_pos();
+                let global = self.parse_declaration_statement()?;
+                globals.push(self.stmt_at(pos, global));
+            }
+        }
+        Ok(Program {
+            functions,
+            globals,
+            order,
+            expr_positions: std::mem::take(&mut self.expr_positions),
+            stmt_positions: std::mem::take(&mut self.stmt_positions),
+        })
+    }
+
+    /// 저장 클래스/한정자/기본 타입/'*' 를 건너뛴 뒤 `identifier "("` 인지 확인
+    fn is_function_ahead(&self) -> bool {
+        let mut offset = 0;
+        while matches!(
+            self.token_at(offset),
+            Token::Static
+                | Token::Extern
+                | Token::Const
+                | Token::Volatile
+                | Token::Int
+                | Token::Char
+                | Token::Void
+                | Token::Float
+                | Token::Double
+                | Token::Asterisk
+        ) {
+            offset += 1;
         }
-        Ok(Program { functions })
+        matches!(self.token_at(offset), Token::Ident(_))
+            && self.token_at(offset + 1) == &Token::LParen
     }
 }
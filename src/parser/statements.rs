@@ -1,6 +1,6 @@
-use crate::ast::Stmt;
 use crate::ast::Stmt::{Break, For, Return, While};
 use crate::ast::stmt::{Block, Declarator};
+use crate::ast::{Stmt, TypeSpecifier};
 use crate::lexer::token::Token;
 use crate::parser::Parser;
 use crate::parser::parser::ParseResult;
@@ -16,6 +16,7 @@ impl Parser {
     /// | declaration_statement
     /// | expression_statement
     pub fn parse_statement(&mut self) -> ParseResult<Stmt> {
+        let pos = self.start_pos();
         let stmt = match self.current_token() {
             Token::LBrace => {
                 let block = self.parse_block_statement()?;
@@ -27,11 +28,19 @@ impl Parser {
             Token::Return => self.parse_return_statement()?,
             Token::Break => self.parse_break_statement()?,
             Token::Continue => self.parse_continue_statement()?,
-            Token::Int | Token::Char => self.parse_declaration_statement()?,
+            Token::Int
+            | Token::Char
+            | Token::Void
+            | Token::Float
+            | Token::Double
+            | Token::Const
+            | Token::Volatile
+            | Token::Static
+            | Token::Extern => self.parse_declaration_statement()?,
             _ => self.parse_expression_statement()?,
         };
 
-        Ok(stmt)
+        Ok(self.stmt_at(pos, stmt))
     }
 
     /// expression_statement ::= expression? ";"
@@ -44,58 +53,49 @@ impl Parser {
         Ok(Stmt::ExprStmt(expr))
     }
 
-    /// declaration_statement ::= type_specifier init_declarator_list ";"
-    fn parse_declaration_statement(&mut self) -> ParseResult<Stmt> {
-        let ty = self.parse_type_specifier()?;
-        let declarators = self.parse_init_declarator_list()?;
+    /// declaration_statement ::= storage_class? base_type init_declarator_list ";"
+    pub fn parse_declaration_statement(&mut self) -> ParseResult<Stmt> {
+        let storage = self.parse_storage_class();
+        let ty = self.parse_base_type()?;
+        let declarators = self.parse_init_declarator_list(&ty)?;
         self.expect(Token::Semicolon)?;
-        Ok(Stmt::Declaration { ty, declarators })
+        Ok(Stmt::Declaration {
+            storage,
+            ty,
+            declarators,
+        })
     }
 
     /// init_declarator_list ::= init_declarator ( "," init_declarator )*
-    fn parse_init_declarator_list(&mut self) -> ParseResult<Vec<Declarator>> {
+    fn parse_init_declarator_list(&mut self, base: &TypeSpecifier) -> ParseResult<Vec<Declarator>> {
         let mut list = Vec::new();
         // 첫 번째 선언자는 반드시 있어야 함
-        list.push(self.parse_init_declarator()?);
+        list.push(self.parse_init_declarator(base)?);
 
         // 콤마로 이어지는 추가 선언자들
         while self.current_token() == &Token::Comma {
             self.next_token(); // ','
-            list.push(self.parse_init_declarator()?);
+            list.push(self.parse_init_declarator(base)?);
         }
         Ok(list)
     }
 
     /// init_declarator ::= declarator ( "=" initializer )?
-    fn parse_init_declarator(&mut self) -> ParseResult<Declarator> {
+    fn parse_init_declarator(&mut self, base: &TypeSpecifier) -> ParseResult<Declarator> {
         // 1) 선언자 파싱
-        let mut decl = self.parse_declarator()?;
+        let (name, pos, ty) = self.parse_declarator(base.clone())?;
         // 2) 선택적 초기화
-        if self.current_token() == &Token::Assign {
+        let init = if self.current_token() == &Token::Assign {
             self.next_token(); // '='
-            let init_expr = self.parse_initializer()?;
-            decl.init = Some(init_expr);
-        }
-        Ok(decl)
-    }
-
-    /// declarator ::= identifier ( "[" int_literal "]" )?
-    fn parse_declarator(&mut self) -> ParseResult<Declarator> {
-        // 1) 이름
-        let name = self.expect_ident()?;
-        // 2) 선택적 배열 첨자
-        let array_size = if self.current_token() == &Token::LBracket {
-            self.next_token(); // '['
-            let sz = self.expect_int_literal()?;
-            self.expect(Token::RBracket)?; // ']'
-            Some(sz)
+            Some(self.parse_initializer()?)
         } else {
             None
         };
         Ok(Declarator {
             name,
-            array_size,
-            init: None,
+            ty,
+            init,
+            pos,
         })
     }
 
@@ -170,18 +170,26 @@ impl Parser {
         self.expect(Token::LParen)?; // '(' 소비
 
         // init
+        let init_pos = self.start_pos();
         let init = match self.current_token() {
             Token::Semicolon => {
                 self.expect(Token::Semicolon)?; // ';' 소비
                 None
             }
-            Token::Void | Token::Int | Token::Char => {
-                Some(Box::new(self.parse_declaration_statement()?))
+            Token::Void
+            | Token::Int
+            | Token::Char
+            | Token::Float
+            | Token::Double
+            | Token::Const
+            | Token::Volatile => {
+                let decl = self.parse_declaration_statement()?;
+                Some(Box::new(self.stmt_at(init_pos, decl)))
             }
             _ => {
                 let e = self.parse_expr()?;
                 self.expect(Token::Semicolon)?;
-                Some(Box::new(Stmt::ExprStmt(Some(e))))
+                Some(Box::new(self.stmt_at(init_pos, Stmt::ExprStmt(Some(e)))))
             }
         };
 
@@ -1,4 +1,5 @@
 use crate::ast::program::Pos;
+use crate::ast::{Expr, Stmt};
 use crate::lexer::token::Token;
 use crate::parser::Parser;
 use crate::parser::parser::ParseResult;
@@ -41,8 +42,7 @@ impl Parser {
     }
 
     pub fn current_token(&self) -> &Token {
-        &self
-            .tokens
+        self.tokens
             .get(self.pos)
             .map(|st| &st.kind)
             .unwrap_or(&Token::EOF)
@@ -57,13 +57,20 @@ impl Parser {
     }
 
     pub fn peek_token(&self) -> &Token {
-        &self
-            .tokens
+        self.tokens
             .get(self.pos + 1)
             .map(|st| &st.kind)
             .unwrap_or(&Token::EOF)
     }
 
+    /// 현재 위치에서 offset 만큼 떨어진 토큰
+    pub fn token_at(&self, offset: usize) -> &Token {
+        self.tokens
+            .get(self.pos + offset)
+            .map(|st| &st.kind)
+            .unwrap_or(&Token::EOF)
+    }
+
     pub fn next_token(&mut self) {
         if self.pos < self.tokens.len() {
             self.pos += 1;
@@ -87,6 +94,18 @@ impl Parser {
         Pos::new(st.line, st.column)
     }
 
+    /// 완성한 식의 위치를 기록 (식은 자식부터 완성되므로 후위 순회 순서)
+    pub fn expr_at(&mut self, pos: Pos, expr: Expr) -> Expr {
+        self.expr_positions.push(pos);
+        expr
+    }
+
+    /// 완성한 문장의 위치를 기록 (식과 같이 후위 순회 순서)
+    pub fn stmt_at(&mut self, pos: Pos, stmt: Stmt) -> Stmt {
+        self.stmt_positions.push(pos);
+        stmt
+    }
+
     /// 직전에 소비된 토큰의 위치
     pub fn end_pos(&self) -> Pos {
         let idx = self.pos.saturating_sub(1);
@@ -1,28 +1,106 @@
 use crate::ast::Program;
-use crate::semantic::analyzer::SemanticError;
+use crate::semantic::analyzer::{SemanticError, SemanticWarning};
+use crate::semantic::control_flow::ControlFlow;
+use crate::semantic::definite_init::DefiniteInit;
+use crate::semantic::entry_point::{EntryPoint, check_entry_point};
+use crate::semantic::lint::LintConfig;
+use crate::semantic::lint::unused::check_unused;
+use crate::semantic::model::SemanticModel;
 use crate::semantic::resolver::resolver::Resolver;
 use crate::semantic::type_checker::type_checker::TypeChecker;
+use crate::semantic::value_range::ValueRange;
+use std::collections::HashSet;
 
 pub type AnalyzeResult<T> = Result<T, SemanticError>;
 
 pub struct Analyzer<'a> {
     pub program: &'a Program,
+    pub warnings: Vec<SemanticWarning>, // 켜진 경고만 남음
+    pub lints: LintConfig,
+    pub entry: EntryPoint, // main 검사 수준
+    pub prelude: bool,     // 라이브러리 함수 프로토타입을 암시적으로 선언
 }
 
 impl<'a> Analyzer<'a> {
     pub fn new(program: &'a Program) -> Self {
-        Analyzer { program }
+        Analyzer {
+            program,
+            warnings: Vec::new(),
+            lints: LintConfig::default(),
+            entry: EntryPoint::default(),
+            prelude: true,
+        }
     }
 
-    pub fn analyze(&mut self) -> AnalyzeResult<()> {
-        // 이름 해석
+    /// 이름 해석과 타입 검사를 마친 의미 모델 반환
+    /// (두 작업은 같은 스코프 트리를 쓰며 트리를 한 번만 순회)
+    pub fn analyze(&mut self) -> AnalyzeResult<SemanticModel<'a>> {
         let mut resolver = Resolver::new();
-        resolver.resolve_program(self.program)?;
-
-        // 타입 검사
+        if self.prelude {
+            resolver.declare_prelude();
+        }
         let mut tc = TypeChecker::new(&mut resolver);
         tc.check_program(self.program)?;
+        self.warnings.append(&mut tc.warnings);
+        check_entry_point(&tc.resolver.table, self.entry)?;
+
+        // 함수별 제어 흐름 분석 (반환 누락, 도달 불가 코드)
+        for func in &self.program.functions {
+            self.warnings.extend(ControlFlow::new(func).check());
+        }
+
+        let mut model = std::mem::take(&mut tc.model);
+
+        // 스코프 트리와 def-use 색인, 링커 입력
+        model.symbols = std::mem::take(&mut resolver.table);
+        model.externals = std::mem::take(&mut resolver.externals);
+
+        // 지역 변수 확정 초기화 분석 (def-use 색인 사용)
+        let mut definite_init = DefiniteInit::new(&model);
+        for func in &self.program.functions {
+            definite_init.check(func);
+        }
+        self.warnings.append(&mut definite_init.warnings);
+
+        // 정수 값 범위 분석 (0 나누기, int 오버플로, 배열 범위)
+        // 타입 검사의 for 루프 범위 검사와 상수 오버플로 경고가 이미 다룬 식은 건너뜀
+        let mut value_range = ValueRange::new(&model);
+        for func in &self.program.functions {
+            value_range.check(func);
+        }
+        let reported: HashSet<_> = self
+            .warnings
+            .iter()
+            .filter_map(|warning| match warning {
+                SemanticWarning::ArrayBounds { expr, .. }
+                | SemanticWarning::ConstantOverflow { expr, .. } => Some(*expr),
+                _ => None,
+            })
+            .collect();
+        self.warnings.extend(
+            value_range
+                .warnings
+                .into_iter()
+                .filter(|warning| match warning {
+                    SemanticWarning::IndexOutOfRange { expr, .. }
+                    | SemanticWarning::SignedOverflow { expr, .. } => !reported.contains(expr),
+                    _ => true,
+                }),
+        );
+
+        // 미사용 이름, 가려진 변수
+        self.warnings.extend(check_unused(self.program, &model));
+
+        // 꺼진 경고를 버리고 -Werror 면 남은 경고를 에러로
+        let lints = &self.lints;
+        self.warnings
+            .retain(|warning| lints.is_enabled(warning.lint()));
+        if lints.werror && !self.warnings.is_empty() {
+            return Err(SemanticError::WarningsAsErrors {
+                count: self.warnings.len(),
+            });
+        }
 
-        Ok(())
+        Ok(model)
     }
 }
@@ -1,4 +1,6 @@
 use crate::ast::TypeSpecifier;
+use crate::consteval::ConstError;
+use crate::semantic::symbol::error::SymbolError;
 
 #[derive(Debug)]
 pub enum SemanticError {
@@ -28,4 +30,107 @@ pub enum SemanticError {
     ExpectedPointer {
         found: TypeSpecifier,
     },
+    NotCallable {
+        found: TypeSpecifier,
+    },
+    AssignToConst {
+        found: TypeSpecifier,
+    },
+    NotAnLvalue {
+        found: TypeSpecifier,
+    },
+    AssignToArray {
+        found: TypeSpecifier,
+    },
+    InvalidPointerArithmetic {
+        found: TypeSpecifier,
+    },
+    LinkageConflict {
+        name: String,
+    },
+    ExternInitializer {
+        name: String,
+    },
+    Redefinition {
+        name: String,
+    },
+    UndefinedFunction {
+        name: String,
+    },
+    MissingReturnValue {
+        expected: TypeSpecifier,
+    },
+    // 정적 저장 기간 변수의 초기화식이 상수식이 아님 (0 으로 나누기 포함)
+    ConstantExpression {
+        name: String,
+        error: ConstError,
+    },
+    // 배열이 담을 수 있는 것보다 많은 초기화 원소 (ty 는 초기화하던 배열)
+    TooManyInitializers {
+        ty: TypeSpecifier,
+    },
+    // 스칼라를 중괄호 목록으로 초기화
+    ScalarBraceInitializer {
+        ty: TypeSpecifier,
+    },
+    // 초기화식 밖의 중괄호 목록
+    UnexpectedInitializerList,
+    // 길이를 아는 배열을 범위 밖 상수 인덱스로 접근 (a[10], a[-1])
+    ArrayIndexOutOfBounds {
+        index: i32,
+        length: usize,
+    },
+    // 크기를 정할 수 없는 배열 (int a[]; 또는 int a[] = {};)
+    IncompleteArray {
+        name: String,
+    },
+    // void x; 처럼 void 타입(또는 void 원소 배열)의 변수
+    VoidObject {
+        name: String,
+    },
+    // int f(int, void) 처럼 (void) 외의 자리에 쓴 void 매개변수
+    VoidParameter {
+        function: String,
+    },
+    // 1 + f() 처럼 void 식의 값을 사용
+    VoidValueUsed,
+    // *p, p[i] 에서 p 가 void*
+    VoidPointerDereference,
+    // 진입점 main 이 함수가 아님
+    MainNotFunction,
+    // static int main(...)
+    StaticMain,
+    // int main(void), int main(int, char **) 가 아닌 main
+    InvalidMainSignature {
+        found: TypeSpecifier,
+    },
+    // 함수 배열 int a[3](int), 배열/함수를 반환하는 함수 int f(void)[3] 처럼 만들 수 없는 타입
+    InvalidDerivedType {
+        name: String,
+        ty: TypeSpecifier,
+    },
+    // int h(int) = 0; 처럼 함수 선언에 초기화식
+    FunctionInitializer {
+        name: String,
+    },
+    // 블록 안 static 함수 선언 (static int h(int);)
+    StaticLocalFunction {
+        name: String,
+    },
+    // -Werror: 남은 경고 수
+    WarningsAsErrors {
+        count: usize,
+    },
+}
+
+impl From<SymbolError> for SemanticError {
+    fn from(err: SymbolError) -> Self {
+        match err {
+            SymbolError::DuplicateDeclaration { name } => {
+                SemanticError::DuplicateDeclaration { name }
+            }
+            SymbolError::LinkageConflict { name } => SemanticError::LinkageConflict { name },
+            SymbolError::Redefinition { name } => SemanticError::Redefinition { name },
+        }
+    }
 }
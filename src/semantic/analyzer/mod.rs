@@ -1,5 +1,7 @@
 mod analyzer;
 pub mod error;
+pub mod warning;
 pub use analyzer::AnalyzeResult;
 pub use analyzer::Analyzer;
 pub use error::SemanticError;
+pub use warning::SemanticWarning;
@@ -0,0 +1,204 @@
+use crate::ast::expr::BinaryOp;
+use crate::ast::{DeclId, ExprId, Pos, SourceMap, StmtId, TypeSpecifier};
+use crate::semantic::type_checker::type_name;
+
+/// 분석을 멈추지 않는 경고
+#[derive(Debug, Clone, PartialEq)]
+pub enum SemanticWarning {
+    // 포인터 변환에서 가리키는 타입의 const/volatile 이 사라짐
+    DiscardedQualifiers {
+        expr: ExprId,
+        expected: TypeSpecifier,
+        found: TypeSpecifier,
+    },
+    // control reaches end of non-void function
+    MissingReturn {
+        function: String,
+        decl: DeclId,
+    },
+    // return / break / continue 뒤 등 도달할 수 없는 구간의 첫 문장
+    UnreachableCode {
+        function: String,
+        stmt: StmtId,
+    },
+    // variable may be used uninitialized: 선언 위치와 처음 읽은 위치
+    MaybeUninitialized {
+        name: String,
+        decl: DeclId,
+        expr: ExprId,
+    },
+    // 한 번도 참조되지 않은 지역 변수
+    UnusedVariable {
+        name: String,
+        decl: DeclId,
+    },
+    UnusedParameter {
+        name: String,
+        decl: DeclId,
+    },
+    // 정의만 되고 호출(참조)되지 않은 static 함수
+    UnusedFunction {
+        name: String,
+        decl: DeclId,
+    },
+    // 바깥 스코프의 변수를 가리는 선언
+    Shadow {
+        name: String,
+        decl: DeclId,
+        shadowed: DeclId,
+    },
+    // if (x = y): == 를 의도했을 가능성
+    AssignmentInCondition {
+        expr: ExprId,
+    },
+    // for 루프 변수의 범위 [min, max] 가 인덱싱하는 배열의 길이를 벗어남
+    ArrayBounds {
+        expr: ExprId,
+        length: usize,
+        min: i64,
+        max: i64,
+    },
+    // 값 범위 분석: 인덱스가 가질 수 있는 범위 [min, max] 가 배열 길이를 벗어남
+    // (None 은 그쪽 한계를 모름)
+    IndexOutOfRange {
+        expr: ExprId,
+        length: usize,
+        min: Option<i64>,
+        max: Option<i64>,
+    },
+    // 값 범위 분석: 나누는 수(/, %)의 범위에 0 이 들어 있음
+    MaybeDivisionByZero {
+        expr: ExprId,
+    },
+    // 값 범위 분석: int 덧셈·곱셈 결과의 범위 [min, max] 가 int 를 넘을 수 있음
+    SignedOverflow {
+        expr: ExprId,
+        op: BinaryOp,
+        min: Option<i64>,
+        max: Option<i64>,
+    },
+    // printf/scanf 형식 지정자와 맞지 않는 인자 (expr 는 인자)
+    FormatMismatch {
+        expr: ExprId,
+        directive: String,
+        expected: String,
+        found: TypeSpecifier,
+    },
+    // 형식 문자열이 소비하는 인자 수와 넘긴 인자 수가 다름 (expr 는 호출)
+    FormatArgumentCount {
+        expr: ExprId,
+        expected: usize,
+        found: usize,
+    },
+    // 알 수 없는 변환 지정자 (expr 는 형식 문자열)
+    InvalidFormat {
+        expr: ExprId,
+        directive: String,
+    },
+    // 상수식이 32 비트 int 범위를 넘어 감싼 값으로 평가됨
+    ConstantOverflow {
+        expr: ExprId,
+        wrapped: i32,
+    },
+}
+
+impl SemanticWarning {
+    /// 경고가 가리키는 원본 위치 (파서를 거치지 않은 노드면 None)
+    pub fn pos(&self, map: &SourceMap) -> Option<Pos> {
+        use SemanticWarning::*;
+        match self {
+            UnreachableCode { stmt, .. } => map.stmt(*stmt),
+            MissingReturn { decl, .. }
+            | UnusedFunction { decl, .. }
+            | UnusedVariable { decl, .. }
+            | UnusedParameter { decl, .. }
+            | Shadow { decl, .. } => map.decl(*decl),
+            DiscardedQualifiers { expr, .. }
+            | MaybeUninitialized { expr, .. }
+            | AssignmentInCondition { expr }
+            | ArrayBounds { expr, .. }
+            | IndexOutOfRange { expr, .. }
+            | MaybeDivisionByZero { expr }
+            | SignedOverflow { expr, .. }
+            | FormatMismatch { expr, .. }
+            | FormatArgumentCount { expr, .. }
+            | InvalidFormat { expr, .. }
+            | ConstantOverflow { expr, .. } => map.expr(*expr),
+        }
+    }
+
+    /// 사용자에게 보여 줄 메시지 (다른 선언을 가리키면 그 위치를 붙임)
+    pub fn message(&self, map: &SourceMap) -> String {
+        use SemanticWarning::*;
+        let at = |decl: &DeclId| match map.decl(*decl) {
+            Some(pos) => format!(" (declared at {pos})"),
+            None => String::new(),
+        };
+        let range = |min: &Option<i64>, max: &Option<i64>| {
+            let bound = |b: &Option<i64>| b.map_or("?".to_string(), |b| b.to_string());
+            format!("[{}, {}]", bound(min), bound(max))
+        };
+        match self {
+            DiscardedQualifiers {
+                expected, found, ..
+            } => format!(
+                "conversion from '{}' to '{}' discards qualifiers",
+                type_name(found),
+                type_name(expected)
+            ),
+            MissingReturn { function, .. } => {
+                format!("control reaches end of non-void function '{function}'")
+            }
+            UnreachableCode { function, .. } => format!("unreachable code in '{function}'"),
+            MaybeUninitialized { name, decl, .. } => {
+                format!("'{name}' may be used uninitialized{}", at(decl))
+            }
+            UnusedVariable { name, .. } => format!("unused variable '{name}'"),
+            UnusedParameter { name, .. } => format!("unused parameter '{name}'"),
+            UnusedFunction { name, .. } => format!("'{name}' defined but not used"),
+            Shadow { name, shadowed, .. } => {
+                format!(
+                    "declaration of '{name}' shadows a previous declaration{}",
+                    at(shadowed)
+                )
+            }
+            AssignmentInCondition { .. } => {
+                "suggest parentheses around assignment used as truth value".to_string()
+            }
+            ArrayBounds {
+                length, min, max, ..
+            } => format!("loop index range [{min}, {max}] is outside array of {length} elements"),
+            IndexOutOfRange {
+                length, min, max, ..
+            } => format!(
+                "index range {} may be outside array of {length} elements",
+                range(min, max)
+            ),
+            MaybeDivisionByZero { .. } => "divisor may be zero".to_string(),
+            SignedOverflow { op, min, max, .. } => format!(
+                "result range {} of {op:?} may overflow int",
+                range(min, max)
+            ),
+            FormatMismatch {
+                directive,
+                expected,
+                found,
+                ..
+            } => {
+                format!(
+                    "format '{directive}' expects {expected}, but argument has type '{}'",
+                    type_name(found)
+                )
+            }
+            FormatArgumentCount {
+                expected, found, ..
+            } => format!("format expects {expected} arguments, but {found} were given"),
+            InvalidFormat { directive, .. } => {
+                format!("unknown conversion type '{directive}' in format")
+            }
+            ConstantOverflow { wrapped, .. } => {
+                format!("integer overflow in expression; result is {wrapped}")
+            }
+        }
+    }
+}
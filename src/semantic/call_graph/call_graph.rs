@@ -0,0 +1,512 @@
+use crate::ast::{Expr, Program, Stmt};
+use crate::semantic::model::SemanticModel;
+use crate::semantic::symbol::symbol::SymbolKind;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+/// 호출 한 번마다 프레임 밖에 쌓이는 크기: 반환 주소 + 저장된 프레임 포인터
+pub const CALL_OVERHEAD: usize = 16;
+
+/// 호출 그래프의 함수
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionNode {
+    pub name: String,
+    pub frame_size: Option<usize>, // 이 단위에 본문이 없는 함수(라이브러리, 다른 단위)는 None
+    pub recursive: bool,           // 자기 자신으로 돌아오는 호출 경로가 있음
+}
+
+/// 호출 관계 (중복 없음)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CallEdge {
+    pub caller: usize,
+    pub callee: usize,
+    pub indirect: bool, // 함수 포인터를 통한 호출: 주소가 쓰인 모든 함수를 대상으로 봄
+}
+
+/// main 에서 시작하는 가장 깊은 호출 사슬
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackDepth {
+    pub bytes: usize,
+    pub path: Vec<String>,
+    // 재귀 함수에 도달할 수 있어 실제 깊이는 상한이 없음
+    // (bytes 는 재귀 호출을 한 번씩만 센 값)
+    pub unbounded: bool,
+}
+
+/// 번역 단위의 호출 그래프
+///
+/// Expr::Call 위치에서 호출 관계를 모으고, 재귀(강한 연결 요소)와
+/// 프레임 크기로 최악의 스택 깊이를 계산
+#[derive(Debug, Default)]
+pub struct CallGraph {
+    pub functions: Vec<FunctionNode>, // 선언 순서, 본문 없는 호출 대상은 뒤에
+    pub calls: Vec<CallEdge>,         // (호출자, 대상) 순으로 정렬
+    index: HashMap<String, usize>,
+    component: Vec<usize>, // 함수별 강한 연결 요소 번호
+}
+
+impl CallGraph {
+    pub fn new(program: &Program, model: &SemanticModel<'_>) -> Self {
+        let mut graph = CallGraph::default();
+        for func in program.functions.iter().filter(|f| f.body.is_some()) {
+            let frame_size = model.frame(&func.name).map(|frame| frame.size);
+            let id = graph.node(&func.name);
+            graph.functions[id].frame_size = frame_size;
+        }
+
+        // 직접 호출과 함수 포인터 호출, 주소가 쓰인 함수 수집
+        let mut sites = Vec::new();
+        let mut address_taken = BTreeSet::new();
+        // 전역 초기화식의 함수 이름 (int (*op)(int) = twice;)
+        let mut globals = CallScan {
+            model,
+            calls: Vec::new(),
+            indirect: false,
+            address_taken: &mut address_taken,
+        };
+        for global in &program.globals {
+            globals.stmt(global);
+        }
+        for func in &program.functions {
+            let Some(body) = &func.body else {
+                continue;
+            };
+            let mut scan = CallScan {
+                model,
+                calls: Vec::new(),
+                indirect: false,
+                address_taken: &mut address_taken,
+            };
+            for stmt in &body.statements {
+                scan.stmt(stmt);
+            }
+            sites.push((func.name.clone(), scan.calls, scan.indirect));
+        }
+
+        let mut calls = BTreeSet::new();
+        for (caller, callees, indirect) in sites {
+            let caller = graph.index[&caller];
+            for callee in callees {
+                let callee = graph.node(&callee);
+                calls.insert(CallEdge {
+                    caller,
+                    callee,
+                    indirect: false,
+                });
+            }
+            if indirect {
+                for name in &address_taken {
+                    let callee = graph.node(name);
+                    if !calls.contains(&CallEdge {
+                        caller,
+                        callee,
+                        indirect: false,
+                    }) {
+                        calls.insert(CallEdge {
+                            caller,
+                            callee,
+                            indirect: true,
+                        });
+                    }
+                }
+            }
+        }
+        graph.calls = calls.into_iter().collect();
+        graph.find_recursion();
+        graph
+    }
+
+    fn node(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.index.get(name) {
+            return id;
+        }
+        let id = self.functions.len();
+        self.functions.push(FunctionNode {
+            name: name.to_string(),
+            frame_size: None,
+            recursive: false,
+        });
+        self.index.insert(name.to_string(), id);
+        id
+    }
+
+    pub fn function(&self, name: &str) -> Option<&FunctionNode> {
+        self.index.get(name).map(|&id| &self.functions[id])
+    }
+
+    /// 함수가 호출하는 함수 이름 (간접 호출 대상 포함)
+    pub fn callees(&self, name: &str) -> Vec<&str> {
+        let Some(&id) = self.index.get(name) else {
+            return Vec::new();
+        };
+        self.successors(id)
+            .map(|edge| self.functions[edge.callee].name.as_str())
+            .collect()
+    }
+
+    /// calls 는 호출자 순으로 정렬되어 있으므로 구간만 잘라 냄
+    fn successors(&self, id: usize) -> impl Iterator<Item = &CallEdge> {
+        let start = self.calls.partition_point(|edge| edge.caller < id);
+        let end = self.calls.partition_point(|edge| edge.caller <= id);
+        self.calls[start..end].iter()
+    }
+
+    /// 재귀 묶음: 자기 호출(직접 재귀)은 한 함수, 상호 재귀는 여러 함수
+    pub fn recursion(&self) -> Vec<Vec<String>> {
+        let mut groups: Vec<Vec<String>> = Vec::new();
+        let mut seen = HashMap::new();
+        for (id, func) in self.functions.iter().enumerate() {
+            if !func.recursive {
+                continue;
+            }
+            let group = *seen.entry(self.component[id]).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(func.name.clone());
+        }
+        groups
+    }
+
+    /// Tarjan 알고리즘으로 강한 연결 요소를 구해 재귀 함수 표시
+    fn find_recursion(&mut self) {
+        let n = self.functions.len();
+        let mut tarjan = Tarjan {
+            graph: self,
+            order: vec![None; n],
+            low: vec![0; n],
+            stack: Vec::new(),
+            on_stack: vec![false; n],
+            counter: 0,
+            component: vec![0; n],
+            components: 0,
+        };
+        for id in 0..n {
+            if tarjan.order[id].is_none() {
+                tarjan.visit(id);
+            }
+        }
+        self.component = tarjan.component;
+
+        let mut sizes = HashMap::new();
+        for &c in &self.component {
+            *sizes.entry(c).or_insert(0) += 1;
+        }
+        for id in 0..n {
+            let self_call = self.successors(id).any(|edge| edge.callee == id);
+            self.functions[id].recursive = self_call || sizes[&self.component[id]] > 1;
+        }
+    }
+
+    /// entry 에서 시작하는 가장 깊은 호출 사슬의 스택 사용량
+    /// (호출마다 프레임 + CALL_OVERHEAD, 본문 없는 함수는 CALL_OVERHEAD 만)
+    pub fn max_stack_depth(&self, entry: &str) -> Option<StackDepth> {
+        let &start = self.index.get(entry)?;
+        let mut memo = HashMap::new();
+        let (bytes, path) = self.deepest(start, &mut memo);
+        let unbounded = self.reachable(start).any(|id| self.functions[id].recursive);
+        Some(StackDepth {
+            bytes,
+            path: path
+                .into_iter()
+                .map(|id| self.functions[id].name.clone())
+                .collect(),
+            unbounded,
+        })
+    }
+
+    /// 응축 그래프(DAG) 위의 최장 경로: 재귀 묶음은 구성원 프레임을 한 번씩 모두 더하고
+    /// 묶음의 어느 구성원에서든 묶음 밖으로 나가는 호출 중 가장 깊은 것을 이어 붙임
+    /// (경로는 들어온 함수, 나머지 구성원, 가장 깊은 바깥 호출 순)
+    fn deepest(
+        &self,
+        id: usize,
+        memo: &mut HashMap<usize, (usize, Vec<usize>)>,
+    ) -> (usize, Vec<usize>) {
+        let component = self.component[id];
+        let members: Vec<usize> = (0..self.functions.len())
+            .filter(|&member| self.component[member] == component)
+            .collect();
+        let own: usize = members
+            .iter()
+            .map(|&member| self.functions[member].frame_size.unwrap_or(0) + CALL_OVERHEAD)
+            .sum();
+
+        let exit = match memo.get(&component) {
+            Some(exit) => exit.clone(),
+            None => {
+                let callees: Vec<usize> = members
+                    .iter()
+                    .flat_map(|&member| self.successors(member))
+                    .filter(|edge| self.component[edge.callee] != component)
+                    .map(|edge| edge.callee)
+                    .collect();
+                let mut best = (0, Vec::new());
+                for callee in callees {
+                    let candidate = self.deepest(callee, memo);
+                    if candidate.0 > best.0 {
+                        best = candidate;
+                    }
+                }
+                memo.insert(component, best.clone());
+                best
+            }
+        };
+
+        let mut path = vec![id];
+        path.extend(members.into_iter().filter(|&member| member != id));
+        path.extend(exit.1);
+        (own + exit.0, path)
+    }
+
+    fn reachable(&self, start: usize) -> impl Iterator<Item = usize> {
+        let mut seen = vec![false; self.functions.len()];
+        let mut stack = vec![start];
+        while let Some(id) = stack.pop() {
+            if std::mem::replace(&mut seen[id], true) {
+                continue;
+            }
+            stack.extend(self.successors(id).map(|edge| edge.callee));
+        }
+        seen.into_iter()
+            .enumerate()
+            .filter_map(|(id, seen)| seen.then_some(id))
+    }
+
+    /// 사람이 읽는 요약: 함수별 프레임과 호출, 재귀, entry 에서의 최악 스택 깊이
+    pub fn to_text(&self, entry: &str) -> String {
+        let mut out = String::new();
+        out.push_str("functions:\n");
+        for (id, func) in self.functions.iter().enumerate() {
+            let frame = match func.frame_size {
+                Some(size) => format!("frame {size} bytes"),
+                None => "external".to_string(),
+            };
+            let callees: Vec<String> = self
+                .successors(id)
+                .map(|edge| {
+                    let name = &self.functions[edge.callee].name;
+                    if edge.indirect {
+                        format!("{name} (indirect)")
+                    } else {
+                        name.clone()
+                    }
+                })
+                .collect();
+            let _ = write!(out, "  {}: {frame}", func.name);
+            if !callees.is_empty() {
+                let _ = write!(out, ", calls {}", callees.join(", "));
+            }
+            out.push('\n');
+        }
+
+        let recursion = self.recursion();
+        if !recursion.is_empty() {
+            out.push_str("recursion:\n");
+            for group in recursion {
+                let kind = if group.len() == 1 { "direct" } else { "mutual" };
+                let _ = writeln!(out, "  {} ({kind})", group.join(" <-> "));
+            }
+        }
+
+        match self.max_stack_depth(entry) {
+            Some(depth) => {
+                let _ = write!(
+                    out,
+                    "stack depth from {entry}: {} bytes ({})",
+                    depth.bytes,
+                    depth.path.join(" -> ")
+                );
+                if depth.unbounded {
+                    out.push_str(", unbounded because of recursion");
+                }
+                out.push('\n');
+            }
+            None => {
+                let _ = writeln!(out, "stack depth from {entry}: no such function");
+            }
+        }
+        out
+    }
+
+    /// Graphviz DOT: 재귀 함수는 빨간색, 본문 없는 함수는 점선 상자, 간접 호출은 점선
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph call_graph {\n    node [shape=ellipse];\n");
+        for func in &self.functions {
+            let mut attrs = match func.frame_size {
+                Some(size) => format!("label=\"{}\\n{size} B\"", func.name),
+                None => format!("label=\"{}\", shape=box, style=dashed", func.name),
+            };
+            if func.recursive {
+                attrs.push_str(", color=red");
+            }
+            let _ = writeln!(out, "    \"{}\" [{attrs}];", func.name);
+        }
+        for edge in &self.calls {
+            let style = if edge.indirect { " [style=dashed]" } else { "" };
+            let _ = writeln!(
+                out,
+                "    \"{}\" -> \"{}\"{style};",
+                self.functions[edge.caller].name, self.functions[edge.callee].name
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+struct Tarjan<'g> {
+    graph: &'g CallGraph,
+    order: Vec<Option<usize>>,
+    low: Vec<usize>,
+    stack: Vec<usize>,
+    on_stack: Vec<bool>,
+    counter: usize,
+    component: Vec<usize>,
+    components: usize,
+}
+
+impl Tarjan<'_> {
+    fn visit(&mut self, id: usize) {
+        self.order[id] = Some(self.counter);
+        self.low[id] = self.counter;
+        self.counter += 1;
+        self.stack.push(id);
+        self.on_stack[id] = true;
+
+        let callees: Vec<usize> = self.graph.successors(id).map(|e| e.callee).collect();
+        for callee in callees {
+            match self.order[callee] {
+                None => {
+                    self.visit(callee);
+                    self.low[id] = self.low[id].min(self.low[callee]);
+                }
+                Some(order) if self.on_stack[callee] => {
+                    self.low[id] = self.low[id].min(order);
+                }
+                Some(_) => {}
+            }
+        }
+
+        if Some(self.low[id]) == self.order[id] {
+            while let Some(member) = self.stack.pop() {
+                self.on_stack[member] = false;
+                self.component[member] = self.components;
+                if member == id {
+                    break;
+                }
+            }
+            self.components += 1;
+        }
+    }
+}
+
+/// 함수 본문의 호출 위치 수집
+struct CallScan<'m, 's> {
+    model: &'m SemanticModel<'m>,
+    calls: Vec<String>,                      // 이름으로 직접 호출한 함수
+    indirect: bool,                          // 함수 포인터를 통한 호출이 있음
+    address_taken: &'s mut BTreeSet<String>, // 호출 외의 자리에서 이름이 쓰인 함수
+}
+
+impl CallScan<'_, '_> {
+    /// 함수를 가리키는 식별자면 그 함수 이름
+    fn function_name(&self, expr: &Expr) -> Option<String> {
+        let id = self.model.symbol_of(expr)?;
+        let symbol = self.model.symbol(id);
+        matches!(symbol.kind, SymbolKind::Function { .. }).then(|| symbol.name.clone())
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Declaration { declarators, .. } => {
+                for init in declarators.iter().filter_map(|d| d.init.as_ref()) {
+                    self.expr(init);
+                }
+            }
+            Stmt::ExprStmt(Some(expr)) | Stmt::Return(Some(expr)) => self.expr(expr),
+            Stmt::Block(block) => {
+                for stmt in &block.statements {
+                    self.stmt(stmt);
+                }
+            }
+            Stmt::If {
+                cond,
+                then_branch,
+                else_branch,
+            } => {
+                self.expr(cond);
+                self.stmt(then_branch);
+                if let Some(else_branch) = else_branch {
+                    self.stmt(else_branch);
+                }
+            }
+            Stmt::While { cond, body } => {
+                self.expr(cond);
+                self.stmt(body);
+            }
+            Stmt::For {
+                init,
+                cond,
+                step,
+                body,
+            } => {
+                if let Some(init) = init {
+                    self.stmt(init);
+                }
+                for expr in cond.iter().chain(step) {
+                    self.expr(expr);
+                }
+                self.stmt(body);
+            }
+            Stmt::ExprStmt(None) | Stmt::Return(None) | Stmt::Break | Stmt::Continue => {}
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Call { func, args } => {
+                match self.function_name(func) {
+                    Some(name) if matches!(func.as_ref(), Expr::Ident(_)) => self.calls.push(name),
+                    _ => {
+                        self.indirect = true;
+                        self.expr(func);
+                    }
+                }
+                for arg in args {
+                    self.expr(arg);
+                }
+            }
+            Expr::Ident(_) => {
+                if let Some(name) = self.function_name(expr) {
+                    self.address_taken.insert(name);
+                }
+            }
+            Expr::UnaryPrefixOp { rhs, .. } => self.expr(rhs),
+            Expr::UnaryPostfixOp { lhs, .. } => self.expr(lhs),
+            Expr::BinaryOp { lhs, rhs, .. }
+            | Expr::Assignment {
+                left: lhs,
+                right: rhs,
+                ..
+            }
+            | Expr::ArrayIndex {
+                array: lhs,
+                index: rhs,
+            } => {
+                self.expr(lhs);
+                self.expr(rhs);
+            }
+            Expr::InitializerList(items) => {
+                for item in items {
+                    self.expr(item);
+                }
+            }
+            Expr::IntLiteral(_)
+            | Expr::FloatLiteral(_)
+            | Expr::DoubleLiteral(_)
+            | Expr::CharLiteral(_)
+            | Expr::StringLiteral(_) => {}
+        }
+    }
+}
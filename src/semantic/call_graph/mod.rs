@@ -0,0 +1,3 @@
+pub mod call_graph;
+
+pub use call_graph::{CALL_OVERHEAD, CallEdge, CallGraph, FunctionNode, StackDepth};
@@ -0,0 +1,122 @@
+use crate::ast::{Expr, Function, Stmt, TypeSpecifier};
+use crate::consteval::char_value;
+use crate::semantic::analyzer::SemanticWarning;
+
+/// 함수 하나의 제어 흐름 분석
+/// - 반환값 없이 끝에 도달할 수 있는 non-void 함수 (main 은 0 을 반환하므로 제외)
+/// - return / break / continue 뒤처럼 도달할 수 없는 문장
+///
+/// 각 문장에 "들어올 수 있는지"를 넘기고 "끝까지 흘러 나가는지"를 돌려받는 구조적 분석
+pub struct ControlFlow<'a> {
+    function: &'a Function,
+    loops: Vec<bool>, // 감싸는 반복문마다 도달 가능한 break 가 있는지
+    silenced: usize,  // 이미 경고한 도달 불가 영역 안에서는 다시 경고하지 않음
+    pub warnings: Vec<SemanticWarning>,
+}
+
+impl<'a> ControlFlow<'a> {
+    pub fn new(function: &'a Function) -> Self {
+        ControlFlow {
+            function,
+            loops: Vec::new(),
+            silenced: 0,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn check(mut self) -> Vec<SemanticWarning> {
+        let Some(body) = &self.function.body else {
+            return self.warnings;
+        };
+        let falls_through = self.check_sequence(&body.statements, true);
+        if falls_through
+            && *self.function.return_ty.unqualified() != TypeSpecifier::Void
+            && self.function.name != "main"
+        {
+            self.warnings.push(SemanticWarning::MissingReturn {
+                function: self.function.name.clone(),
+                decl: self.function.id(),
+            });
+        }
+        self.warnings
+    }
+
+    /// 문장 목록: 도달할 수 없는 구간의 첫 문장에만 경고
+    fn check_sequence(&mut self, stmts: &[Stmt], mut reachable: bool) -> bool {
+        let mut warned = false;
+        for stmt in stmts {
+            if !reachable && !warned {
+                warned = true;
+                if self.silenced == 0 {
+                    self.warnings.push(SemanticWarning::UnreachableCode {
+                        function: self.function.name.clone(),
+                        stmt: stmt.id(),
+                    });
+                }
+                self.silenced += 1;
+            }
+            reachable = self.check_stmt(stmt, reachable);
+        }
+        if warned {
+            self.silenced -= 1;
+        }
+        reachable
+    }
+
+    /// reachable: 문장 시작에 도달할 수 있는지, 반환: 문장 끝을 지나 다음 문장으로 흐르는지
+    fn check_stmt(&mut self, stmt: &Stmt, reachable: bool) -> bool {
+        match stmt {
+            Stmt::Block(block) => self.check_sequence(&block.statements, reachable),
+            Stmt::If {
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                let then_flows = self.check_stmt(then_branch, reachable);
+                let else_flows = match else_branch {
+                    Some(else_branch) => self.check_stmt(else_branch, reachable),
+                    None => reachable,
+                };
+                then_flows || else_flows
+            }
+            Stmt::While { cond, body } => self.check_loop(Some(cond), body, reachable),
+            Stmt::For {
+                init, cond, body, ..
+            } => {
+                let reachable = match init {
+                    Some(init) => self.check_stmt(init, reachable),
+                    None => reachable,
+                };
+                self.check_loop(cond.as_ref(), body, reachable)
+            }
+            Stmt::Break => {
+                if reachable && let Some(has_break) = self.loops.last_mut() {
+                    *has_break = true;
+                }
+                false
+            }
+            Stmt::Return(_) | Stmt::Continue => false,
+            Stmt::Declaration { .. } | Stmt::ExprStmt(_) => reachable,
+        }
+    }
+
+    /// 반복문은 조건이 항상 참이면(while (1), for (;;)) break 로만 빠져나감
+    fn check_loop(&mut self, cond: Option<&Expr>, body: &Stmt, reachable: bool) -> bool {
+        self.loops.push(false);
+        self.check_stmt(body, reachable);
+        let has_break = self.loops.pop().unwrap_or(false);
+        let infinite = cond.is_none_or(is_always_true);
+        reachable && (!infinite || has_break)
+    }
+}
+
+/// 항상 참인 상수 조건
+pub fn is_always_true(cond: &Expr) -> bool {
+    match cond {
+        Expr::IntLiteral(n) => *n != 0,
+        Expr::CharLiteral(c) => char_value(*c) != 0,
+        Expr::FloatLiteral(f) => *f != 0.0,
+        Expr::DoubleLiteral(d) => *d != 0.0,
+        _ => false,
+    }
+}
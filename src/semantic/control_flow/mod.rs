@@ -0,0 +1,3 @@
+pub mod control_flow;
+
+pub use control_flow::ControlFlow;
@@ -0,0 +1,421 @@
+use crate::ast::expr::{AssignOp, BinaryOp, PrefixOp};
+use crate::ast::{DeclId, Expr, Function, Stmt, TypeSpecifier};
+use crate::semantic::analyzer::SemanticWarning;
+use crate::semantic::control_flow::control_flow::is_always_true;
+use crate::semantic::model::SemanticModel;
+use crate::semantic::symbol::symbol::{Linkage, SymbolId};
+use std::collections::{HashMap, HashSet};
+
+/// 초기화되지 않았을 수 있는 변수 집합 (분기 합류점에서 합집합)
+type State = HashSet<SymbolId>;
+
+/// 함수 하나의 확정 초기화(definite initialization) 분석
+///
+/// 초기화식 없는 자동 지역 스칼라 변수를 추적하며, 어떤 경로에서든 대입 전에 값을 읽으면 경고
+/// - 배열과 주소가 한 번이라도 취해진 변수(&x)는 포인터로 초기화될 수 있으므로 추적하지 않음
+/// - static 지역 변수는 0 으로 초기화되고, extern 지역 변수는 다른 곳의 정의를 가리킴
+/// - 반복문은 상태가 더 이상 커지지 않을 때까지 본문을 반복 분석한 뒤 경고
+pub struct DefiniteInit<'a> {
+    model: &'a SemanticModel<'a>,
+    locals: HashMap<DeclId, SymbolId>, // 추적 후보: 링크 없는 변수 선언
+    escaped: HashSet<SymbolId>,        // 주소가 취해진 변수
+    loops: Vec<LoopExits>,
+    reporting: bool, // 반복문 고정점 계산 중에는 경고하지 않음
+    reported: HashSet<SymbolId>,
+    pub warnings: Vec<SemanticWarning>,
+}
+
+/// 반복문을 빠져나가거나(break) 다음 반복으로 가는(continue) 경로의 상태
+#[derive(Default)]
+struct LoopExits {
+    breaks: State,
+    continues: State,
+}
+
+impl<'a> DefiniteInit<'a> {
+    pub fn new(model: &'a SemanticModel<'a>) -> Self {
+        let locals = model
+            .symbols
+            .symbols
+            .iter()
+            .enumerate()
+            .filter(|(_, symbol)| symbol.linkage == Linkage::None)
+            .map(|(index, symbol)| (symbol.decl, SymbolId(index)))
+            .collect();
+        DefiniteInit {
+            model,
+            locals,
+            escaped: HashSet::new(),
+            loops: Vec::new(),
+            reporting: true,
+            reported: HashSet::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// 함수 하나를 분석해 경고를 warnings 에 추가
+    pub fn check(&mut self, function: &Function) {
+        let Some(body) = &function.body else {
+            return;
+        };
+        self.escaped = escaped_variables(self.model, &body.statements);
+        let mut state = State::new();
+        for stmt in &body.statements {
+            self.check_stmt(stmt, &mut state);
+        }
+    }
+
+    fn check_stmt(&mut self, stmt: &Stmt, state: &mut State) {
+        match stmt {
+            Stmt::Declaration {
+                storage,
+                declarators,
+                ..
+            } => {
+                for d in declarators {
+                    match &d.init {
+                        Some(init) => self.check_expr(init, state),
+                        None if storage.is_none() && !matches!(d.ty, TypeSpecifier::Array(..)) => {
+                            if let Some(&id) = self.locals.get(&d.id())
+                                && !self.escaped.contains(&id)
+                            {
+                                state.insert(id);
+                            }
+                        }
+                        None => {}
+                    }
+                }
+            }
+            Stmt::ExprStmt(expr) => {
+                if let Some(expr) = expr {
+                    self.check_expr(expr, state);
+                }
+            }
+            Stmt::Return(expr) => {
+                if let Some(expr) = expr {
+                    self.check_expr(expr, state);
+                }
+                // 이후 문장은 도달 불가: 합류점에서 영향 없음
+                state.clear();
+            }
+            Stmt::Break => {
+                if let Some(exits) = self.loops.last_mut() {
+                    exits.breaks.extend(state.drain());
+                }
+            }
+            Stmt::Continue => {
+                if let Some(exits) = self.loops.last_mut() {
+                    exits.continues.extend(state.drain());
+                }
+            }
+            Stmt::Block(block) => {
+                for stmt in &block.statements {
+                    self.check_stmt(stmt, state);
+                }
+            }
+            Stmt::If {
+                cond,
+                then_branch,
+                else_branch,
+            } => {
+                let (then_state, mut else_state) = self.check_cond(cond, std::mem::take(state));
+                *state = then_state;
+                self.check_stmt(then_branch, state);
+                if let Some(else_branch) = else_branch {
+                    self.check_stmt(else_branch, &mut else_state);
+                }
+                state.extend(else_state);
+            }
+            Stmt::While { cond, body } => self.check_loop(Some(cond), None, body, state),
+            Stmt::For {
+                init,
+                cond,
+                step,
+                body,
+            } => {
+                if let Some(init) = init {
+                    self.check_stmt(init, state);
+                }
+                self.check_loop(cond.as_ref(), step.as_ref(), body, state);
+            }
+        }
+    }
+
+    /// 반복문 머리의 상태 = 진입 상태 ∪ 본문 끝(continue 포함)에서 돌아오는 상태
+    fn check_loop(
+        &mut self,
+        cond: Option<&Expr>,
+        step: Option<&Expr>,
+        body: &Stmt,
+        state: &mut State,
+    ) {
+        let reporting = self.reporting;
+        self.reporting = false;
+        let mut head = state.clone();
+        let exit = loop {
+            let mut current = head.clone();
+            let exit = self.check_iteration(cond, step, body, &mut current);
+            let before = head.len();
+            head.extend(current);
+            if head.len() == before {
+                break exit;
+            }
+        };
+        self.reporting = reporting;
+        if reporting {
+            // 고정점 상태로 한 번 더 분석하며 경고
+            let mut current = head;
+            self.check_iteration(cond, step, body, &mut current);
+        }
+        *state = exit;
+    }
+
+    /// 조건 -> 본문 -> 증감식 한 바퀴. state 는 다음 반복 머리로 가는 상태가 되고
+    /// 반복문을 빠져나가는 상태(조건 거짓, break)를 반환
+    fn check_iteration(
+        &mut self,
+        cond: Option<&Expr>,
+        step: Option<&Expr>,
+        body: &Stmt,
+        state: &mut State,
+    ) -> State {
+        let mut exit = match cond {
+            Some(cond) => {
+                let (taken, not_taken) = self.check_cond(cond, std::mem::take(state));
+                *state = taken;
+                if is_always_true(cond) {
+                    State::new()
+                } else {
+                    not_taken
+                }
+            }
+            None => State::new(),
+        };
+        self.loops.push(LoopExits::default());
+        self.check_stmt(body, state);
+        let exits = self.loops.pop().unwrap_or_default();
+        state.extend(exits.continues);
+        if let Some(step) = step {
+            self.check_expr(step, state);
+        }
+        exit.extend(exits.breaks);
+        exit
+    }
+
+    /// 식을 평가 순서대로 따라가며 읽기를 검사하고 대입으로 초기화된 변수를 제거
+    fn check_expr(&mut self, expr: &Expr, state: &mut State) {
+        match expr {
+            Expr::Ident(_) => self.check_read(expr, state),
+            Expr::Assignment {
+                left, op, right, ..
+            } => {
+                self.check_expr(right, state);
+                match (left.as_ref(), op) {
+                    (Expr::Ident(_), AssignOp::Assign) => {
+                        if let Some(id) = self.model.symbol_of(left) {
+                            state.remove(&id);
+                        }
+                    }
+                    // x += y 는 x 를 읽음
+                    _ => self.check_expr(left, state),
+                }
+            }
+            // 오른쪽은 조건부로 평가되므로 그 안의 대입은 보장되지 않음
+            Expr::BinaryOp {
+                op: BinaryOp::And | BinaryOp::Or,
+                ..
+            } => {
+                let (taken, not_taken) = self.check_cond(expr, std::mem::take(state));
+                *state = taken;
+                state.extend(not_taken);
+            }
+            Expr::BinaryOp { lhs, rhs, .. } => {
+                self.check_expr(lhs, state);
+                self.check_expr(rhs, state);
+            }
+            // &x 의 x 는 추적 대상이 아님
+            Expr::UnaryPrefixOp {
+                op: PrefixOp::Address,
+                rhs,
+            } if matches!(rhs.as_ref(), Expr::Ident(_)) => {}
+            Expr::UnaryPrefixOp { rhs, .. } => self.check_expr(rhs, state),
+            Expr::UnaryPostfixOp { lhs, .. } => self.check_expr(lhs, state),
+            Expr::Call { func, args } => {
+                self.check_expr(func, state);
+                for arg in args {
+                    self.check_expr(arg, state);
+                }
+            }
+            Expr::ArrayIndex { array, index } => {
+                self.check_expr(array, state);
+                self.check_expr(index, state);
+            }
+            Expr::InitializerList(items) => {
+                for item in items {
+                    self.check_expr(item, state);
+                }
+            }
+            Expr::IntLiteral(_)
+            | Expr::FloatLiteral(_)
+            | Expr::DoubleLiteral(_)
+            | Expr::CharLiteral(_)
+            | Expr::StringLiteral(_) => {}
+        }
+    }
+
+    /// 조건을 평가한 뒤 참인 경로와 거짓인 경로의 상태
+    /// (a && b 의 b 는 a 가 참일 때만, a || b 의 b 는 a 가 거짓일 때만 평가됨)
+    fn check_cond(&mut self, cond: &Expr, mut state: State) -> (State, State) {
+        match cond {
+            Expr::UnaryPrefixOp {
+                op: PrefixOp::Not,
+                rhs,
+            } => {
+                let (taken, not_taken) = self.check_cond(rhs, state);
+                (not_taken, taken)
+            }
+            Expr::BinaryOp {
+                lhs,
+                op: BinaryOp::And,
+                rhs,
+            } => {
+                let (lhs_true, mut lhs_false) = self.check_cond(lhs, state);
+                let (rhs_true, rhs_false) = self.check_cond(rhs, lhs_true);
+                lhs_false.extend(rhs_false);
+                (rhs_true, lhs_false)
+            }
+            Expr::BinaryOp {
+                lhs,
+                op: BinaryOp::Or,
+                rhs,
+            } => {
+                let (mut lhs_true, lhs_false) = self.check_cond(lhs, state);
+                let (rhs_true, rhs_false) = self.check_cond(rhs, lhs_false);
+                lhs_true.extend(rhs_true);
+                (lhs_true, rhs_false)
+            }
+            _ => {
+                self.check_expr(cond, &mut state);
+                (state.clone(), state)
+            }
+        }
+    }
+
+    fn check_read(&mut self, expr: &Expr, state: &State) {
+        let Some(id) = self.model.symbol_of(expr) else {
+            return;
+        };
+        if !self.reporting || !state.contains(&id) || !self.reported.insert(id) {
+            return;
+        }
+        let symbol = self.model.symbol(id);
+        self.warnings.push(SemanticWarning::MaybeUninitialized {
+            name: symbol.name.clone(),
+            decl: symbol.decl,
+            expr: expr.id(),
+        });
+    }
+}
+
+/// 함수 본문에서 주소가 취해진(&x) 변수 집합
+/// 포인터를 통해 값이 바뀔 수 있으므로 흐름 분석에서 추적하지 않음
+pub(crate) fn escaped_variables(model: &SemanticModel<'_>, body: &[Stmt]) -> HashSet<SymbolId> {
+    let mut escaped = HashSet::new();
+    for stmt in body {
+        collect_escaped_stmt(model, stmt, &mut escaped);
+    }
+    escaped
+}
+
+fn collect_escaped_stmt(model: &SemanticModel<'_>, stmt: &Stmt, escaped: &mut HashSet<SymbolId>) {
+    match stmt {
+        Stmt::Declaration { declarators, .. } => {
+            for d in declarators.iter().filter_map(|d| d.init.as_ref()) {
+                collect_escaped_expr(model, d, escaped);
+            }
+        }
+        Stmt::ExprStmt(Some(expr)) | Stmt::Return(Some(expr)) => {
+            collect_escaped_expr(model, expr, escaped)
+        }
+        Stmt::Block(block) => {
+            for stmt in &block.statements {
+                collect_escaped_stmt(model, stmt, escaped);
+            }
+        }
+        Stmt::If {
+            cond,
+            then_branch,
+            else_branch,
+        } => {
+            collect_escaped_expr(model, cond, escaped);
+            collect_escaped_stmt(model, then_branch, escaped);
+            if let Some(else_branch) = else_branch {
+                collect_escaped_stmt(model, else_branch, escaped);
+            }
+        }
+        Stmt::While { cond, body } => {
+            collect_escaped_expr(model, cond, escaped);
+            collect_escaped_stmt(model, body, escaped);
+        }
+        Stmt::For {
+            init,
+            cond,
+            step,
+            body,
+        } => {
+            if let Some(init) = init {
+                collect_escaped_stmt(model, init, escaped);
+            }
+            for expr in cond.iter().chain(step) {
+                collect_escaped_expr(model, expr, escaped);
+            }
+            collect_escaped_stmt(model, body, escaped);
+        }
+        Stmt::ExprStmt(None) | Stmt::Return(None) | Stmt::Break | Stmt::Continue => {}
+    }
+}
+
+fn collect_escaped_expr(model: &SemanticModel<'_>, expr: &Expr, escaped: &mut HashSet<SymbolId>) {
+    match expr {
+        Expr::UnaryPrefixOp {
+            op: PrefixOp::Address,
+            rhs,
+        } if matches!(rhs.as_ref(), Expr::Ident(_)) => {
+            if let Some(id) = model.symbol_of(rhs) {
+                escaped.insert(id);
+            }
+        }
+        Expr::UnaryPrefixOp { rhs, .. } => collect_escaped_expr(model, rhs, escaped),
+        Expr::UnaryPostfixOp { lhs, .. } => collect_escaped_expr(model, lhs, escaped),
+        Expr::BinaryOp { lhs, rhs, .. }
+        | Expr::Assignment {
+            left: lhs,
+            right: rhs,
+            ..
+        }
+        | Expr::ArrayIndex {
+            array: lhs,
+            index: rhs,
+        } => {
+            collect_escaped_expr(model, lhs, escaped);
+            collect_escaped_expr(model, rhs, escaped);
+        }
+        Expr::Call { func, args } => {
+            collect_escaped_expr(model, func, escaped);
+            for arg in args {
+                collect_escaped_expr(model, arg, escaped);
+            }
+        }
+        Expr::InitializerList(items) => {
+            for item in items {
+                collect_escaped_expr(model, item, escaped);
+            }
+        }
+        Expr::Ident(_)
+        | Expr::IntLiteral(_)
+        | Expr::FloatLiteral(_)
+        | Expr::DoubleLiteral(_)
+        | Expr::CharLiteral(_)
+        | Expr::StringLiteral(_) => {}
+    }
+}
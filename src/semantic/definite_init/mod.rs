@@ -0,0 +1,3 @@
+pub mod definite_init;
+
+pub use definite_init::DefiniteInit;
@@ -0,0 +1,67 @@
+use crate::ast::TypeSpecifier;
+use crate::semantic::analyzer::AnalyzeResult;
+use crate::semantic::analyzer::SemanticError::*;
+use crate::semantic::symbol::symbol::{Linkage, SymbolKind, SymbolTable};
+
+/// 진입점(main) 검사 수준
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntryPoint {
+    // 라이브러리 등 진입점이 없는 프로그램: 검사하지 않음
+    None,
+    // int main(void), int main(int, char **) 와 흔한 확장 int main(int, char **, char **)
+    #[default]
+    Hosted,
+    // 표준의 두 형태만 허용
+    Strict,
+}
+
+impl EntryPoint {
+    pub fn from_name(name: &str) -> Option<EntryPoint> {
+        match name {
+            "none" => Some(EntryPoint::None),
+            "hosted" => Some(EntryPoint::Hosted),
+            "strict" => Some(EntryPoint::Strict),
+            _ => None,
+        }
+    }
+}
+
+/// 번역 단위에 main 이 있으면 외부 링크 함수이고 시그니처가 올바른지 검사
+/// (main 이 어느 단위에 정의되었는지는 링커가 검사)
+pub fn check_entry_point(table: &SymbolTable, mode: EntryPoint) -> AnalyzeResult<()> {
+    if mode == EntryPoint::None {
+        return Ok(());
+    }
+    let Some(main) = table.lookup_global("main") else {
+        return Ok(());
+    };
+    let SymbolKind::Function {
+        param_types,
+        is_variadic,
+    } = &main.kind
+    else {
+        return Err(MainNotFunction);
+    };
+    if main.linkage != Linkage::External {
+        return Err(StaticMain);
+    }
+
+    let argv = TypeSpecifier::Pointer(Box::new(TypeSpecifier::Pointer(Box::new(
+        TypeSpecifier::Char,
+    ))));
+    // 매개변수 타입은 이미 포인터로 조정되고 최상위 한정자가 제거된 상태
+    let valid_params = match param_types.as_slice() {
+        [] => true,
+        [argc, v] => *argc == TypeSpecifier::Int && *v == argv,
+        [argc, v, envp] => {
+            mode == EntryPoint::Hosted && *argc == TypeSpecifier::Int && *v == argv && *envp == argv
+        }
+        _ => false,
+    };
+    if main.ty != TypeSpecifier::Int || *is_variadic || !valid_params {
+        return Err(InvalidMainSignature {
+            found: main.value_ty(),
+        });
+    }
+    Ok(())
+}
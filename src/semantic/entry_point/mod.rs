@@ -0,0 +1,3 @@
+pub mod entry_point;
+
+pub use entry_point::{EntryPoint, check_entry_point};
@@ -0,0 +1,5 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum LintError {
+    UnknownLint { name: String },
+    UnknownFlag { flag: String },
+}
@@ -0,0 +1,158 @@
+use crate::semantic::analyzer::SemanticWarning;
+use crate::semantic::lint::error::LintError;
+use std::collections::BTreeSet;
+
+/// 이름 있는 경고 종류 (-W<name>, -Wno-<name> 으로 켜고 끔)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Lint {
+    DiscardedQualifiers,
+    ReturnType,
+    UnreachableCode,
+    MaybeUninitialized,
+    UnusedVariable,
+    UnusedParameter,
+    UnusedFunction,
+    Shadow,
+    Parentheses,
+    Overflow,
+    ArrayBounds,
+    Format,
+    DivisionByZero,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 13] = [
+        Lint::DiscardedQualifiers,
+        Lint::ReturnType,
+        Lint::UnreachableCode,
+        Lint::MaybeUninitialized,
+        Lint::UnusedVariable,
+        Lint::UnusedParameter,
+        Lint::UnusedFunction,
+        Lint::Shadow,
+        Lint::Parentheses,
+        Lint::Overflow,
+        Lint::ArrayBounds,
+        Lint::Format,
+        Lint::DivisionByZero,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Lint::DiscardedQualifiers => "discarded-qualifiers",
+            Lint::ReturnType => "return-type",
+            Lint::UnreachableCode => "unreachable-code",
+            Lint::MaybeUninitialized => "maybe-uninitialized",
+            Lint::UnusedVariable => "unused-variable",
+            Lint::UnusedParameter => "unused-parameter",
+            Lint::UnusedFunction => "unused-function",
+            Lint::Shadow => "shadow",
+            Lint::Parentheses => "parentheses",
+            Lint::Overflow => "overflow",
+            Lint::ArrayBounds => "array-bounds",
+            Lint::Format => "format",
+            Lint::DivisionByZero => "div-by-zero",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.into_iter().find(|lint| lint.name() == name)
+    }
+
+    /// 기본으로 켜지는지 (-Wall 이 켜는 묶음과 같음: gcc 처럼 unused-parameter, shadow 는 빠짐)
+    pub fn enabled_by_default(self) -> bool {
+        !matches!(self, Lint::UnusedParameter | Lint::Shadow)
+    }
+
+    /// -Wextra 가 -Wall 묶음에 더해 켜는지 (shadow 는 -Wshadow 로만)
+    pub fn enabled_by_extra(self) -> bool {
+        self.enabled_by_default() || self == Lint::UnusedParameter
+    }
+}
+
+impl SemanticWarning {
+    pub fn lint(&self) -> Lint {
+        match self {
+            SemanticWarning::DiscardedQualifiers { .. } => Lint::DiscardedQualifiers,
+            SemanticWarning::MissingReturn { .. } => Lint::ReturnType,
+            SemanticWarning::UnreachableCode { .. } => Lint::UnreachableCode,
+            SemanticWarning::MaybeUninitialized { .. } => Lint::MaybeUninitialized,
+            SemanticWarning::UnusedVariable { .. } => Lint::UnusedVariable,
+            SemanticWarning::UnusedParameter { .. } => Lint::UnusedParameter,
+            SemanticWarning::UnusedFunction { .. } => Lint::UnusedFunction,
+            SemanticWarning::Shadow { .. } => Lint::Shadow,
+            SemanticWarning::AssignmentInCondition { .. } => Lint::Parentheses,
+            SemanticWarning::ConstantOverflow { .. } | SemanticWarning::SignedOverflow { .. } => {
+                Lint::Overflow
+            }
+            SemanticWarning::ArrayBounds { .. } | SemanticWarning::IndexOutOfRange { .. } => {
+                Lint::ArrayBounds
+            }
+            SemanticWarning::MaybeDivisionByZero { .. } => Lint::DivisionByZero,
+            SemanticWarning::FormatMismatch { .. }
+            | SemanticWarning::FormatArgumentCount { .. }
+            | SemanticWarning::InvalidFormat { .. } => Lint::Format,
+        }
+    }
+}
+
+/// 켜진 경고 목록과 -Werror 여부
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintConfig {
+    pub enabled: BTreeSet<Lint>,
+    pub werror: bool, // 남은 경고가 하나라도 있으면 분석 실패
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        LintConfig {
+            enabled: Lint::ALL
+                .into_iter()
+                .filter(|lint| lint.enabled_by_default())
+                .collect(),
+            werror: false,
+        }
+    }
+}
+
+impl LintConfig {
+    pub fn is_enabled(&self, lint: Lint) -> bool {
+        self.enabled.contains(&lint)
+    }
+
+    /// 명령행 경고 옵션 적용
+    /// -Wall (기본 묶음), -Wextra (+ unused-parameter), -Werror, -W<name>, -Wno-<name>
+    pub fn apply_flag(&mut self, flag: &str) -> Result<(), LintError> {
+        let Some(name) = flag.strip_prefix("-W") else {
+            return Err(LintError::UnknownFlag {
+                flag: flag.to_string(),
+            });
+        };
+        match name {
+            "all" => self.enable_where(Lint::enabled_by_default),
+            "extra" => self.enable_where(Lint::enabled_by_extra),
+            "error" => self.werror = true,
+            "no-error" => self.werror = false,
+            _ => {
+                let (name, enable) = match name.strip_prefix("no-") {
+                    Some(name) => (name, false),
+                    None => (name, true),
+                };
+                let lint = Lint::from_name(name).ok_or_else(|| LintError::UnknownLint {
+                    name: name.to_string(),
+                })?;
+                if enable {
+                    self.enabled.insert(lint);
+                } else {
+                    self.enabled.remove(&lint);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn enable_where(&mut self, included: fn(Lint) -> bool) {
+        self.enabled
+            .extend(Lint::ALL.into_iter().filter(|&lint| included(lint)));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod error;
+pub mod lint;
+pub mod unused;
+
+pub use error::LintError;
+pub use lint::{Lint, LintConfig};
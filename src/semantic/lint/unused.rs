@@ -0,0 +1,75 @@
+use crate::ast::{DeclId, Program};
+use crate::semantic::analyzer::SemanticWarning;
+use crate::semantic::model::SemanticModel;
+use crate::semantic::symbol::symbol::{Linkage, ScopeId, SymbolId, SymbolKind};
+use std::collections::HashSet;
+
+/// 스코프 트리와 def-use 색인으로 찾는 경고
+/// - 한 번도 참조되지 않은 지역 변수, 매개변수, static 함수
+/// - 바깥 스코프에서 먼저 선언된 변수를 가리는 지역 변수·매개변수
+pub fn check_unused(program: &Program, model: &SemanticModel<'_>) -> Vec<SemanticWarning> {
+    let params: HashSet<DeclId> = program
+        .functions
+        .iter()
+        .filter(|func| func.is_definition())
+        .flat_map(|func| func.params.iter().map(|param| param.id()))
+        .collect();
+
+    let table = &model.symbols;
+    let mut warnings = Vec::new();
+    for (index, symbol) in table.symbols.iter().enumerate() {
+        let id = SymbolId(index);
+        let unused = table.uses_of(id).is_empty();
+
+        if matches!(symbol.kind, SymbolKind::Function { .. }) {
+            if unused && symbol.linkage == Linkage::Internal && symbol.is_defined {
+                // 앞선 프로토타입이 있어도 정의 위치를 가리킴
+                let decl = program
+                    .functions
+                    .iter()
+                    .find(|func| func.is_definition() && func.name == symbol.name)
+                    .map_or(symbol.decl, |func| func.id());
+                warnings.push(SemanticWarning::UnusedFunction {
+                    name: symbol.name.clone(),
+                    decl,
+                });
+            }
+            continue;
+        }
+        if symbol.scope == ScopeId::GLOBAL {
+            continue;
+        }
+
+        // 선언 순서대로 SymbolId 가 붙으므로 더 작은 id 만 먼저 선언된 바깥 변수
+        let outer = table.scopes[symbol.scope.0]
+            .parent
+            .and_then(|parent| table.resolve_from(parent, &symbol.name))
+            .filter(|&outer| outer < id);
+        if let Some(outer) = outer {
+            let outer = table.symbol(outer);
+            if matches!(outer.kind, SymbolKind::Variable) {
+                warnings.push(SemanticWarning::Shadow {
+                    name: symbol.name.clone(),
+                    decl: symbol.decl,
+                    shadowed: outer.decl,
+                });
+            }
+        }
+
+        if unused && symbol.linkage == Linkage::None {
+            let name = symbol.name.clone();
+            warnings.push(if params.contains(&symbol.decl) {
+                SemanticWarning::UnusedParameter {
+                    name,
+                    decl: symbol.decl,
+                }
+            } else {
+                SemanticWarning::UnusedVariable {
+                    name,
+                    decl: symbol.decl,
+                }
+            });
+        }
+    }
+    warnings
+}
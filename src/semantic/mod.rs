@@ -1,6 +1,14 @@
 pub mod analyzer;
+pub mod call_graph;
+pub mod control_flow;
+pub mod definite_init;
+pub mod entry_point;
+pub mod lint;
+pub mod model;
 pub mod resolver;
 pub mod symbol;
 pub mod type_checker;
+pub mod value_range;
 
 pub use analyzer::Analyzer;
+pub use model::SemanticModel;
@@ -0,0 +1,163 @@
+use crate::ast::{DeclId, Expr, ExprId, Program, TypeSpecifier};
+use crate::semantic::symbol::symbol::{Symbol, SymbolId, SymbolTable};
+use crate::semantic::type_checker::conversion::ImplicitConversion;
+use crate::semantic::type_checker::value_category::ValueCategory;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+/// 의미 분석 결과: 코드 생성, 인터프리터, IDE 기능이 공통으로 쓰는 입력
+///
+/// ExprId/DeclId 는 노드 주소이므로 분석한 Program 을 빌리는 동안에만 쓸 수 있음 ('p)
+#[derive(Debug, Default)]
+pub struct SemanticModel<'p> {
+    pub exprs: HashMap<ExprId, ExprInfo>,     // 식별 타입, 값 범주
+    pub conversions: Vec<ImplicitConversion>, // 삽입된 암시적 변환 (검사 순서대로)
+    pub symbols: SymbolTable,                 // 스코프 트리, 심볼, def-use 색인
+    pub frames: HashMap<String, FrameLayout>, // 함수별 지역 변수 배치
+    pub constants: HashMap<ExprId, i32>,      // 정적 변수 초기화식 중 정수 상수식의 값
+    pub initializers: HashMap<DeclId, Initializer>, // 선언자별 펼친 초기화식
+    pub externals: Vec<String>,               // 다른 단위에서 찾아야 하는 이름
+    pub program: PhantomData<&'p Program>,
+}
+
+/// 식 하나의 분석 결과
+#[derive(Debug, Clone)]
+pub struct ExprInfo {
+    pub ty: TypeSpecifier, // decay 전 타입 (한정자 포함)
+    pub category: ValueCategory,
+}
+
+/// 펼친 초기화식: 변수 전체를 바이트 오프셋 순서대로 빈틈없이 덮음
+/// (중괄호 생략, 문자열 리터럴, 0 채우기가 모두 풀린 형태)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Initializer {
+    pub ty: TypeSpecifier, // 크기가 정해진 타입 (int a[] = {1, 2} 은 int[2])
+    pub elements: Vec<InitElement>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InitElement {
+    // 스칼라 원소 하나 (필요한 변환은 conversions 에 기록됨)
+    Value {
+        offset: usize,
+        ty: TypeSpecifier,
+        expr: ExprId,
+    },
+    // 문자열 리터럴로 채운 char 원소 ('\0' 포함)
+    Byte {
+        offset: usize,
+        value: u8,
+    },
+    // 0 으로 채우는 구간
+    Zero {
+        offset: usize,
+        size: usize,
+    },
+}
+
+impl InitElement {
+    pub fn offset(&self) -> usize {
+        match self {
+            InitElement::Value { offset, .. }
+            | InitElement::Byte { offset, .. }
+            | InitElement::Zero { offset, .. } => *offset,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        match self {
+            InitElement::Value { ty, .. } => ty.size().unwrap_or(0),
+            InitElement::Byte { .. } => 1,
+            InitElement::Zero { size, .. } => *size,
+        }
+    }
+}
+
+/// 함수 스택 프레임: 매개변수와 자동 지역 변수의 위치
+/// (static/extern 지역 변수는 프레임에 두지 않음)
+#[derive(Debug, Clone, Default)]
+pub struct FrameLayout {
+    pub slots: Vec<FrameSlot>,
+    pub size: usize, // 8 바이트 단위로 올림
+}
+
+#[derive(Debug, Clone)]
+pub struct FrameSlot {
+    pub decl: DeclId,
+    pub name: String,
+    pub ty: TypeSpecifier,
+    pub offset: usize, // 프레임 시작으로부터의 바이트 오프셋
+}
+
+impl SemanticModel<'_> {
+    pub fn expr(&self, expr: &Expr) -> Option<&ExprInfo> {
+        self.exprs.get(&expr.id())
+    }
+
+    pub fn type_of(&self, expr: &Expr) -> Option<&TypeSpecifier> {
+        self.expr(expr).map(|info| &info.ty)
+    }
+
+    /// 식의 값에 삽입된 암시적 변환 (적용 순서대로)
+    pub fn conversions_of(&self, expr: &Expr) -> impl Iterator<Item = &ImplicitConversion> {
+        let id = expr.id();
+        self.conversions.iter().filter(move |c| c.expr == id)
+    }
+
+    /// 식별자 식이 가리키는 심볼
+    pub fn symbol_of(&self, expr: &Expr) -> Option<SymbolId> {
+        self.symbols.symbol_of(expr.id())
+    }
+
+    pub fn symbol(&self, id: SymbolId) -> &Symbol {
+        self.symbols.symbol(id)
+    }
+
+    /// 식별자 식이 가리키는 선언 노드
+    pub fn declaration_of(&self, expr: &Expr) -> Option<DeclId> {
+        self.symbol_of(expr).map(|id| self.symbol(id).decl)
+    }
+
+    /// 심볼을 가리키는 식별자 사용들
+    pub fn references_to(&self, id: SymbolId) -> &[ExprId] {
+        self.symbols.uses_of(id)
+    }
+
+    /// 상수식으로 평가된 정적 변수 초기화식의 값
+    pub fn constant(&self, expr: &Expr) -> Option<i32> {
+        self.constants.get(&expr.id()).copied()
+    }
+
+    /// 선언자의 펼친 초기화식
+    pub fn initializer(&self, decl: DeclId) -> Option<&Initializer> {
+        self.initializers.get(&decl)
+    }
+
+    pub fn frame(&self, function: &str) -> Option<&FrameLayout> {
+        self.frames.get(function)
+    }
+}
+
+impl FrameLayout {
+    /// 타입의 정렬에 맞춰 다음 슬롯을 배치
+    pub fn allocate(&mut self, decl: DeclId, name: &str, ty: &TypeSpecifier) {
+        let align = ty.align().unwrap_or(1);
+        let offset = self.size.div_ceil(align) * align;
+        self.size = offset + ty.size().unwrap_or(0);
+        self.slots.push(FrameSlot {
+            decl,
+            name: name.to_string(),
+            ty: ty.clone(),
+            offset,
+        });
+    }
+
+    /// 프레임 전체 크기를 8 바이트 단위로 맞춤
+    pub fn finish(&mut self) {
+        self.size = self.size.div_ceil(8) * 8;
+    }
+
+    pub fn slot(&self, decl: DeclId) -> Option<&FrameSlot> {
+        self.slots.iter().find(|slot| slot.decl == decl)
+    }
+}
@@ -1,4 +1,5 @@
 pub mod error;
+pub mod prelude;
 pub mod resolver;
 pub mod utils;
 
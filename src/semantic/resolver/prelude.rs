@@ -0,0 +1,55 @@
+use crate::ast::{DeclId, Program};
+use crate::lexer::Lexer;
+use crate::parser::Parser;
+use crate::semantic::resolver::resolver::Resolver;
+use std::sync::LazyLock;
+
+/// 프로토타입 없이 쓸 수 있는 C 라이브러리 함수 (정의는 런타임이 제공)
+/// size_t 가 없으므로 크기는 int 로, FILE 이 없으므로 스트림은 void * 로 받음
+const PRELUDE_SOURCE: &str = r#"
+int putchar(int c);
+int getchar(void);
+int printf(const char *format, ...);
+int fprintf(void *stream, const char *format, ...);
+int sprintf(char *str, const char *format, ...);
+int snprintf(char *str, int size, const char *format, ...);
+int scanf(const char *format, ...);
+int fscanf(void *stream, const char *format, ...);
+int sscanf(const char *str, const char *format, ...);
+void *malloc(int size);
+void free(void *ptr);
+void exit(int status);
+"#;
+
+// 심볼의 DeclId 가 가리키는 노드이므로 프로그램이 끝날 때까지 유지
+static PRELUDE: LazyLock<Program> = LazyLock::new(|| {
+    let tokens = Lexer::new(PRELUDE_SOURCE).collect_spanned_tokens();
+    Parser::new(tokens)
+        .parse_program()
+        .expect("prelude 는 올바른 선언이어야 함")
+});
+
+/// 프렐류드 선언 (함수 프로토타입만 있음)
+pub fn prelude() -> &'static Program {
+    &PRELUDE
+}
+
+/// 런타임 라이브러리가 정의하는 함수인지
+pub fn is_runtime_function(name: &str) -> bool {
+    PRELUDE.functions.iter().any(|func| func.name == name)
+}
+
+/// 프렐류드의 선언 노드인지 (사용자 선언으로 대체 가능)
+pub fn is_prelude_declaration(decl: DeclId) -> bool {
+    PRELUDE.functions.iter().any(|func| func.id() == decl)
+}
+
+impl Resolver {
+    /// 프렐류드 함수들을 전역 스코프에 선언
+    /// (같은 시그니처로 다시 선언하면 합쳐지고, 다른 시그니처면 사용자 선언이 대체)
+    pub fn declare_prelude(&mut self) {
+        for func in &prelude().functions {
+            self.declare_function(func).expect("빈 전역 스코프에 선언");
+        }
+    }
+}
@@ -1,172 +1,56 @@
-use crate::ast::{Expr, Program, Stmt};
+use crate::ast::Expr;
 use crate::semantic::analyzer::AnalyzeResult;
 use crate::semantic::analyzer::error::SemanticError;
-use crate::semantic::symbol::symbol::SymbolTable;
+use crate::semantic::symbol::symbol::{Linkage, SymbolId, SymbolTable};
+use std::collections::{BTreeSet, HashSet};
 
+/// 이름 해석 상태: 스코프 트리와 선언 규칙
+///
+/// 트리를 따로 순회하지 않고, TypeChecker 가 노드를 방문하면서
+/// 선언(declare_*)과 참조(reference)를 이 구조체에 알림
 pub struct Resolver {
     pub table: SymbolTable,
     pub loop_depth: usize,
+    pub referenced_globals: BTreeSet<String>, // 식에서 참조된 링크 있는 이름 (함수, 전역 변수)
+    pub initialized_globals: HashSet<String>, // 초기화식으로 정의된 전역 변수
+    pub externals: Vec<String>,               // 참조되었지만 이 단위에 정의가 없는 이름
 }
 
 impl Resolver {
-    pub fn resolve_program(&mut self, prog: &Program) -> AnalyzeResult<()> {
-        // 함수 선언 등록
-        for func in &prog.functions {
-            self.declare_function(func)
-                .map_err(|_| SemanticError::DuplicateDeclaration {
-                    name: func.name.clone(),
+    /// 식별자 사용을 해석하고, 링커가 해결해야 할 수 있는 함수·전역 변수 참조 기록
+    pub fn reference(&mut self, expr: &Expr, name: &str) -> AnalyzeResult<SymbolId> {
+        let id =
+            self.resolve_identifier(expr, name)
+                .map_err(|_| SemanticError::UndefinedSymbol {
+                    name: name.to_string(),
                 })?;
+        if self.table.symbol(id).linkage != Linkage::None {
+            self.referenced_globals.insert(name.to_string());
         }
-        // 함수별 스코프·본문 검사
-        for func in &prog.functions {
-            self.push_scope();
-            for param in &func.params {
-                self.declare_variable(&param.name, &param.ty).map_err(|_| {
-                    SemanticError::DuplicateDeclaration {
-                        name: param.name.clone(),
-                    }
-                })?;
-            }
-            self.resolve_block(&Stmt::Block(func.body.clone()))?;
-            self.pop_scope();
-        }
-        Ok(())
+        Ok(id)
     }
 
-    fn resolve_block(&mut self, stmt: &Stmt) -> Result<(), SemanticError> {
-        if let Stmt::Block(block) = stmt {
-            for s in &block.statements {
-                self.resolve_stmt(s)?;
-            }
-        } else {
-            // println!("{:?}", stmt);
-            self.resolve_stmt(stmt)?;
+    /// break / continue 는 반복문 안에서만
+    pub fn check_jump(&self, error: SemanticError) -> AnalyzeResult<()> {
+        if self.loop_depth == 0 {
+            return Err(error);
         }
         Ok(())
     }
 
-    fn resolve_stmt(&mut self, stmt: &Stmt) -> Result<(), SemanticError> {
-        match stmt {
-            Stmt::ExprStmt(expr) => {
-                if let Some(expr_opt) = expr {
-                    self.resolve_expr(expr_opt)?
-                }
-            }
-            Stmt::If {
-                cond,
-                then_branch,
-                else_branch,
-            } => {
-                self.resolve_expr(cond)?;
-                self.resolve_block(then_branch)?;
-                if let Some(else_branch_opt) = else_branch {
-                    self.resolve_block(else_branch_opt)?;
-                }
-            }
-            Stmt::While { cond, body } => {
-                self.loop_depth += 1;
-                self.resolve_expr(cond)?;
-                self.resolve_stmt(body)?;
-                self.loop_depth -= 1;
-            }
-            Stmt::Return(expr) => {
-                if let Some(expr_opt) = expr {
-                    self.resolve_expr(expr_opt)?;
-                }
-            }
-            Stmt::Block(inner_stmts) => {
-                self.resolve_block(&Stmt::Block(inner_stmts.clone()))?;
-            }
-            Stmt::For {
-                init,
-                cond,
-                step,
-                body,
-            } => {
-                self.loop_depth += 1;
-                self.push_scope();
-                if let Some(init_stmt) = init {
-                    self.resolve_stmt(init_stmt)?;
-                }
-                if let Some(cond_expr) = cond {
-                    self.resolve_expr(cond_expr)?;
-                }
-                self.resolve_stmt(body)?;
-                if let Some(step_expr) = step {
-                    self.resolve_expr(step_expr)?;
-                }
-                self.pop_scope();
-                self.loop_depth -= 1;
-            }
-            Stmt::Declaration { ty, declarators } => {
-                for declarator in declarators {
-                    self.declare_variable(&declarator.name, ty).map_err(|_| {
-                        SemanticError::DuplicateDeclaration {
-                            name: declarator.name.clone(),
-                        }
-                    })?;
-                    if let Some(init_expr) = &declarator.init {
-                        self.resolve_expr(&init_expr)?;
-                    }
-                }
-            }
-            Stmt::Continue => {
-                if self.loop_depth == 0 {
-                    return Err(SemanticError::InvalidContinue);
-                }
-            }
-            Stmt::Break => {
-                if self.loop_depth == 0 {
-                    return Err(SemanticError::InvalidBreak);
-                }
-            }
-        }
-        Ok(())
-    }
-
-    fn resolve_expr(&mut self, expr: &Expr) -> Result<(), SemanticError> {
-        match expr {
-            Expr::Ident(name) => {
-                self.resolve_identifier(name)
-                    .map_err(|_| SemanticError::UndefinedSymbol { name: name.clone() })?;
-            }
-            Expr::BinaryOp { lhs, rhs, .. } => {
-                self.resolve_expr(lhs)?;
-                self.resolve_expr(rhs)?;
-            }
-            Expr::UnaryPostfixOp { lhs, .. } => {
-                self.resolve_expr(lhs)?;
-            }
-            Expr::UnaryPrefixOp { rhs, .. } => {
-                self.resolve_expr(rhs)?;
-            }
-            Expr::Assignment { left, right, .. } => {
-                self.resolve_expr(left)?;
-                self.resolve_expr(right)?;
-            }
-            Expr::ArrayIndex { array, index } => {
-                self.resolve_expr(array)?;
-                self.resolve_expr(index)?;
-            }
-            Expr::Call { func, args, .. } => match func.as_ref() {
-                Expr::Ident(func_name) => {
-                    self.resolve_identifier(func_name).map_err(|_| {
-                        SemanticError::UndefinedSymbol {
-                            name: func_name.clone(),
-                        }
-                    })?;
-                    for arg in args {
-                        self.resolve_expr(arg)?;
-                    }
-                }
-                _ => unreachable!(),
-            },
-            Expr::InitializerList(expr_list) => {
-                for e in expr_list {
-                    self.resolve_expr(e)?;
-                }
+    // 선언만 되고 정의되지 않은 이름의 참조 검사 (번역 단위 끝에서 한 번)
+    // - 내부 링크(static) 함수는 다른 번역 단위에서 정의될 수 없으므로 오류
+    // - 외부 링크 이름은 링커가 해결하도록 externals 에 기록
+    //   (블록 안 extern 선언으로만 알려진 이름 포함)
+    pub fn check_undefined_symbols(&mut self) -> AnalyzeResult<()> {
+        for name in &self.referenced_globals {
+            match self.table.lookup_global(name) {
+                Some(symbol) if symbol.is_defined => {}
+                Some(symbol) if symbol.linkage == Linkage::Internal => {
+                    return Err(SemanticError::UndefinedFunction { name: name.clone() });
+                }
+                _ => self.externals.push(name.clone()),
             }
-            Expr::CharLiteral(_) | Expr::IntLiteral(_) => {}
         }
         Ok(())
     }
@@ -1,78 +1,247 @@
-use crate::ast::{Function, TypeSpecifier};
+use crate::ast::functions::Parameter;
+use crate::ast::stmt::Declarator;
+use crate::ast::{Expr, Function, StorageClass, TypeSpecifier};
 use crate::semantic::resolver::error::ResolveError;
+use crate::semantic::resolver::prelude::is_prelude_declaration;
 use crate::semantic::resolver::resolver::Resolver;
 use crate::semantic::symbol::error::SymbolError;
-use crate::semantic::symbol::symbol::{Symbol, SymbolKind, SymbolTable};
+use crate::semantic::symbol::symbol::{
+    Linkage, ScopeId, Symbol, SymbolId, SymbolKind, SymbolTable,
+};
+use std::collections::{BTreeSet, HashSet};
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Resolver {
     pub fn new() -> Self {
         Resolver {
             table: SymbolTable::new(),
             loop_depth: 0,
+            referenced_globals: BTreeSet::new(),
+            initialized_globals: HashSet::new(),
+            externals: Vec::new(),
         }
     }
 
     // 함수 선언을 심볼 테이블에 추가
     pub fn declare_function(&mut self, func: &Function) -> Result<(), SymbolError> {
-        let name = func.name.clone();
-        let return_ty = func.return_ty.clone();
-        let param_types: Vec<TypeSpecifier> =
-            func.params.iter().map(|param| param.ty.clone()).collect();
-
-        if let Some(existing) = self.table.lookup(&name) {
-            // 같은 함수 시그니처인지 검사
-            if let SymbolKind::Function { param_types: existing_params } = &existing.kind {
-                if existing.ty == return_ty && *existing_params == param_types {
-                    // 시그니처 일치: 중복 선언이 아니므로 무시
-                    return Ok(());
-                }
+        // 매개변수의 최상위 한정자는 함수 타입에 포함되지 않음
+        let param_types: Vec<TypeSpecifier> = func
+            .params
+            .iter()
+            .map(|param| param.ty.unqualified().clone())
+            .collect();
+        let symbol = Symbol {
+            ty: func.return_ty.clone(),
+            kind: SymbolKind::Function {
+                param_types,
+                is_variadic: func.is_variadic,
+            },
+            linkage: file_scope_linkage(func.storage),
+            is_defined: func.is_definition(),
+            decl: func.id(),
+            name: func.name.clone(),
+            scope: ScopeId::GLOBAL,
+        };
+        self.declare_function_symbol(symbol, func.storage)
+    }
+
+    // 변수와 함께 선언한 함수 프로토타입 (int x, h(int);)
+    pub fn declare_function_declarator(
+        &mut self,
+        declarator: &Declarator,
+        storage: Option<StorageClass>,
+    ) -> Result<(), SymbolError> {
+        let symbol =
+            function_declarator_symbol(declarator, file_scope_linkage(storage), ScopeId::GLOBAL);
+        self.declare_function_symbol(symbol, storage)
+    }
+
+    fn declare_function_symbol(
+        &mut self,
+        symbol: Symbol,
+        storage: Option<StorageClass>,
+    ) -> Result<(), SymbolError> {
+        let name = symbol.name.clone();
+        if let Some(id) = self.table.resolve_global(&name) {
+            let existing = self.table.symbol_mut(id);
+            // 저장 클래스 없는 함수 선언은 extern 과 같이 앞선 링크를 따름
+            let storage = storage.or(Some(StorageClass::Extern));
+            let composite = existing.composite(&symbol);
+            let compatible =
+                composite.is_some() && check_linkage(&name, existing.linkage, storage).is_ok();
+            // 프렐류드와 시그니처나 링크(static)가 다른 선언은 프렐류드 선언을 대체
+            if !compatible && is_prelude_declaration(existing.decl) {
+                *existing = symbol;
+                return Ok(());
             }
             // 이름이 변수이거나 시그니처 불일치
-            return Err(SymbolError::DuplicateDeclaration { name });
+            let Some((ty, kind)) = composite else {
+                return Err(SymbolError::DuplicateDeclaration { name });
+            };
+            // 시그니처 호환: 링크만 맞으면 중복 선언이 아님
+            check_linkage(&name, existing.linkage, storage)?;
+            (existing.ty, existing.kind) = (ty, kind);
+            // 정의는 한 번만 허용
+            if symbol.is_defined {
+                if existing.is_defined {
+                    return Err(SymbolError::Redefinition { name });
+                }
+                existing.is_defined = true;
+            }
+            return Ok(());
         }
 
+        self.table.declare(name, symbol).map(|_| ())
+    }
+
+    // 전역 변수 선언을 심볼 테이블에 추가
+    // 호환되는 타입·같은 링크의 재선언(extern int x; int x = 1;)은 허용하고 초기화는 한 번만 허용
+    // extern 이 아닌 선언은 초기화식이 없어도 (잠정) 정의
+    pub fn declare_global(
+        &mut self,
+        declarator: &Declarator,
+        storage: Option<StorageClass>,
+    ) -> Result<(), SymbolError> {
+        let name = declarator.name.as_str();
+        let has_init = declarator.init.is_some();
         let symbol = Symbol {
-            ty: return_ty,
-            kind: SymbolKind::Function { param_types },
+            ty: declarator.ty.clone(),
+            kind: SymbolKind::Variable,
+            linkage: file_scope_linkage(storage),
+            is_defined: has_init || storage != Some(StorageClass::Extern),
+            decl: declarator.id(),
+            name: name.to_string(),
+            scope: ScopeId::GLOBAL,
         };
-        self.table.declare(name, symbol)
+        if has_init && !self.initialized_globals.insert(name.to_string()) {
+            return Err(SymbolError::DuplicateDeclaration {
+                name: name.to_string(),
+            });
+        }
+
+        if let Some(id) = self.table.resolve_global(name) {
+            let existing = self.table.symbol_mut(id);
+            // 같은 이름의 전역 변수는 프렐류드 함수 선언을 대체
+            if is_prelude_declaration(existing.decl) {
+                *existing = symbol;
+                return Ok(());
+            }
+            let Some((ty, kind)) = existing.composite(&symbol) else {
+                return Err(SymbolError::DuplicateDeclaration {
+                    name: name.to_string(),
+                });
+            };
+            check_linkage(name, existing.linkage, storage)?;
+            // extern int a[]; int a[10]; 처럼 뒤 선언이 크기를 채우면 합성 타입을 따름
+            (existing.ty, existing.kind) = (ty, kind);
+            existing.is_defined |= symbol.is_defined;
+            return Ok(());
+        }
+
+        self.table.declare(name.to_string(), symbol).map(|_| ())
     }
 
-    // 변수 선언을 심볼 테이블에 추가
-    pub fn declare_variable(&mut self, name: &str, ty: &TypeSpecifier) -> Result<(), SymbolError> {
+    // 블록 안 변수 선언을 심볼 테이블에 추가
+    // extern 지역 선언은 전역 개체를 가리키므로 이미 있는 전역 선언과 타입이 호환되어야 하고
+    // 같은 심볼을 현재 스코프의 이름으로 연결
+    pub fn declare_local(
+        &mut self,
+        declarator: &Declarator,
+        storage: Option<StorageClass>,
+    ) -> Result<SymbolId, SymbolError> {
+        let (name, ty) = (&declarator.name, &declarator.ty);
+        if storage == Some(StorageClass::Extern)
+            && let Some(id) = self.table.resolve_global(name)
+        {
+            let global = self.table.symbol(id);
+            if !matches!(global.kind, SymbolKind::Variable) || global.ty.composite(ty).is_none() {
+                return Err(SymbolError::DuplicateDeclaration { name: name.clone() });
+            }
+            self.table.bind(name.clone(), id)?;
+            return Ok(id);
+        }
+
+        let linkage = match storage {
+            // 이 단위에 전역 선언이 없는 extern: 다른 단위의 정의를 가리킴
+            Some(StorageClass::Extern) => Linkage::External,
+            // static 지역 변수는 저장 기간만 다르고 링크는 없음
+            Some(StorageClass::Static) | None => Linkage::None,
+        };
         let symbol = Symbol {
             ty: ty.clone(),
             kind: SymbolKind::Variable,
+            linkage,
+            is_defined: storage != Some(StorageClass::Extern),
+            decl: declarator.id(),
+            name: name.clone(),
+            scope: self.table.current,
         };
-
-        let result = self.table.declare(name.to_string(), symbol);
-        result
+        self.table.declare(name.clone(), symbol)
     }
 
-    // 식별자 참조 시 심볼 테이블 조회
-    pub fn resolve_identifier(&mut self, identifier: &str) -> Result<&Symbol, ResolveError> {
-        if let Some(found_symbol) = self.table.lookup(identifier) {
-            Ok(found_symbol)
-        } else {
-            Err(ResolveError::UndefinedSymbol {
-                name: identifier.to_string(),
-            })
+    // 블록 안 함수 선언 (int h(int);)
+    // extern 지역 선언과 같이 전역 함수를 가리키므로 이미 있는 전역 선언과 호환되어야 하고
+    // 같은 심볼을 현재 스코프의 이름으로 연결 (전역 선언이 없으면 다른 단위의 정의를 가리킴)
+    pub fn declare_local_function(
+        &mut self,
+        declarator: &Declarator,
+    ) -> Result<SymbolId, SymbolError> {
+        let name = &declarator.name;
+        let symbol = function_declarator_symbol(declarator, Linkage::External, self.table.current);
+        if let Some(id) = self.table.resolve_global(name) {
+            let global = self.table.symbol(id);
+            if global.composite(&symbol).is_some() {
+                self.table.bind(name.clone(), id)?;
+                return Ok(id);
+            }
+            // 프렐류드와 시그니처가 다른 선언은 프렐류드 선언 대신 씀
+            if !is_prelude_declaration(global.decl) {
+                return Err(SymbolError::DuplicateDeclaration { name: name.clone() });
+            }
         }
+        self.table.declare(name.clone(), symbol)
+    }
+
+    // 매개변수 선언을 심볼 테이블에 추가 (이름 없는 매개변수는 선언하지 않음)
+    pub fn declare_parameter(
+        &mut self,
+        param: &Parameter,
+    ) -> Result<Option<SymbolId>, SymbolError> {
+        let Some(name) = &param.name else {
+            return Ok(None);
+        };
+        let symbol = Symbol {
+            ty: param.ty.clone(),
+            kind: SymbolKind::Variable,
+            linkage: Linkage::None,
+            is_defined: true,
+            decl: param.id(),
+            name: name.clone(),
+            scope: self.table.current,
+        };
+        self.table.declare(name.clone(), symbol).map(Some)
     }
 
-    pub fn lookup_function(
+    // 식별자 참조를 심볼로 해석하고 def-use 색인에 기록
+    pub fn resolve_identifier(
         &mut self,
+        expr: &Expr,
         name: &str,
-    ) -> Result<(Vec<TypeSpecifier>, TypeSpecifier), ResolveError> {
-        let sym = self.resolve_identifier(name)?;
-        if let SymbolKind::Function { param_types } = &sym.kind {
-            Ok((param_types.clone(), sym.ty.clone()))
-        } else {
-            Err(ResolveError::NotAFunciton {
+    ) -> Result<SymbolId, ResolveError> {
+        let Some(id) = self.table.resolve(name) else {
+            return Err(ResolveError::UndefinedSymbol {
                 name: name.to_string(),
-            })
-        }
+            });
+        };
+        self.table.record_use(expr.id(), id);
+        Ok(id)
     }
+
     pub fn push_scope(&mut self) {
         self.table.push_scope();
     }
@@ -81,3 +250,57 @@ impl Resolver {
         self.table.pop_scope();
     }
 }
+
+/// 함수 타입 선언자의 심볼 (본문이 없으므로 정의가 아님)
+fn function_declarator_symbol(declarator: &Declarator, linkage: Linkage, scope: ScopeId) -> Symbol {
+    let TypeSpecifier::Function {
+        ret,
+        params,
+        is_variadic,
+    } = &declarator.ty
+    else {
+        unreachable!("함수 타입 선언자만 옴");
+    };
+    Symbol {
+        ty: (**ret).clone(),
+        kind: SymbolKind::Function {
+            param_types: params.clone(),
+            is_variadic: *is_variadic,
+        },
+        linkage,
+        is_defined: false,
+        decl: declarator.id(),
+        name: declarator.name.clone(),
+        scope,
+    }
+}
+
+/// 파일 스코프 선언의 링크: static 이면 내부, 그 외에는 외부
+fn file_scope_linkage(storage: Option<StorageClass>) -> Linkage {
+    match storage {
+        Some(StorageClass::Static) => Linkage::Internal,
+        _ => Linkage::External,
+    }
+}
+
+/// 이미 선언된 이름을 다시 선언할 때 링크가 어긋나는지 검사
+/// - static 뒤의 extern 선언은 앞선 내부 링크를 따름
+/// - 외부 링크로 선언된 이름을 static 으로 다시 선언하면 충돌
+/// - static 전역 변수를 저장 클래스 없이 다시 선언하면 충돌
+fn check_linkage(
+    name: &str,
+    existing: Linkage,
+    storage: Option<StorageClass>,
+) -> Result<(), SymbolError> {
+    let conflict = match storage {
+        Some(StorageClass::Static) => existing != Linkage::Internal,
+        Some(StorageClass::Extern) => false,
+        None => existing == Linkage::Internal,
+    };
+    if conflict {
+        return Err(SymbolError::LinkageConflict {
+            name: name.to_string(),
+        });
+    }
+    Ok(())
+}
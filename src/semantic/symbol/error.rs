@@ -1,4 +1,6 @@
 #[derive(Debug)]
 pub enum SymbolError {
     DuplicateDeclaration { name: String },
+    LinkageConflict { name: String },
+    Redefinition { name: String },
 }
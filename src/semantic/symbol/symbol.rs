@@ -1,59 +1,231 @@
 use crate::ast;
 use crate::semantic::symbol::error::SymbolError;
-use ast::TypeSpecifier;
+use ast::ty::composite_all;
+use ast::{DeclId, ExprId, TypeSpecifier};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
 pub struct Symbol {
     pub ty: TypeSpecifier,
     pub kind: SymbolKind,
+    pub linkage: Linkage,
+    pub is_defined: bool, // 본문이 있는 함수, 초기화식이 있는 전역 변수 등 정의가 이미 나온 경우
+    pub decl: DeclId,     // 처음 선언한 노드
+    pub name: String,
+    pub scope: ScopeId, // 선언된 스코프
 }
 
-#[derive(Debug, Clone)]
+/// 같은 이름의 선언들이 같은 개체를 가리키는지를 결정하는 링크
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Linkage {
+    None,     // 지역 변수, 매개변수, static 지역 변수
+    Internal, // static 전역 변수/함수: 번역 단위 안에서만 보임
+    External, // 그 외 전역 변수/함수, extern 선언
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum SymbolKind {
-    Function { param_types: Vec<TypeSpecifier> },
+    Function {
+        param_types: Vec<TypeSpecifier>,
+        is_variadic: bool,
+    },
     Variable,
 }
 
+impl Symbol {
+    /// 같은 개체의 선언으로 볼 수 있으면 두 선언을 합친 (타입, 종류)
+    /// 함수는 반환·매개변수 타입과 가변 인자까지 비교하고,
+    /// 크기 없는 배열과 크기 있는 배열은 호환되어 크기 있는 쪽을 따름
+    pub fn composite(&self, other: &Symbol) -> Option<(TypeSpecifier, SymbolKind)> {
+        let ty = self.ty.composite(&other.ty)?;
+        let kind = match (&self.kind, &other.kind) {
+            (SymbolKind::Variable, SymbolKind::Variable) => SymbolKind::Variable,
+            (
+                SymbolKind::Function {
+                    param_types: a,
+                    is_variadic: a_variadic,
+                },
+                SymbolKind::Function {
+                    param_types: b,
+                    is_variadic: b_variadic,
+                },
+            ) if a.len() == b.len() && a_variadic == b_variadic => SymbolKind::Function {
+                param_types: composite_all(a, b)?,
+                is_variadic: *a_variadic,
+            },
+            _ => return None,
+        };
+        Some((ty, kind))
+    }
+
+    /// 식에서 이름이 가리키는 값의 타입 (함수 이름은 함수 타입)
+    pub fn value_ty(&self) -> TypeSpecifier {
+        match &self.kind {
+            SymbolKind::Function {
+                param_types,
+                is_variadic,
+            } => TypeSpecifier::Function {
+                ret: Box::new(self.ty.clone()),
+                params: param_types.clone(),
+                is_variadic: *is_variadic,
+            },
+            SymbolKind::Variable => self.ty.clone(),
+        }
+    }
+}
+
+/// 선언 하나에 붙는 고유 번호 (SymbolTable::symbols 의 인덱스)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SymbolId(pub usize);
+
+/// 스코프 하나의 번호 (SymbolTable::scopes 의 인덱스, 0 은 전역 스코프)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScopeId(pub usize);
+
+impl ScopeId {
+    pub const GLOBAL: ScopeId = ScopeId(0);
+}
+
+/// 스코프 트리의 노드: 스코프를 벗어나도 지우지 않으므로 분석 후에도 조회 가능
+#[derive(Debug, Clone, Default)]
+pub struct Scope {
+    pub parent: Option<ScopeId>,
+    pub children: Vec<ScopeId>,
+    pub names: HashMap<String, SymbolId>, // 이 스코프에서 선언된 이름
+}
+
+/// 심볼 테이블
+/// - 모든 선언은 symbols 에 한 번만 저장되고 SymbolId 로 가리킴
+/// - 스코프는 트리로 남고 current 가 지금 분석 중인 스코프를 가리킴
+/// - 식별자 사용 -> 선언(def-use) 색인을 함께 기록
 #[derive(Debug)]
 pub struct SymbolTable {
-    pub scopes: Vec<HashMap<String, Symbol>>,
+    pub symbols: Vec<Symbol>,
+    pub scopes: Vec<Scope>,
+    pub current: ScopeId,
+    pub references: HashMap<ExprId, SymbolId>, // 식별자 사용 -> 선언
+    pub uses: HashMap<SymbolId, Vec<ExprId>>,  // 선언 -> 사용 (해석 순서대로)
+}
+
+impl Default for SymbolTable {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SymbolTable {
     pub fn new() -> Self {
         SymbolTable {
-            scopes: vec![HashMap::new()],
+            symbols: Vec::new(),
+            scopes: vec![Scope::default()],
+            current: ScopeId::GLOBAL,
+            references: HashMap::new(),
+            uses: HashMap::new(),
         }
     }
 
     // 현재 스코프에 심볼 선언
-    pub fn declare(&mut self, name: String, symbol: Symbol) -> Result<(), SymbolError> {
-        let current = self.scopes.last_mut().unwrap();
-        if current.contains_key(&name) {
+    pub fn declare(&mut self, name: String, mut symbol: Symbol) -> Result<SymbolId, SymbolError> {
+        if self.scopes[self.current.0].names.contains_key(&name) {
+            return Err(SymbolError::DuplicateDeclaration { name });
+        }
+        let id = SymbolId(self.symbols.len());
+        symbol.name = name.clone();
+        symbol.scope = self.current;
+        self.symbols.push(symbol);
+        self.scopes[self.current.0].names.insert(name, id);
+        Ok(id)
+    }
+
+    // 이미 있는 심볼을 현재 스코프의 이름으로 연결 (블록 안 extern 선언)
+    pub fn bind(&mut self, name: String, id: SymbolId) -> Result<(), SymbolError> {
+        let names = &mut self.scopes[self.current.0].names;
+        if names.contains_key(&name) {
             return Err(SymbolError::DuplicateDeclaration { name });
         }
-        current.insert(name, symbol);
+        names.insert(name, id);
         Ok(())
     }
 
-    // 이름을 가장 깊은 스코프부터 검색
-    pub fn lookup(&self, name: &str) -> Option<&Symbol> {
-        for scope in self.scopes.iter().rev() {
-            if let Some(symbol) = scope.get(name) {
-                return Some(symbol);
+    pub fn symbol(&self, id: SymbolId) -> &Symbol {
+        &self.symbols[id.0]
+    }
+
+    pub fn symbol_mut(&mut self, id: SymbolId) -> &mut Symbol {
+        &mut self.symbols[id.0]
+    }
+
+    // 전역 스코프에서만 이름 검색
+    pub fn resolve_global(&self, name: &str) -> Option<SymbolId> {
+        self.scopes[ScopeId::GLOBAL.0].names.get(name).copied()
+    }
+
+    // 이름을 현재 스코프부터 부모 방향으로 검색
+    pub fn resolve(&self, name: &str) -> Option<SymbolId> {
+        self.resolve_from(self.current, name)
+    }
+
+    // 주어진 스코프부터 부모 방향으로 검색
+    pub fn resolve_from(&self, scope: ScopeId, name: &str) -> Option<SymbolId> {
+        let mut scope = Some(scope);
+        while let Some(id) = scope {
+            let node = &self.scopes[id.0];
+            if let Some(symbol) = node.names.get(name) {
+                return Some(*symbol);
             }
+            scope = node.parent;
         }
         None
     }
 
-    // 새로운 스코프로 진입
-    pub fn push_scope(&mut self) {
-        self.scopes.push(HashMap::new());
+    pub fn lookup_global(&self, name: &str) -> Option<&Symbol> {
+        self.resolve_global(name).map(|id| self.symbol(id))
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<&Symbol> {
+        self.resolve(name).map(|id| self.symbol(id))
+    }
+
+    /// 전역 스코프의 (이름, 심볼)
+    pub fn globals(&self) -> impl Iterator<Item = (&String, &Symbol)> {
+        self.scopes[ScopeId::GLOBAL.0]
+            .names
+            .iter()
+            .map(|(name, id)| (name, self.symbol(*id)))
+    }
+
+    // 식별자 사용 기록
+    pub fn record_use(&mut self, expr: ExprId, id: SymbolId) {
+        self.references.insert(expr, id);
+        self.uses.entry(id).or_default().push(expr);
+    }
+
+    /// 식별자 사용이 가리키는 심볼 (go-to-definition)
+    pub fn symbol_of(&self, expr: ExprId) -> Option<SymbolId> {
+        self.references.get(&expr).copied()
+    }
+
+    /// 심볼을 가리키는 식별자 사용들 (find-references)
+    pub fn uses_of(&self, id: SymbolId) -> &[ExprId] {
+        self.uses.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    // 새 자식 스코프를 만들고 진입
+    pub fn push_scope(&mut self) -> ScopeId {
+        let id = ScopeId(self.scopes.len());
+        self.scopes.push(Scope {
+            parent: Some(self.current),
+            ..Scope::default()
+        });
+        self.scopes[self.current.0].children.push(id);
+        self.current = id;
+        id
     }
 
-    // 현재 스코프 종료
+    // 현재 스코프를 벗어나 부모로 (스코프 자체는 남김)
     pub fn pop_scope(&mut self) {
-        self.scopes.pop();
+        self.current = self.scopes[self.current.0]
+            .parent
+            .expect("전역 스코프는 벗어날 수 없음");
     }
 }
@@ -0,0 +1,351 @@
+use crate::ast::expr::{AssignOp, BinaryOp, PostfixOp, PrefixOp};
+use crate::ast::{Expr, ExprId, Stmt, TypeSpecifier};
+use crate::consteval::eval_int;
+use crate::semantic::analyzer::SemanticError::*;
+use crate::semantic::analyzer::{AnalyzeResult, SemanticWarning};
+use crate::semantic::symbol::symbol::SymbolId;
+use crate::semantic::type_checker::TypeChecker;
+
+/// for 루프 변수가 본문에서 가질 수 있는 값의 범위 [min, max]
+struct LoopRange {
+    var: SymbolId,
+    min: i64,
+    max: i64,
+}
+
+impl<'a, 'p> TypeChecker<'a, 'p> {
+    /// 길이를 아는 배열을 상수 인덱스로 접근하면 범위를 검사
+    /// (&a[n] 처럼 주소만 구하는 경우는 끝 바로 다음 위치까지 허용)
+    pub(super) fn check_constant_index(
+        &self,
+        expr: &Expr,
+        array_ty: &TypeSpecifier,
+        index: &Expr,
+    ) -> AnalyzeResult<()> {
+        let TypeSpecifier::Array(_, Some(length)) = array_ty.unqualified() else {
+            return Ok(());
+        };
+        let Ok(value) = eval_int(index) else {
+            return Ok(());
+        };
+        let end = if self.address_operand == Some(expr.id()) {
+            *length as i64
+        } else {
+            *length as i64 - 1
+        };
+        if value < 0 || value as i64 > end {
+            return Err(ArrayIndexOutOfBounds {
+                index: value,
+                length: *length,
+            });
+        }
+        Ok(())
+    }
+
+    /// for (i = a; i < b; i++) 꼴 루프에서 본문의 x[i] 가 배열 범위를 벗어나면 경고
+    /// (본문에서 루프 변수를 바꾸면 범위를 알 수 없으므로 건너뜀,
+    ///  루프 변수를 검사하는 조건 아래의 인덱싱은 범위가 좁혀지므로 값 범위 분석에 맡김)
+    pub(super) fn check_loop_bounds(
+        &mut self,
+        init: Option<&Stmt>,
+        cond: Option<&Expr>,
+        step: Option<&Expr>,
+        body: &Stmt,
+    ) {
+        let Some(range) = self.loop_range(init, cond, step) else {
+            return;
+        };
+        let mut scan = LoopScan::default();
+        scan.stmt(body);
+        if scan
+            .modified
+            .iter()
+            .any(|e| self.resolver.table.symbol_of(*e) == Some(range.var))
+        {
+            return;
+        }
+        for LoopIndex {
+            expr,
+            array,
+            index,
+            guards,
+        } in scan.indexes
+        {
+            if self.resolver.table.symbol_of(index) != Some(range.var)
+                || guards
+                    .iter()
+                    .any(|e| self.resolver.table.symbol_of(*e) == Some(range.var))
+            {
+                continue;
+            }
+            let Some(TypeSpecifier::Array(_, Some(length))) = self
+                .model
+                .exprs
+                .get(&array)
+                .map(|info| info.ty.unqualified())
+            else {
+                continue;
+            };
+            if range.min < 0 || range.max >= *length as i64 {
+                self.warnings.push(SemanticWarning::ArrayBounds {
+                    expr,
+                    length: *length,
+                    min: range.min,
+                    max: range.max,
+                });
+            }
+        }
+    }
+
+    /// 초기값, 비교 상수, ±1 증감으로 루프 변수의 범위를 구함
+    fn loop_range(
+        &self,
+        init: Option<&Stmt>,
+        cond: Option<&Expr>,
+        step: Option<&Expr>,
+    ) -> Option<LoopRange> {
+        let (var, start) = match init? {
+            Stmt::ExprStmt(Some(Expr::Assignment {
+                left,
+                op: AssignOp::Assign,
+                right,
+                ..
+            })) if matches!(left.as_ref(), Expr::Ident(_)) => (
+                self.resolver.table.symbol_of(left.id())?,
+                eval_int(right).ok()?,
+            ),
+            Stmt::Declaration { declarators, .. } if declarators.len() == 1 => {
+                let d = &declarators[0];
+                let id = self.resolver.table.resolve(&d.name)?;
+                (id, eval_int(d.init.as_ref()?).ok()?)
+            }
+            _ => return None,
+        };
+        let Expr::BinaryOp { lhs, op, rhs } = cond? else {
+            return None;
+        };
+        if self.resolver.table.symbol_of(lhs.id()) != Some(var) {
+            return None;
+        }
+        let bound = eval_int(rhs).ok()? as i64;
+        let start = start as i64;
+        let (min, max) = match (op, step_direction(step?)?) {
+            (BinaryOp::Lt, 1) => (start, bound - 1),
+            (BinaryOp::Le, 1) => (start, bound),
+            (BinaryOp::Gt, -1) => (bound + 1, start),
+            (BinaryOp::Ge, -1) => (bound, start),
+            _ => return None,
+        };
+        // 한 번도 돌지 않는 루프
+        if min > max {
+            return None;
+        }
+        Some(LoopRange { var, min, max })
+    }
+}
+
+/// i++, ++i, i += 1 은 1, 감소는 -1
+fn step_direction(step: &Expr) -> Option<i32> {
+    match step {
+        Expr::UnaryPostfixOp {
+            op: PostfixOp::PostInc,
+            ..
+        }
+        | Expr::UnaryPrefixOp {
+            op: PrefixOp::PreInc,
+            ..
+        } => Some(1),
+        Expr::UnaryPostfixOp {
+            op: PostfixOp::PostDec,
+            ..
+        }
+        | Expr::UnaryPrefixOp {
+            op: PrefixOp::PreDec,
+            ..
+        } => Some(-1),
+        Expr::Assignment {
+            op: AssignOp::PlusAssign,
+            right,
+            ..
+        } if eval_int(right) == Ok(1) => Some(1),
+        Expr::Assignment {
+            op: AssignOp::MinusAssign,
+            right,
+            ..
+        } if eval_int(right) == Ok(1) => Some(-1),
+        _ => None,
+    }
+}
+
+/// 루프 본문의 인덱싱 식과 그 식을 감싼 조건들에 나온 식별자
+struct LoopIndex {
+    expr: ExprId,
+    array: ExprId,
+    index: ExprId,
+    guards: Vec<ExprId>,
+}
+
+/// 루프 본문에서 값이 바뀌는 식별자와 인덱싱 식 수집
+#[derive(Default)]
+struct LoopScan {
+    modified: Vec<ExprId>,
+    indexes: Vec<LoopIndex>,
+    /// 지금 보는 위치를 감싼 조건 (if/while/for 조건, &&/|| 의 왼쪽) 의 식별자
+    guards: Vec<ExprId>,
+}
+
+impl LoopScan {
+    fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Declaration { declarators, .. } => {
+                for init in declarators.iter().filter_map(|d| d.init.as_ref()) {
+                    self.expr(init);
+                }
+            }
+            Stmt::ExprStmt(Some(expr)) | Stmt::Return(Some(expr)) => self.expr(expr),
+            Stmt::Block(block) => {
+                for stmt in &block.statements {
+                    self.stmt(stmt);
+                }
+            }
+            Stmt::If {
+                cond,
+                then_branch,
+                else_branch,
+            } => {
+                self.expr(cond);
+                self.guarded(cond, |scan| {
+                    scan.stmt(then_branch);
+                    if let Some(else_branch) = else_branch {
+                        scan.stmt(else_branch);
+                    }
+                });
+            }
+            Stmt::While { cond, body } => {
+                self.expr(cond);
+                self.guarded(cond, |scan| scan.stmt(body));
+            }
+            Stmt::For {
+                init,
+                cond,
+                step,
+                body,
+            } => {
+                if let Some(init) = init {
+                    self.stmt(init);
+                }
+                for expr in cond.iter().chain(step) {
+                    self.expr(expr);
+                }
+                match cond {
+                    Some(cond) => self.guarded(cond, |scan| scan.stmt(body)),
+                    None => self.stmt(body),
+                }
+            }
+            Stmt::ExprStmt(None) | Stmt::Return(None) | Stmt::Break | Stmt::Continue => {}
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        match expr {
+            // 대입, 증감, 주소 노출은 모두 값이 바뀔 수 있는 것으로 봄
+            Expr::Assignment { left, right, .. } => {
+                self.modified.push(left.id());
+                self.expr(left);
+                self.expr(right);
+            }
+            Expr::UnaryPrefixOp {
+                op: PrefixOp::PreInc | PrefixOp::PreDec | PrefixOp::Address,
+                rhs,
+            } => {
+                self.modified.push(rhs.id());
+                self.expr(rhs);
+            }
+            Expr::UnaryPostfixOp { lhs, .. } => {
+                self.modified.push(lhs.id());
+                self.expr(lhs);
+            }
+            Expr::UnaryPrefixOp { rhs, .. } => self.expr(rhs),
+            Expr::ArrayIndex { array, index } => {
+                self.indexes.push(LoopIndex {
+                    expr: expr.id(),
+                    array: array.id(),
+                    index: index.id(),
+                    guards: self.guards.clone(),
+                });
+                self.expr(array);
+                self.expr(index);
+            }
+            Expr::BinaryOp {
+                lhs,
+                op: BinaryOp::And | BinaryOp::Or,
+                rhs,
+            } => {
+                self.expr(lhs);
+                self.guarded(lhs, |scan| scan.expr(rhs));
+            }
+            Expr::BinaryOp { lhs, rhs, .. } => {
+                self.expr(lhs);
+                self.expr(rhs);
+            }
+            Expr::Call { func, args } => {
+                self.expr(func);
+                for arg in args {
+                    self.expr(arg);
+                }
+            }
+            Expr::InitializerList(items) => {
+                for item in items {
+                    self.expr(item);
+                }
+            }
+            Expr::Ident(_)
+            | Expr::IntLiteral(_)
+            | Expr::FloatLiteral(_)
+            | Expr::DoubleLiteral(_)
+            | Expr::CharLiteral(_)
+            | Expr::StringLiteral(_) => {}
+        }
+    }
+
+    /// cond 의 식별자를 조건으로 걸어 둔 채 f 로 안쪽을 훑음
+    fn guarded(&mut self, cond: &Expr, f: impl FnOnce(&mut Self)) {
+        let depth = self.guards.len();
+        collect_idents(cond, &mut self.guards);
+        f(self);
+        self.guards.truncate(depth);
+    }
+}
+
+/// 식에 나오는 식별자 식을 모두 모음
+fn collect_idents(expr: &Expr, out: &mut Vec<ExprId>) {
+    match expr {
+        Expr::Ident(_) => out.push(expr.id()),
+        Expr::UnaryPrefixOp { rhs: inner, .. } | Expr::UnaryPostfixOp { lhs: inner, .. } => {
+            collect_idents(inner, out)
+        }
+        Expr::BinaryOp { lhs, rhs, .. }
+        | Expr::Assignment {
+            left: lhs,
+            right: rhs,
+            ..
+        }
+        | Expr::ArrayIndex {
+            array: lhs,
+            index: rhs,
+        } => {
+            collect_idents(lhs, out);
+            collect_idents(rhs, out);
+        }
+        Expr::Call { func, args } => {
+            collect_idents(func, out);
+            args.iter().for_each(|arg| collect_idents(arg, out));
+        }
+        Expr::InitializerList(items) => items.iter().for_each(|item| collect_idents(item, out)),
+        Expr::IntLiteral(_)
+        | Expr::FloatLiteral(_)
+        | Expr::DoubleLiteral(_)
+        | Expr::CharLiteral(_)
+        | Expr::StringLiteral(_) => {}
+    }
+}
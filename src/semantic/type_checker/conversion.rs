@@ -0,0 +1,169 @@
+use crate::ast::{Expr, ExprId, TypeSpecifier};
+use crate::consteval::eval_int;
+use crate::semantic::type_checker::utils::{is_arithmetic, is_floating, is_integer};
+
+/// 암시적 변환 종류: 백엔드가 부호 확장/절단/변환 명령을 넣어야 하는 위치
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConversionKind {
+    IntegerPromotion,   // char -> int (부호 확장)
+    IntegerTruncation,  // int -> char (하위 8비트)
+    IntToFloating,      // char/int -> float/double
+    FloatingToInt,      // float/double -> char/int (0 방향 절삭)
+    FloatingExtension,  // float -> double
+    FloatingTruncation, // double -> float
+    NullPointer,        // 널 포인터 상수 0 -> 포인터
+    PointerConversion,  // void* <-> T* (값은 그대로)
+    // 포인터 산술 (p + i, a[i], ++p): 정수 피연산자에 원소 크기를 곱함
+    ScaledIndex { element_size: usize },
+    // 포인터 차 (p - q): 바이트 차이를 원소 크기로 나눔
+    PointerDifference { element_size: usize },
+}
+
+/// 식 하나에 삽입된 암시적 변환
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImplicitConversion {
+    pub expr: ExprId,
+    pub kind: ConversionKind,
+    pub from: TypeSpecifier,
+    pub to: TypeSpecifier,
+}
+
+/// 대입 변환(대입, 초기화, 인자 전달, return) 결과
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AssignConversion {
+    Identity,                // 변환 없음
+    Convert(ConversionKind), // 암시적 변환 삽입
+    Incompatible,            // 변환 불가
+}
+
+/// 정수 승격: int 보다 좁은 정수(char)는 int 로
+pub fn integer_promotion(ty: &TypeSpecifier) -> TypeSpecifier {
+    match ty {
+        TypeSpecifier::Char => TypeSpecifier::Int,
+        other => other.clone(),
+    }
+}
+
+/// 일반 산술 변환: 두 산술 피연산자를 맞출 공통 타입 (double > float > int)
+pub fn usual_arithmetic_conversion(lt: &TypeSpecifier, rt: &TypeSpecifier) -> TypeSpecifier {
+    if *lt == TypeSpecifier::Double || *rt == TypeSpecifier::Double {
+        TypeSpecifier::Double
+    } else if *lt == TypeSpecifier::Float || *rt == TypeSpecifier::Float {
+        TypeSpecifier::Float
+    } else {
+        TypeSpecifier::Int
+    }
+}
+
+/// 가변 인자 자리의 기본 인자 승격: char -> int, float -> double
+pub fn default_argument_promotion(ty: &TypeSpecifier) -> TypeSpecifier {
+    match ty {
+        TypeSpecifier::Float => TypeSpecifier::Double,
+        other => integer_promotion(other),
+    }
+}
+
+/// 산술 타입 사이의 변환 종류 (같은 타입이면 None)
+pub fn arithmetic_conversion(from: &TypeSpecifier, to: &TypeSpecifier) -> Option<ConversionKind> {
+    use TypeSpecifier::{Char, Double, Float, Int};
+    match (from, to) {
+        _ if from == to => None,
+        (Char, Int) => Some(ConversionKind::IntegerPromotion),
+        (Int, Char) => Some(ConversionKind::IntegerTruncation),
+        (Float, Double) => Some(ConversionKind::FloatingExtension),
+        (Double, Float) => Some(ConversionKind::FloatingTruncation),
+        _ if is_integer(from) && is_floating(to) => Some(ConversionKind::IntToFloating),
+        _ if is_floating(from) && is_integer(to) => Some(ConversionKind::FloatingToInt),
+        _ => None,
+    }
+}
+
+/// 널 포인터 상수: 값이 0 인 정수 상수식 (0, '\0', 1 - 1 등)
+pub fn is_null_pointer_constant(expr: &Expr, ty: &TypeSpecifier) -> bool {
+    is_integer(ty.unqualified()) && eval_int(expr) == Ok(0)
+}
+
+/// 가리키는 타입이 (한정자를 빼고) void 인지
+fn is_void_pointee(pointee: &TypeSpecifier) -> bool {
+    *pointee.unqualified() == TypeSpecifier::Void
+}
+
+/// 대입 변환 규칙
+/// - 산술 타입끼리는 항상 변환 가능 (좁아지는 변환 포함)
+/// - 포인터는 가리키는 타입이 (한정자를 빼고) 같거나, 한쪽이 void* 인 객체 포인터
+/// - 널 포인터 상수는 모든 포인터로
+pub fn assignment_conversion(
+    to: &TypeSpecifier,
+    from: &TypeSpecifier,
+    from_is_null: bool,
+) -> AssignConversion {
+    let (to, from) = (to.unqualified(), from.unqualified());
+    if is_arithmetic(to) && is_arithmetic(from) {
+        return match arithmetic_conversion(from, to) {
+            Some(kind) => AssignConversion::Convert(kind),
+            None => AssignConversion::Identity,
+        };
+    }
+    match (to, from) {
+        (TypeSpecifier::Pointer(t), TypeSpecifier::Pointer(f)) => {
+            if t.unqualified() == f.unqualified() {
+                AssignConversion::Identity
+            } else if (is_void_pointee(t)
+                && !matches!(f.unqualified(), TypeSpecifier::Function { .. }))
+                || (is_void_pointee(f)
+                    && !matches!(t.unqualified(), TypeSpecifier::Function { .. }))
+            {
+                AssignConversion::Convert(ConversionKind::PointerConversion)
+            } else {
+                AssignConversion::Incompatible
+            }
+        }
+        (TypeSpecifier::Pointer(_), _) if from_is_null && is_integer(from) => {
+            AssignConversion::Convert(ConversionKind::NullPointer)
+        }
+        _ if to == from => AssignConversion::Identity,
+        _ => AssignConversion::Incompatible,
+    }
+}
+
+/// 포인터 비교가 가능한 두 피연산자인지
+/// - 관계 비교(<, <= 등): 가리키는 타입이 (한정자를 빼고) 같은 포인터끼리
+/// - 동등 비교(==, !=): 추가로 void* 와 객체 포인터, 포인터와 널 포인터 상수
+pub fn comparable_pointers(
+    lt: &TypeSpecifier,
+    rt: &TypeSpecifier,
+    lhs_is_null: bool,
+    rhs_is_null: bool,
+    is_equality: bool,
+) -> bool {
+    match (lt, rt) {
+        (TypeSpecifier::Pointer(l), TypeSpecifier::Pointer(r)) if !is_equality => {
+            l.unqualified() == r.unqualified()
+        }
+        (TypeSpecifier::Pointer(_), TypeSpecifier::Pointer(_)) => !matches!(
+            assignment_conversion(lt, rt, false),
+            AssignConversion::Incompatible
+        ),
+        (TypeSpecifier::Pointer(_), other) => is_equality && rhs_is_null && is_integer(other),
+        (other, TypeSpecifier::Pointer(_)) => is_equality && lhs_is_null && is_integer(other),
+        _ => false,
+    }
+}
+
+/// 포인터 산술에 쓸 원소 크기: void*, 함수 포인터 등 크기 없는 대상은 None
+pub fn pointee_size(ty: &TypeSpecifier) -> Option<usize> {
+    match ty {
+        TypeSpecifier::Pointer(pointee) => pointee.size(),
+        _ => None,
+    }
+}
+
+/// 포인터 변환에서 가리키는 타입의 한정자가 사라지는지 (const char* -> char*)
+pub fn discards_qualifiers(to: &TypeSpecifier, from: &TypeSpecifier) -> bool {
+    match (to.unqualified(), from.unqualified()) {
+        (TypeSpecifier::Pointer(t), TypeSpecifier::Pointer(f)) => {
+            !t.qualifiers().contains(f.qualifiers())
+        }
+        _ => false,
+    }
+}
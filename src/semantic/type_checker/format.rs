@@ -0,0 +1,285 @@
+use crate::ast::{Expr, TypeSpecifier};
+use crate::semantic::analyzer::SemanticWarning;
+use crate::semantic::symbol::symbol::{Linkage, SymbolKind};
+use crate::semantic::type_checker::TypeChecker;
+use crate::semantic::type_checker::utils::type_name;
+
+/// 형식 문자열을 해석하는 라이브러리 함수 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormatKind {
+    Printf,
+    Scanf,
+}
+
+/// 이름으로 알아보는 printf/scanf 계열 함수와 형식 문자열 인자의 위치
+fn format_function(name: &str) -> Option<(FormatKind, usize)> {
+    match name {
+        "printf" => Some((FormatKind::Printf, 0)),
+        "fprintf" | "sprintf" => Some((FormatKind::Printf, 1)),
+        "snprintf" => Some((FormatKind::Printf, 2)),
+        "scanf" => Some((FormatKind::Scanf, 0)),
+        "fscanf" | "sscanf" => Some((FormatKind::Scanf, 1)),
+        _ => None,
+    }
+}
+
+/// 변환 지정자가 기대하는 인자 (기본 인자 승격 후 타입과 비교)
+#[derive(Debug, Clone, PartialEq)]
+enum Expected {
+    Int,                       // char, int
+    Double,                    // float, double
+    Pointer,                   // 아무 포인터 (%p)
+    PointerTo(TypeSpecifier),  // %s 와 scanf 의 저장 위치
+    Unsupported(&'static str), // long 처럼 이 언어에 없는 타입
+}
+
+impl Expected {
+    fn accepts(&self, ty: &TypeSpecifier) -> bool {
+        match (self, ty) {
+            (Expected::Int, TypeSpecifier::Int) => true,
+            (Expected::Double, TypeSpecifier::Double) => true,
+            (Expected::Pointer, TypeSpecifier::Pointer(_)) => true,
+            (Expected::PointerTo(target), TypeSpecifier::Pointer(inner)) => {
+                inner.unqualified() == target
+            }
+            _ => false,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Expected::Int => "int".to_string(),
+            Expected::Double => "double".to_string(),
+            Expected::Pointer => "pointer".to_string(),
+            Expected::PointerTo(target) => format!("{} *", type_name(target)),
+            Expected::Unsupported(name) => name.to_string(),
+        }
+    }
+}
+
+/// 변환 지정 하나가 소비하는 인자 ('*' 너비·정밀도는 따로 하나씩)
+struct Directive {
+    text: String,
+    expected: Expected,
+}
+
+impl<'a, 'p> TypeChecker<'a, 'p> {
+    /// 알려진 printf/scanf 계열 호출에서 형식 문자열 리터럴을 해석해
+    /// 가변 인자의 타입과 개수를 검사 (불일치는 경고)
+    /// - variadic: '...' 자리 인자의 기본 인자 승격 후 타입
+    pub(super) fn check_format_call(
+        &mut self,
+        call: &Expr,
+        func: &Expr,
+        args: &[Expr],
+        variadic: &[TypeSpecifier],
+    ) {
+        let Expr::Ident(name) = func else {
+            return;
+        };
+        let Some((kind, index)) = format_function(name) else {
+            return;
+        };
+        // 같은 이름의 지역 함수 포인터나 static 함수는 라이브러리 함수가 아님
+        let library = self
+            .resolver
+            .table
+            .symbol_of(func.id())
+            .map(|id| self.resolver.table.symbol(id))
+            .is_some_and(|symbol| {
+                matches!(symbol.kind, SymbolKind::Function { .. })
+                    && symbol.linkage == Linkage::External
+            });
+        if !library {
+            return;
+        }
+        // 형식 문자열이 리터럴일 때만 검사
+        let Some(Expr::StringLiteral(format)) = args.get(index) else {
+            return;
+        };
+
+        let directives = match parse_format(format, kind) {
+            Ok(directives) => directives,
+            Err(directive) => {
+                self.warnings.push(SemanticWarning::InvalidFormat {
+                    expr: args[index].id(),
+                    directive,
+                });
+                return;
+            }
+        };
+
+        let first = args.len() - variadic.len();
+        for (i, directive) in directives.iter().enumerate() {
+            let Some(found) = variadic.get(i) else {
+                break;
+            };
+            if !directive.expected.accepts(found) {
+                self.warnings.push(SemanticWarning::FormatMismatch {
+                    expr: args[first + i].id(),
+                    directive: directive.text.clone(),
+                    expected: directive.expected.describe(),
+                    found: found.clone(),
+                });
+            }
+        }
+        if directives.len() != variadic.len() {
+            self.warnings.push(SemanticWarning::FormatArgumentCount {
+                expr: call.id(),
+                expected: directives.len(),
+                found: variadic.len(),
+            });
+        }
+    }
+}
+
+/// 형식 문자열을 인자를 소비하는 변환 지정 목록으로 해석
+/// 알 수 없는 변환 지정자를 만나면 그 지정 문자열을 Err 로 반환
+fn parse_format(format: &str, kind: FormatKind) -> Result<Vec<Directive>, String> {
+    let chars: Vec<char> = format.chars().collect();
+    let mut directives = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] != '%' {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        let text = |end: usize| {
+            chars[start..end.min(chars.len())]
+                .iter()
+                .collect::<String>()
+        };
+
+        // printf: 플래그, 너비, 정밀도 ('*' 는 int 인자 하나)
+        // scanf: 대입 생략 '*', 최대 너비
+        let mut suppressed = false;
+        let mut star_args = 0;
+        match kind {
+            FormatKind::Printf => {
+                while i < chars.len() && "-+ #0".contains(chars[i]) {
+                    i += 1;
+                }
+                for precision in [false, true] {
+                    if precision {
+                        if chars.get(i) != Some(&'.') {
+                            break;
+                        }
+                        i += 1;
+                    }
+                    if chars.get(i) == Some(&'*') {
+                        star_args += 1;
+                        i += 1;
+                    } else {
+                        while i < chars.len() && chars[i].is_ascii_digit() {
+                            i += 1;
+                        }
+                    }
+                }
+            }
+            FormatKind::Scanf => {
+                if chars.get(i) == Some(&'*') {
+                    suppressed = true;
+                    i += 1;
+                }
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+        }
+
+        // 길이 수정자
+        let length: String = match (chars.get(i), chars.get(i + 1)) {
+            (Some('h'), Some('h')) | (Some('l'), Some('l')) => chars[i..i + 2].iter().collect(),
+            (Some(c @ ('h' | 'l' | 'L')), _) => c.to_string(),
+            _ => String::new(),
+        };
+        i += length.len();
+
+        let Some(&conversion) = chars.get(i) else {
+            return Err(text(i));
+        };
+        i += 1;
+        // scanf 의 %[...] 문자 집합 (맨 앞의 ']' 는 집합에 포함)
+        if kind == FormatKind::Scanf && conversion == '[' {
+            if chars.get(i) == Some(&'^') {
+                i += 1;
+            }
+            if chars.get(i) == Some(&']') {
+                i += 1;
+            }
+            while i < chars.len() && chars[i] != ']' {
+                i += 1;
+            }
+            if i == chars.len() {
+                return Err(text(i));
+            }
+            i += 1;
+        }
+        if conversion == '%' {
+            continue;
+        }
+
+        let expected = match kind {
+            FormatKind::Printf => printf_argument(conversion, &length),
+            FormatKind::Scanf => scanf_argument(conversion, &length),
+        }
+        .ok_or_else(|| text(i))?;
+        let text = text(i);
+        for _ in 0..star_args {
+            directives.push(Directive {
+                text: text.clone(),
+                expected: Expected::Int,
+            });
+        }
+        if !suppressed {
+            directives.push(Directive { text, expected });
+        }
+    }
+    Ok(directives)
+}
+
+/// printf 변환 지정자가 기대하는 (승격된) 인자
+fn printf_argument(conversion: char, length: &str) -> Option<Expected> {
+    let expected = match (conversion, length) {
+        ('d' | 'i' | 'u' | 'o' | 'x' | 'X' | 'c', "" | "h" | "hh") => Expected::Int,
+        ('d' | 'i', "l") => Expected::Unsupported("long"),
+        ('d' | 'i', "ll") => Expected::Unsupported("long long"),
+        ('u' | 'o' | 'x' | 'X', "l") => Expected::Unsupported("unsigned long"),
+        ('u' | 'o' | 'x' | 'X', "ll") => Expected::Unsupported("unsigned long long"),
+        ('f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A', "" | "l") => Expected::Double,
+        ('f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A', "L") => {
+            Expected::Unsupported("long double")
+        }
+        ('s', "") => Expected::PointerTo(TypeSpecifier::Char),
+        ('p', "") => Expected::Pointer,
+        ('n', "") => Expected::PointerTo(TypeSpecifier::Int),
+        _ => return None,
+    };
+    Some(expected)
+}
+
+/// scanf 변환 지정자가 기대하는 저장 위치 포인터
+fn scanf_argument(conversion: char, length: &str) -> Option<Expected> {
+    let expected = match (conversion, length) {
+        ('d' | 'i' | 'u' | 'o' | 'x' | 'X' | 'n', "") => Expected::PointerTo(TypeSpecifier::Int),
+        ('d' | 'i' | 'u' | 'o' | 'x' | 'X' | 'n', "hh") => Expected::PointerTo(TypeSpecifier::Char),
+        ('d' | 'i' | 'u' | 'o' | 'x' | 'X' | 'n', "h") => Expected::Unsupported("short *"),
+        ('d' | 'i' | 'u' | 'o' | 'x' | 'X' | 'n', "l") => Expected::Unsupported("long *"),
+        ('d' | 'i' | 'u' | 'o' | 'x' | 'X' | 'n', "ll") => Expected::Unsupported("long long *"),
+        ('f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A', "") => {
+            Expected::PointerTo(TypeSpecifier::Float)
+        }
+        ('f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A', "l") => {
+            Expected::PointerTo(TypeSpecifier::Double)
+        }
+        ('f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A', "L") => {
+            Expected::Unsupported("long double *")
+        }
+        ('c' | 's' | '[', "") => Expected::PointerTo(TypeSpecifier::Char),
+        ('p', "") => Expected::PointerTo(TypeSpecifier::Pointer(Box::new(TypeSpecifier::Void))),
+        _ => return None,
+    };
+    Some(expected)
+}
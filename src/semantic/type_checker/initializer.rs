@@ -0,0 +1,204 @@
+use crate::ast::Expr::{InitializerList, StringLiteral};
+use crate::ast::{Expr, TypeSpecifier};
+use crate::semantic::analyzer::AnalyzeResult;
+use crate::semantic::analyzer::SemanticError::*;
+use crate::semantic::model::{InitElement, Initializer};
+use crate::semantic::symbol::symbol::SymbolId;
+use crate::semantic::type_checker::TypeChecker;
+
+impl<'a, 'p> TypeChecker<'a, 'p> {
+    /// 선언 초기화식 검사 후 펼친 초기화식을 만듦
+    /// - 중괄호 목록은 원소 순서대로 대응시키고, 하위 배열의 중괄호는 생략 가능
+    /// - 남는 원소는 에러, 빠진 원소는 0 으로 채움
+    /// - 크기를 생략한 배열은 초기화한 원소 수로 크기를 정함
+    pub(super) fn check_initializer(
+        &mut self,
+        name: &str,
+        ty: &TypeSpecifier,
+        init: &Expr,
+    ) -> AnalyzeResult<Initializer> {
+        let mut elements = Vec::new();
+        let completed = match (ty.unqualified(), init) {
+            (TypeSpecifier::Array(elem, size), InitializerList(items)) => {
+                let mut pos = 0;
+                let count = self.init_array(elem, *size, items, &mut pos, 0, &mut elements)?;
+                if pos < items.len() {
+                    return Err(TooManyInitializers { ty: ty.clone() });
+                }
+                with_length(ty, size.unwrap_or(count))
+            }
+            (TypeSpecifier::Array(elem, size), StringLiteral(_))
+                if *elem.unqualified() == TypeSpecifier::Char =>
+            {
+                let count = self.init_string(ty, *size, init, 0, &mut elements)?;
+                with_length(ty, size.unwrap_or(count))
+            }
+            (_, InitializerList(_)) => {
+                return Err(ScalarBraceInitializer { ty: ty.clone() });
+            }
+            _ => {
+                self.init_scalar(ty, init, 0, &mut elements)?;
+                ty.clone()
+            }
+        };
+
+        // int a[] = {} 처럼 크기가 0 으로 정해지면 완성할 수 없음
+        let size = completed.size().unwrap_or(0);
+        if size == 0 && matches!(completed.unqualified(), TypeSpecifier::Array(..)) {
+            return Err(IncompleteArray {
+                name: name.to_string(),
+            });
+        }
+        Ok(Initializer {
+            ty: completed,
+            elements: fill_zeros(elements, size),
+        })
+    }
+
+    /// 초기화식으로 크기가 정해진 배열의 타입을 심볼에 반영
+    pub(super) fn complete_array_type(&mut self, id: SymbolId, completed: &TypeSpecifier) {
+        let symbol = self.resolver.table.symbol_mut(id);
+        if matches!(symbol.ty.unqualified(), TypeSpecifier::Array(_, None)) {
+            symbol.ty = completed.clone();
+        }
+    }
+
+    /// items[pos..] 로 배열 원소를 차례로 초기화하고 초기화한 원소 수를 반환
+    /// (size 가 있으면 그만큼만 소비하고 나머지는 바깥 목록에 남김)
+    fn init_array(
+        &mut self,
+        elem: &TypeSpecifier,
+        size: Option<usize>,
+        items: &[Expr],
+        pos: &mut usize,
+        offset: usize,
+        elements: &mut Vec<InitElement>,
+    ) -> AnalyzeResult<usize> {
+        let stride = elem.size().unwrap_or(0);
+        let mut count = 0;
+        while *pos < items.len() && size.is_none_or(|n| count < n) {
+            self.init_object(elem, items, pos, offset + count * stride, elements)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// items[pos] 부터 ty 타입 객체 하나를 초기화
+    fn init_object(
+        &mut self,
+        ty: &TypeSpecifier,
+        items: &[Expr],
+        pos: &mut usize,
+        offset: usize,
+        elements: &mut Vec<InitElement>,
+    ) -> AnalyzeResult<()> {
+        let item = &items[*pos];
+        match (ty.unqualified(), item) {
+            // 하위 배열의 중괄호: 안쪽 목록은 하위 배열만 채움
+            (TypeSpecifier::Array(elem, size), InitializerList(sub)) => {
+                *pos += 1;
+                let mut inner = 0;
+                self.init_array(elem, *size, sub, &mut inner, offset, elements)?;
+                if inner < sub.len() {
+                    return Err(TooManyInitializers { ty: ty.clone() });
+                }
+            }
+            (TypeSpecifier::Array(elem, size), StringLiteral(_))
+                if *elem.unqualified() == TypeSpecifier::Char =>
+            {
+                *pos += 1;
+                self.init_string(ty, *size, item, offset, elements)?;
+            }
+            // 중괄호 생략: 바깥 목록에서 하위 배열 크기만큼 이어서 소비
+            (TypeSpecifier::Array(elem, size), _) => {
+                self.init_array(elem, *size, items, pos, offset, elements)?;
+            }
+            (_, InitializerList(_)) => {
+                return Err(ScalarBraceInitializer { ty: ty.clone() });
+            }
+            _ => {
+                *pos += 1;
+                self.init_scalar(ty, item, offset, elements)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// char 배열을 문자열 리터럴로 초기화하고 채운 원소 수를 반환
+    /// ('\0' 이 들어갈 자리가 없으면 생략, 문자 자체가 넘치면 에러)
+    fn init_string(
+        &mut self,
+        ty: &TypeSpecifier,
+        size: Option<usize>,
+        init: &Expr,
+        offset: usize,
+        elements: &mut Vec<InitElement>,
+    ) -> AnalyzeResult<usize> {
+        self.check_expr(init)?;
+        let StringLiteral(s) = init else {
+            unreachable!()
+        };
+        if size.is_some_and(|n| s.len() > n) {
+            return Err(TooManyInitializers { ty: ty.clone() });
+        }
+        let count = size.map_or(s.len() + 1, |n| n.min(s.len() + 1));
+        for (i, value) in s.bytes().chain(std::iter::once(0)).take(count).enumerate() {
+            elements.push(InitElement::Byte {
+                offset: offset + i,
+                value,
+            });
+        }
+        Ok(count)
+    }
+
+    fn init_scalar(
+        &mut self,
+        ty: &TypeSpecifier,
+        init: &Expr,
+        offset: usize,
+        elements: &mut Vec<InitElement>,
+    ) -> AnalyzeResult<()> {
+        let found = self.check_value(init)?;
+        self.check_assignable(ty, init, found)?;
+        elements.push(InitElement::Value {
+            offset,
+            ty: ty.unqualified().clone(),
+            expr: init.id(),
+        });
+        Ok(())
+    }
+}
+
+/// 배열 타입의 길이를 정함 (한정자는 유지)
+fn with_length(ty: &TypeSpecifier, length: usize) -> TypeSpecifier {
+    match ty {
+        TypeSpecifier::Qualified(quals, inner) => {
+            TypeSpecifier::Qualified(*quals, Box::new(with_length(inner, length)))
+        }
+        TypeSpecifier::Array(elem, _) => TypeSpecifier::Array(elem.clone(), Some(length)),
+        other => other.clone(),
+    }
+}
+
+/// 원소 사이의 빈 곳과 끝부분을 Zero 구간으로 채움
+fn fill_zeros(elements: Vec<InitElement>, size: usize) -> Vec<InitElement> {
+    let mut filled = Vec::with_capacity(elements.len() + 1);
+    let mut cursor = 0;
+    for element in elements {
+        if element.offset() > cursor {
+            filled.push(InitElement::Zero {
+                offset: cursor,
+                size: element.offset() - cursor,
+            });
+        }
+        cursor = element.offset() + element.size();
+        filled.push(element);
+    }
+    if size > cursor {
+        filled.push(InitElement::Zero {
+            offset: cursor,
+            size: size - cursor,
+        });
+    }
+    filled
+}
@@ -1,4 +1,10 @@
+mod bounds;
+pub mod conversion;
+mod format;
+mod initializer;
 pub mod type_checker;
 mod utils;
+pub(crate) use utils::type_name;
+pub mod value_category;
 
 pub use type_checker::TypeChecker;
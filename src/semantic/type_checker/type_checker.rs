@@ -1,40 +1,123 @@
 use crate::ast::Expr::*;
 use crate::ast::Stmt::*;
-use crate::ast::TypeSpecifier::{Char, Int, Pointer};
+use crate::ast::TypeSpecifier::{Char, Double, Float, Int};
+use crate::ast::expr::AssignOp;
 use crate::ast::expr::BinaryOp::*;
 use crate::ast::expr::PrefixOp::*;
-use crate::ast::{Expr, Program, Stmt, TypeSpecifier};
+use crate::ast::{
+    Expr, ExprId, ExternalDecl, Function, Program, Stmt, StorageClass, TypeSpecifier,
+};
+use crate::consteval::{ConstError, eval_int};
 use crate::semantic::analyzer::AnalyzeResult;
 use crate::semantic::analyzer::SemanticError::*;
-use crate::semantic::resolver::{ResolveError, Resolver};
+use crate::semantic::analyzer::SemanticWarning;
+use crate::semantic::model::{ExprInfo, FrameLayout, SemanticModel};
+use crate::semantic::resolver::Resolver;
+use crate::semantic::type_checker::conversion::{
+    AssignConversion, ConversionKind, ImplicitConversion, arithmetic_conversion,
+    assignment_conversion, comparable_pointers, default_argument_promotion, discards_qualifiers,
+    integer_promotion, is_null_pointer_constant, pointee_size, usual_arithmetic_conversion,
+};
+use crate::semantic::type_checker::utils::{
+    check_derived_type, check_function_declarator, check_object_type, decay, function_signature,
+    is_arithmetic, is_arithmetic_constant, is_incomplete_array, is_integer, is_pointer, is_scalar,
+    is_void_object,
+};
+use crate::semantic::type_checker::value_category::{ValueCategory, value_category};
 
-pub struct TypeChecker<'a> {
+pub struct TypeChecker<'a, 'p> {
     pub resolver: &'a mut Resolver,
     pub current_ret_ty: Option<TypeSpecifier>,
+    pub warnings: Vec<SemanticWarning>,
+    pub model: SemanticModel<'p>,
+    pub frame: Option<FrameLayout>, // 검사 중인 함수의 스택 프레임
+    pub address_operand: Option<ExprId>, // 검사 중인 & 의 피연산자 (&a[n] 허용)
 }
 
-impl<'a> TypeChecker<'a> {
+impl<'a, 'p> TypeChecker<'a, 'p> {
+    /// 이름 해석과 타입 검사를 한 번의 순회로 수행
+    /// 외부 선언은 원본 순서대로 등록·검사하므로 이름은 선언 뒤에서만 쓸 수 있음
+    /// - 전역 변수: 선언 후 초기화식 검사
+    /// - 함수: 선언을 등록한 뒤 (정의라면) 본문 검사 (재귀 호출 허용)
     pub fn check_program(&mut self, prog: &Program) -> AnalyzeResult<()> {
         self.current_ret_ty = None;
+        for decl in &prog.order {
+            match *decl {
+                ExternalDecl::Global(index) => self.check_global(&prog.globals[index])?,
+                ExternalDecl::Function(index) => self.check_function(&prog.functions[index])?,
+            }
+        }
+        self.resolver.check_undefined_symbols()
+    }
 
-        for func in &prog.functions {
-            self.current_ret_ty = Some(func.return_ty.clone());
-            // 새 스코프
-            self.resolver.push_scope();
-            // 파라미터를 로컬 변수로 선언
-            for param in &func.params {
-                self.resolver
-                    .declare_variable(&param.name, &param.ty)
-                    .map_err(|_| DuplicateDeclaration {
-                        name: param.name.clone(),
-                    })?;
-            }
-            // 본문 검사
-            self.check_block(&func.body.statements)?;
-            self.resolver.pop_scope();
+    fn check_global(&mut self, global: &Stmt) -> AnalyzeResult<()> {
+        let Declaration {
+            storage,
+            declarators,
+            ..
+        } = global
+        else {
+            return Ok(());
+        };
+        for d in declarators {
+            // int x, h(int); 의 h 는 함수 프로토타입
+            if check_function_declarator(d)? {
+                self.resolver.declare_function_declarator(d, *storage)?;
+                continue;
+            }
+            check_object_type(&d.name, &d.ty)?;
+            self.resolver.declare_global(d, *storage)?;
+            if let Some(init) = &d.init {
+                let initializer = self.check_initializer(&d.name, &d.ty, init)?;
+                self.check_constant_initializer(&d.name, init)?;
+                if let Some(id) = self.resolver.table.resolve_global(&d.name) {
+                    self.complete_array_type(id, &initializer.ty);
+                }
+                self.model.initializers.insert(d.id(), initializer);
+            }
+        }
+        Ok(())
+    }
 
-            self.current_ret_ty = None;
+    fn check_function(&mut self, func: &Function) -> AnalyzeResult<()> {
+        // void 는 (void) 처럼 매개변수가 없다는 표시로만 쓸 수 있음
+        if func.params.iter().any(|param| is_void_object(&param.ty)) {
+            return Err(VoidParameter {
+                function: func.name.clone(),
+            });
         }
+        check_derived_type(
+            &func.name,
+            &TypeSpecifier::Function {
+                ret: Box::new(func.return_ty.clone()),
+                params: func.params.iter().map(|param| param.ty.clone()).collect(),
+                is_variadic: func.is_variadic,
+            },
+        )?;
+        self.resolver.declare_function(func)?;
+
+        // 프로토타입은 본문 없음
+        let Some(body) = &func.body else {
+            return Ok(());
+        };
+        self.current_ret_ty = Some(func.return_ty.clone());
+        // 매개변수와 본문 가장 바깥 블록은 같은 스코프
+        self.resolver.push_scope();
+        let mut frame = FrameLayout::default();
+        for param in &func.params {
+            self.resolver.declare_parameter(param)?;
+            if let Some(name) = &param.name {
+                frame.allocate(param.id(), name, &param.ty);
+            }
+        }
+        self.frame = Some(frame);
+        self.check_block(&body.statements)?;
+        self.resolver.pop_scope();
+        if let Some(mut frame) = self.frame.take() {
+            frame.finish();
+            self.model.frames.insert(func.name.clone(), frame);
+        }
+        self.current_ret_ty = None;
         Ok(())
     }
 
@@ -47,47 +130,75 @@ impl<'a> TypeChecker<'a> {
 
     fn check_stmt(&mut self, stmt: &Stmt) -> AnalyzeResult<()> {
         match stmt {
-            Declaration { ty, declarators } => {
+            Declaration {
+                storage,
+                declarators,
+                ..
+            } => {
                 for d in declarators {
-                    // 초기화식 타입 검사
-                    if let Some(init) = &d.init {
-                        let found = self.check_expr(init)?;
-                        if &found != ty {
-                            return Err(TypeMismatch {
-                                expected: ty.clone(),
-                                found,
+                    // 블록 안 함수 선언은 객체가 아니라 전역 함수를 가리킴 (프레임에 두지 않음)
+                    if check_function_declarator(d)? {
+                        if *storage == Some(StorageClass::Static) {
+                            return Err(StaticLocalFunction {
+                                name: d.name.clone(),
                             });
                         }
+                        self.resolver.declare_local_function(d)?;
+                        continue;
                     }
-
-                    let var_ty = if d.array_size.is_some() {
-                        Pointer(Box::new(ty.clone()))
-                    } else {
-                        ty.clone()
-                    };
-
-                    self.resolver
-                        .declare_variable(&d.name, &var_ty)
-                        .map_err(|_| DuplicateDeclaration {
+                    // 블록 안 extern 선언은 다른 곳의 정의를 가리키므로 초기화할 수 없음
+                    if *storage == Some(StorageClass::Extern) && d.init.is_some() {
+                        return Err(ExternInitializer {
+                            name: d.name.clone(),
+                        });
+                    }
+                    check_object_type(&d.name, &d.ty)?;
+                    // 선언자의 스코프는 초기화식보다 먼저 시작
+                    let id = self.resolver.declare_local(d, *storage)?;
+                    let mut ty = d.ty.clone();
+                    if let Some(init) = &d.init {
+                        let initializer = self.check_initializer(&d.name, &d.ty, init)?;
+                        // static 지역 변수는 프로그램 시작 전에 초기화되므로 상수식만 허용
+                        if *storage == Some(StorageClass::Static) {
+                            self.check_constant_initializer(&d.name, init)?;
+                        }
+                        ty = initializer.ty.clone();
+                        self.complete_array_type(id, &ty);
+                        self.model.initializers.insert(d.id(), initializer);
+                    } else if storage.is_none() && is_incomplete_array(&ty) {
+                        return Err(IncompleteArray {
                             name: d.name.clone(),
-                        })?;
+                        });
+                    }
+                    // 자동 지역 변수만 스택 프레임에 배치
+                    if storage.is_none()
+                        && let Some(frame) = &mut self.frame
+                    {
+                        frame.allocate(d.id(), &d.name, &ty);
+                    }
                 }
             }
+            // 식 문장의 값은 버려지므로 void 식도 허용
             ExprStmt(opt) => {
                 if let Some(e) = opt {
                     let _ = self.check_expr(e)?;
                 }
             }
             Return(opt) => {
-                if let Some(e) = opt {
-                    let found = self.check_expr(e)?;
-                    let expected = self
-                        .current_ret_ty
-                        .clone()
-                        .expect("현재 함수 반환 타입이 없음");
-                    if found != expected && !(expected == Char && found == Int) {
-                        return Err(TypeMismatch { expected, found });
+                let expected = self
+                    .current_ret_ty
+                    .clone()
+                    .expect("현재 함수 반환 타입이 없음");
+                match opt {
+                    Some(e) => {
+                        let found = self.check_value(e)?;
+                        self.check_assignable(&expected, e, found)?;
                     }
+                    // non-void 함수의 'return;'
+                    None if *expected.unqualified() != TypeSpecifier::Void => {
+                        return Err(MissingReturnValue { expected });
+                    }
+                    None => {}
                 }
             }
             If {
@@ -95,29 +206,27 @@ impl<'a> TypeChecker<'a> {
                 then_branch,
                 else_branch,
             } => {
-                // 조건은 int
-                let ct = self.check_expr(cond)?;
-                if ct != TypeSpecifier::Int {
-                    return Err(TypeMismatch {
-                        expected: TypeSpecifier::Int,
-                        found: ct,
-                    });
+                // gcc 처럼 if ((x = y)) 로 괄호를 한 번 더 감싸면 의도한 대입으로 봄
+                if let Assignment {
+                    op: AssignOp::Assign,
+                    parenthesized: false,
+                    ..
+                } = cond
+                {
+                    self.warnings
+                        .push(SemanticWarning::AssignmentInCondition { expr: cond.id() });
                 }
+                self.check_condition(cond)?;
                 self.check_stmt(then_branch)?;
                 if let Some(eb) = else_branch {
                     self.check_stmt(eb)?;
                 }
             }
             While { cond, body } => {
-                // 조건은 int
-                let ct = self.check_expr(cond)?;
-                if ct != TypeSpecifier::Int {
-                    return Err(TypeMismatch {
-                        expected: TypeSpecifier::Int,
-                        found: ct,
-                    });
-                }
+                self.resolver.loop_depth += 1;
+                self.check_condition(cond)?;
                 self.check_stmt(body)?;
+                self.resolver.loop_depth -= 1;
             }
             For {
                 init,
@@ -125,67 +234,235 @@ impl<'a> TypeChecker<'a> {
                 step,
                 body,
             } => {
+                self.resolver.loop_depth += 1;
+                self.resolver.push_scope();
                 if let Some(i) = init {
                     self.check_stmt(i)?;
                 }
                 if let Some(c) = cond {
-                    // 조건문은 결과는 int
-                    let ct = self.check_expr(c)?;
-                    if ct != TypeSpecifier::Int {
-                        return Err(TypeMismatch {
-                            expected: TypeSpecifier::Int,
-                            found: ct,
-                        });
-                    }
+                    self.check_condition(c)?;
                 }
                 if let Some(s) = step {
                     let _ = self.check_expr(s)?;
                 }
                 self.check_stmt(body)?;
+                // for 에서 선언한 루프 변수는 스코프를 닫기 전에 찾아야 함
+                self.check_loop_bounds(init.as_deref(), cond.as_ref(), step.as_ref(), body);
+                self.resolver.pop_scope();
+                self.resolver.loop_depth -= 1;
             }
             Block(stmts) => {
                 self.resolver.push_scope();
                 self.check_block(&stmts.statements)?;
                 self.resolver.pop_scope();
             }
-            Break | Continue => {
-                // loop_depth 는 Resolver 에서 이미 검사
+            Break => self.resolver.check_jump(InvalidBreak)?,
+            Continue => self.resolver.check_jump(InvalidContinue)?,
+        }
+        Ok(())
+    }
+
+    /// 정적 저장 기간 변수의 초기화식은 상수식이어야 함
+    /// - 정수 상수식은 consteval 로 평가해 값을 의미 모델에 기록 (오버플로는 경고)
+    /// - 실수 리터럴로만 이루어진 산술식, 주소 상수(&x, 배열·함수 이름, 문자열 리터럴)도 허용
+    fn check_constant_initializer(&mut self, name: &str, init: &Expr) -> AnalyzeResult<()> {
+        if let InitializerList(items) = init {
+            for item in items {
+                self.check_constant_initializer(name, item)?;
             }
+            return Ok(());
+        }
+        let error = match eval_int(init) {
+            Ok(value) => {
+                self.model.constants.insert(init.id(), value);
+                return Ok(());
+            }
+            Err(ConstError::Overflow { wrapped, .. }) => {
+                self.model.constants.insert(init.id(), wrapped);
+                self.warnings.push(SemanticWarning::ConstantOverflow {
+                    expr: init.id(),
+                    wrapped,
+                });
+                return Ok(());
+            }
+            Err(ConstError::NotInteger | ConstError::NotConstant)
+                if is_arithmetic_constant(init) || self.is_address_constant(init) =>
+            {
+                return Ok(());
+            }
+            Err(error) => error,
+        };
+        Err(ConstantExpression {
+            name: name.to_string(),
+            error,
+        })
+    }
+
+    /// 링크 시간에 정해지는 주소: &x, 배열·함수 이름, 문자열 리터럴
+    fn is_address_constant(&self, expr: &Expr) -> bool {
+        match expr {
+            StringLiteral(_) => true,
+            UnaryPrefixOp { op: Address, rhs } => matches!(rhs.as_ref(), Ident(_)),
+            Ident(_) => matches!(
+                self.model.type_of(expr).map(|ty| ty.unqualified()),
+                Some(TypeSpecifier::Array(..) | TypeSpecifier::Function { .. })
+            ),
+            _ => false,
+        }
+    }
+
+    /// 대입 변환 검사: 불가능하면 에러, 필요한 암시적 변환은 기록,
+    /// 가리키는 타입의 한정자가 사라지면 경고
+    pub(super) fn check_assignable(
+        &mut self,
+        to: &TypeSpecifier,
+        expr: &Expr,
+        from: TypeSpecifier,
+    ) -> AnalyzeResult<()> {
+        match assignment_conversion(to, &from, is_null_pointer_constant(expr, &from)) {
+            AssignConversion::Incompatible => {
+                return Err(TypeMismatch {
+                    expected: to.clone(),
+                    found: from,
+                });
+            }
+            AssignConversion::Convert(kind) => {
+                self.record_conversion(expr, kind, &from, to.unqualified());
+            }
+            AssignConversion::Identity => {}
+        }
+        if discards_qualifiers(to, &from) {
+            self.warnings.push(SemanticWarning::DiscardedQualifiers {
+                expr: expr.id(),
+                expected: to.clone(),
+                found: from,
+            });
         }
         Ok(())
     }
 
-    fn check_expr(&mut self, expr: &Expr) -> AnalyzeResult<TypeSpecifier> {
+    /// 산술 피연산자를 to 타입으로 맞추는 변환 기록
+    fn convert_operand(&mut self, expr: &Expr, from: &TypeSpecifier, to: &TypeSpecifier) {
+        if let Some(kind) = arithmetic_conversion(from, to) {
+            self.record_conversion(expr, kind, from, to);
+        }
+    }
+
+    fn record_conversion(
+        &mut self,
+        expr: &Expr,
+        kind: ConversionKind,
+        from: &TypeSpecifier,
+        to: &TypeSpecifier,
+    ) {
+        self.model.conversions.push(ImplicitConversion {
+            expr: expr.id(),
+            kind,
+            from: from.clone(),
+            to: to.clone(),
+        });
+    }
+
+    /// 조건식(if, while, for)은 0 과 비교할 수 있는 스칼라 타입
+    fn check_condition(&mut self, cond: &Expr) -> AnalyzeResult<()> {
+        let ty = self.check_value(cond)?;
+        if !is_scalar(&ty) {
+            return Err(TypeMismatch {
+                expected: Int,
+                found: ty,
+            });
+        }
+        Ok(())
+    }
+
+    /// 값으로 쓰이는 식의 타입: 한정자를 벗기고 배열은 첫 원소를 가리키는 포인터로 decay
+    /// void 식(void 함수 호출)은 값이 없으므로 에러
+    pub(super) fn check_value(&mut self, expr: &Expr) -> AnalyzeResult<TypeSpecifier> {
+        let ty = self.check_expr(expr)?;
+        if *ty.unqualified() == TypeSpecifier::Void {
+            return Err(VoidValueUsed);
+        }
+        Ok(decay(ty.unqualified().clone()))
+    }
+
+    /// 값을 쓰는 대상(대입, ++, --)의 타입: 수정 가능한 lvalue 만 허용
+    fn check_modifiable(&mut self, expr: &Expr) -> AnalyzeResult<TypeSpecifier> {
+        let ty = self.check_expr(expr)?;
+        match value_category(expr, &ty) {
+            ValueCategory::Lvalue => {}
+            ValueCategory::ArrayLvalue => return Err(AssignToArray { found: ty }),
+            ValueCategory::FunctionDesignator | ValueCategory::Rvalue => {
+                return Err(NotAnLvalue { found: ty });
+            }
+        }
+        if ty.qualifiers().is_const {
+            return Err(AssignToConst { found: ty });
+        }
+        Ok(ty.unqualified().clone())
+    }
+
+    /// 식 타입 검사 후 타입과 값 범주를 의미 모델에 기록
+    pub(super) fn check_expr(&mut self, expr: &Expr) -> AnalyzeResult<TypeSpecifier> {
+        let ty = self.infer_expr(expr)?;
+        self.model.exprs.insert(
+            expr.id(),
+            ExprInfo {
+                ty: ty.clone(),
+                category: value_category(expr, &ty),
+            },
+        );
+        Ok(ty)
+    }
+
+    fn infer_expr(&mut self, expr: &Expr) -> AnalyzeResult<TypeSpecifier> {
         match expr {
             IntLiteral(_) => Ok(TypeSpecifier::Int),
+            FloatLiteral(_) => Ok(Float),
+            DoubleLiteral(_) => Ok(Double),
             CharLiteral(_) => Ok(Char),
+            // 문자열 리터럴은 '\0' 포함 char 배열
+            StringLiteral(s) => Ok(TypeSpecifier::Array(Box::new(Char), Some(s.len() + 1))),
             Ident(name) => {
-                let sym = self
-                    .resolver
-                    .resolve_identifier(name)
-                    .map_err(|_| UndefinedSymbol { name: name.clone() })?;
-                Ok(sym.ty.clone())
-            }
-            Assignment { left, right, op: _ } => {
-                let lt = self.check_expr(left)?;
-                let rt = self.check_expr(right)?;
-                if lt == rt {
-                    Ok(lt)
-                } else {
-                    Err(TypeMismatch {
-                        expected: lt,
-                        found: rt,
-                    })
+                let id = self.resolver.reference(expr, name)?;
+                Ok(self.resolver.table.symbol(id).value_ty())
+            }
+            Assignment {
+                left, right, op, ..
+            } => {
+                let lt = self.check_modifiable(left)?;
+                let rt = self.check_value(right)?;
+                match op.binary_op() {
+                    None => self.check_assignable(&lt, right, rt)?,
+                    // x op= y 는 x op y 를 계산한 뒤 x 의 타입으로 되돌림
+                    Some(bin) => {
+                        let result = self.check_binary(&bin, expr, left, lt.clone(), right, rt)?;
+                        self.check_assignable(&lt, expr, result)?;
+                    }
                 }
+                Ok(lt)
             }
 
             UnaryPrefixOp { op, rhs } => {
-                let ty = self.check_expr(rhs)?;
+                // 주소 연산자의 피연산자는 배열이어도 decay 하지 않음
+                let ty = match op {
+                    Address => {
+                        self.address_operand = Some(rhs.id());
+                        let ty = self.check_expr(rhs)?;
+                        if !value_category(rhs, &ty).is_addressable() {
+                            return Err(NotAnLvalue { found: ty });
+                        }
+                        ty
+                    }
+                    PreInc | PreDec => self.check_modifiable(rhs)?,
+                    _ => self.check_value(rhs)?,
+                };
                 match op {
-                    // -x, !x 는 int만
-                    Neg | Not => {
-                        if ty == TypeSpecifier::Int {
-                            Ok(TypeSpecifier::Int)
+                    // -x 는 산술 타입, 정수 승격 후 계산
+                    Neg => {
+                        if is_arithmetic(&ty) {
+                            let promoted = integer_promotion(&ty);
+                            self.convert_operand(rhs, &ty, &promoted);
+                            Ok(promoted)
                         } else {
                             Err(TypeMismatch {
                                 expected: TypeSpecifier::Int,
@@ -193,59 +470,55 @@ impl<'a> TypeChecker<'a> {
                             })
                         }
                     }
-                    Address => Ok(TypeSpecifier::Pointer(Box::new(ty))),
-                    Deref => {
-                        if let TypeSpecifier::Pointer(inner) = ty {
-                            Ok(*inner)
+                    // !x 는 스칼라 타입, 결과는 int
+                    Not => {
+                        if is_scalar(&ty) {
+                            Ok(TypeSpecifier::Int)
                         } else {
                             Err(TypeMismatch {
-                                expected: TypeSpecifier::Pointer(Box::new(TypeSpecifier::Int)),
+                                expected: TypeSpecifier::Int,
                                 found: ty,
                             })
                         }
                     }
-                    PreInc | PreDec => {
-                        if ty == TypeSpecifier::Int {
-                            Ok(TypeSpecifier::Int)
+                    Address => Ok(TypeSpecifier::Pointer(Box::new(ty))),
+                    Deref => {
+                        if let TypeSpecifier::Pointer(inner) = ty {
+                            if *inner.unqualified() == TypeSpecifier::Void {
+                                return Err(VoidPointerDereference);
+                            }
+                            Ok(*inner)
                         } else {
                             Err(TypeMismatch {
-                                expected: TypeSpecifier::Int,
+                                expected: TypeSpecifier::Pointer(Box::new(TypeSpecifier::Int)),
                                 found: ty,
                             })
                         }
                     }
+                    PreInc | PreDec => self.check_step(expr, ty),
                 }
             }
 
             UnaryPostfixOp { lhs, .. } => {
-                let ty = self.check_expr(lhs)?;
-                if ty != TypeSpecifier::Int {
-                    return Err(TypeMismatch {
-                        expected: TypeSpecifier::Int,
-                        found: ty,
-                    });
-                }
-
-                Ok(TypeSpecifier::Int)
+                let ty = self.check_modifiable(lhs)?;
+                self.check_step(expr, ty)
             }
 
             Call { func, args } => {
-                let function_name = if let Ident(name) = func.as_ref() {
-                    name
-                } else {
-                    unreachable!()
+                // callee 는 함수 이름이든 함수 포인터 식이든 함수 포인터로 decay
+                let callee_ty = self.check_value(func)?;
+                let Some((param_types, ret_ty, is_variadic)) = function_signature(&callee_ty)
+                else {
+                    return Err(match func.as_ref() {
+                        Ident(name) => NotAFunction { name: name.clone() },
+                        _ => NotCallable { found: callee_ty },
+                    });
                 };
 
-                let (param_types, ret_ty) =
-                    self.resolver
-                        .lookup_function(function_name)
-                        .map_err(|e| match e {
-                            ResolveError::UndefinedSymbol { name } => UndefinedSymbol { name },
-                            ResolveError::NotAFunciton { name } => NotAFunction { name },
-                        })?;
-
-                // 인자 갯수 검사
-                if args.len() != param_types.len() {
+                // 인자 갯수 검사 (가변 인자 함수는 고정 매개변수 이상)
+                if args.len() < param_types.len()
+                    || (!is_variadic && args.len() > param_types.len())
+                {
                     return Err(ArgumentCountMismatch {
                         expected: param_types.len(),
                         found: args.len(),
@@ -253,92 +526,227 @@ impl<'a> TypeChecker<'a> {
                 }
 
                 // 인자 타입 검사
+                let fixed = param_types.len();
                 for (arg, expected_ty) in args.iter().zip(param_types) {
-                    let actual_ty = self.check_expr(arg)?;
-                    if actual_ty != expected_ty {
+                    let actual_ty = self.check_value(arg)?;
+                    self.check_assignable(&expected_ty, arg, actual_ty)?;
+                }
+
+                // '...' 자리의 인자는 기본 인자 승격 후 산술/포인터 타입이어야 함
+                let mut variadic = Vec::new();
+                for arg in &args[fixed..] {
+                    let ty = self.check_value(arg)?;
+                    if !is_scalar(&ty) {
                         return Err(TypeMismatch {
-                            expected: expected_ty.clone(),
-                            found: actual_ty.clone(),
+                            expected: Int,
+                            found: ty,
                         });
                     }
+                    let promoted = default_argument_promotion(&ty);
+                    self.convert_operand(arg, &ty, &promoted);
+                    variadic.push(promoted);
+                }
+                if is_variadic {
+                    self.check_format_call(expr, func, args, &variadic);
                 }
 
                 Ok(ret_ty)
             }
             ArrayIndex { array, index } => {
-                let idx_ty = self.check_expr(index)?;
-                if idx_ty != TypeSpecifier::Int {
+                let idx_ty = self.check_value(index)?;
+                if !is_integer(&idx_ty) {
                     return Err(TypeMismatch {
                         expected: TypeSpecifier::Int,
                         found: idx_ty,
                     });
                 }
 
-                let arr_ty = self.check_expr(array)?;
-                match arr_ty {
-                    TypeSpecifier::Pointer(inner) => Ok(*inner),
-                    other => Err(ExpectedPointer { found: other }),
+                // 배열 길이를 알면 상수 인덱스의 범위를 검사
+                let array_ty = self.check_expr(array)?;
+                self.check_constant_index(expr, &array_ty, index)?;
+
+                // a[i] 는 *(a + i): 인덱스에 원소 크기를 곱함
+                let arr_ty = decay(array_ty.unqualified().clone());
+                let TypeSpecifier::Pointer(inner) = &arr_ty else {
+                    return Err(ExpectedPointer { found: arr_ty });
+                };
+                // p[i] 는 *(p + i) 이므로 void* 는 인덱싱할 수 없음
+                if *inner.unqualified() == TypeSpecifier::Void {
+                    return Err(VoidPointerDereference);
                 }
+                self.scale_index(index, &idx_ty, &arr_ty)?;
+                Ok(*inner.clone())
             }
-            InitializerList(es) => {
-                if es.is_empty() {
-                    return Ok(self.current_ret_ty.clone().unwrap());
-                }
+            // 중괄호 목록은 선언의 초기화식에만 올 수 있음
+            InitializerList(_) => Err(UnexpectedInitializerList),
+            BinaryOp { lhs, op, rhs } => {
+                let lt = self.check_value(lhs)?;
+                let rt = self.check_value(rhs)?;
+                self.check_binary(op, expr, lhs, lt, rhs, rt)
+            }
+        }
+    }
 
-                let first_ty = self.check_expr(&es[0])?;
-                for e in &es[1..] {
-                    let ty = self.check_expr(e)?;
-                    if ty != first_ty {
-                        return Err(TypeMismatch {
-                            expected: first_ty.clone(),
-                            found: ty,
-                        });
-                    }
+    /// 이항 연산 타입 검사 (복합 대입의 연산에도 사용)
+    /// 산술 피연산자는 정수 승격과 일반 산술 변환으로 공통 타입에 맞춤
+    fn check_binary(
+        &mut self,
+        op: &crate::ast::expr::BinaryOp,
+        expr: &Expr,
+        lhs: &Expr,
+        lt: TypeSpecifier,
+        rhs: &Expr,
+        rt: TypeSpecifier,
+    ) -> AnalyzeResult<TypeSpecifier> {
+        match op {
+            Add | Sub if is_pointer(&lt) || is_pointer(&rt) => {
+                self.check_pointer_arithmetic(op, expr, lhs, lt, rhs, rt)
+            }
+            Add | Sub | Mul | Div | Rem | BitAnd | BitOr | BitXor => {
+                // %, 비트 연산은 정수만
+                let operand_ok: fn(&TypeSpecifier) -> bool = match op {
+                    Add | Sub | Mul | Div => is_arithmetic,
+                    _ => is_integer,
+                };
+                if !operand_ok(&lt) || !operand_ok(&rt) {
+                    return Err(TypeMismatch {
+                        expected: Int,
+                        found: if operand_ok(&lt) { rt } else { lt },
+                    });
                 }
-
-                Ok(first_ty)
+                Ok(self.convert_arithmetic_operands(lhs, &lt, rhs, &rt))
             }
-            BinaryOp { lhs, op, rhs } => {
-                let lt = self.check_expr(lhs)?;
-                let rt = self.check_expr(rhs)?;
+            And | Or => {
+                if !is_scalar(&lt) || !is_scalar(&rt) {
+                    return Err(TypeMismatch {
+                        expected: Int,
+                        found: if is_scalar(&lt) { rt } else { lt },
+                    });
+                }
+                Ok(Int)
+            }
+            Eq | Ne | Lt | Le | Gt | Ge => {
+                if is_arithmetic(&lt) && is_arithmetic(&rt) {
+                    self.convert_arithmetic_operands(lhs, &lt, rhs, &rt);
+                } else if !comparable_pointers(
+                    &lt,
+                    &rt,
+                    is_null_pointer_constant(lhs, &lt),
+                    is_null_pointer_constant(rhs, &rt),
+                    matches!(op, Eq | Ne),
+                ) {
+                    return Err(TypeMismatch {
+                        expected: lt,
+                        found: rt,
+                    });
+                }
+                Ok(Int)
+            }
+        }
+    }
 
-                match op {
-                    Add | Sub | Mul | Div | Rem | BitAnd | BitOr | BitXor => {
-                        if !matches!(lt, Int | Char) || !matches!(rt, Int | Char) {
-                            return Err(TypeMismatch {
-                                expected: Int,
-                                found: if lt != Int { lt } else { rt },
-                            });
-                        }
-                        Ok(Int)
-                        // if lt != TypeSpecifier::Int || rt != TypeSpecifier::Int {
-                        //     return Err(TypeMismatch {
-                        //         expected: TypeSpecifier::Int,
-                        //         found: if lt != TypeSpecifier::Int { lt } else { rt },
-                        //     });
-                        // }
-                        // Ok(TypeSpecifier::Int)
-                    }
-                    And | Or => {
-                        if lt != TypeSpecifier::Int || rt != TypeSpecifier::Int {
-                            return Err(TypeMismatch {
-                                expected: TypeSpecifier::Int,
-                                found: if lt != TypeSpecifier::Int { lt } else { rt },
-                            });
-                        }
-                        Ok(TypeSpecifier::Int)
-                    }
-                    Eq | Ne | Lt | Le | Gt | Ge => {
-                        if lt != rt {
-                            return Err(TypeMismatch {
-                                expected: lt.clone(),
-                                found: rt,
-                            });
-                        }
-                        Ok(TypeSpecifier::Int)
-                    }
+    /// 포인터 산술
+    /// - 포인터 ± 정수, 정수 + 포인터: 포인터 (정수에 원소 크기를 곱함)
+    /// - 포인터 - 포인터: 가리키는 타입이 같아야 하며 결과는 원소 개수 차이 (int)
+    fn check_pointer_arithmetic(
+        &mut self,
+        op: &crate::ast::expr::BinaryOp,
+        expr: &Expr,
+        lhs: &Expr,
+        lt: TypeSpecifier,
+        rhs: &Expr,
+        rt: TypeSpecifier,
+    ) -> AnalyzeResult<TypeSpecifier> {
+        match (&lt, &rt) {
+            (TypeSpecifier::Pointer(l), TypeSpecifier::Pointer(r)) if *op == Sub => {
+                if l.unqualified() != r.unqualified() {
+                    return Err(TypeMismatch {
+                        expected: lt,
+                        found: rt,
+                    });
                 }
+                let Some(element_size) = pointee_size(&lt) else {
+                    return Err(InvalidPointerArithmetic { found: lt });
+                };
+                self.record_conversion(
+                    expr,
+                    ConversionKind::PointerDifference { element_size },
+                    &lt,
+                    &Int,
+                );
+                Ok(Int)
+            }
+            (TypeSpecifier::Pointer(_), index) if is_integer(index) => {
+                self.scale_index(rhs, &rt, &lt)?;
+                Ok(lt)
             }
+            (index, TypeSpecifier::Pointer(_)) if is_integer(index) && *op == Add => {
+                self.scale_index(lhs, &lt, &rt)?;
+                Ok(rt)
+            }
+            _ => Err(TypeMismatch {
+                expected: Int,
+                found: if is_pointer(&lt) { rt } else { lt },
+            }),
+        }
+    }
+
+    /// 포인터에 더해지는 정수 피연산자: 정수 승격 후 원소 크기를 곱함
+    fn scale_index(
+        &mut self,
+        index: &Expr,
+        idx_ty: &TypeSpecifier,
+        ptr_ty: &TypeSpecifier,
+    ) -> AnalyzeResult<()> {
+        let Some(element_size) = pointee_size(ptr_ty) else {
+            return Err(InvalidPointerArithmetic {
+                found: ptr_ty.clone(),
+            });
+        };
+        self.convert_operand(index, idx_ty, &integer_promotion(idx_ty));
+        self.record_conversion(
+            index,
+            ConversionKind::ScaledIndex { element_size },
+            &Int,
+            &Int,
+        );
+        Ok(())
+    }
+
+    /// ++, -- 의 대상: 산술 타입 또는 크기 있는 대상을 가리키는 포인터
+    fn check_step(&mut self, expr: &Expr, ty: TypeSpecifier) -> AnalyzeResult<TypeSpecifier> {
+        if is_arithmetic(&ty) {
+            return Ok(ty);
+        }
+        if !is_pointer(&ty) {
+            return Err(TypeMismatch {
+                expected: Int,
+                found: ty,
+            });
         }
+        let Some(element_size) = pointee_size(&ty) else {
+            return Err(InvalidPointerArithmetic { found: ty });
+        };
+        self.record_conversion(
+            expr,
+            ConversionKind::ScaledIndex { element_size },
+            &Int,
+            &Int,
+        );
+        Ok(ty)
+    }
+
+    /// 일반 산술 변환: 두 피연산자를 공통 타입으로 맞추고 그 타입을 반환
+    fn convert_arithmetic_operands(
+        &mut self,
+        lhs: &Expr,
+        lt: &TypeSpecifier,
+        rhs: &Expr,
+        rt: &TypeSpecifier,
+    ) -> TypeSpecifier {
+        let common = usual_arithmetic_conversion(&integer_promotion(lt), &integer_promotion(rt));
+        self.convert_operand(lhs, lt, &common);
+        self.convert_operand(rhs, rt, &common);
+        common
     }
 }
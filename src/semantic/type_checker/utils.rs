@@ -1,11 +1,209 @@
+use crate::ast::expr::PrefixOp;
+use crate::ast::stmt::Declarator;
+use crate::ast::{Expr, TypeSpecifier};
+use crate::semantic::analyzer::AnalyzeResult;
+use crate::semantic::analyzer::SemanticError;
+use crate::semantic::model::SemanticModel;
 use crate::semantic::resolver::resolver::Resolver;
 use crate::semantic::type_checker::type_checker::TypeChecker;
 
-impl<'a> TypeChecker<'a> {
+impl<'a, 'p> TypeChecker<'a, 'p> {
     pub fn new(resolver: &'a mut Resolver) -> Self {
         TypeChecker {
             resolver,
             current_ret_ty: None,
+            warnings: Vec::new(),
+            model: SemanticModel::default(),
+            frame: None,
+            address_operand: None,
         }
     }
 }
+
+/// 정수 타입 (int, char)
+pub fn is_integer(ty: &TypeSpecifier) -> bool {
+    matches!(ty, TypeSpecifier::Int | TypeSpecifier::Char)
+}
+
+/// 실수 타입 (float, double)
+pub fn is_floating(ty: &TypeSpecifier) -> bool {
+    matches!(ty, TypeSpecifier::Float | TypeSpecifier::Double)
+}
+
+/// 산술 타입 (정수 + 실수)
+pub fn is_arithmetic(ty: &TypeSpecifier) -> bool {
+    is_integer(ty) || is_floating(ty)
+}
+
+/// 배열 -> 첫 원소 포인터, 함수 -> 함수 포인터 (decay)
+pub fn decay(ty: TypeSpecifier) -> TypeSpecifier {
+    match ty {
+        TypeSpecifier::Array(elem, _) => TypeSpecifier::Pointer(elem),
+        func @ TypeSpecifier::Function { .. } => TypeSpecifier::Pointer(Box::new(func)),
+        other => other,
+    }
+}
+
+/// 함수 포인터 타입이면 (매개변수 타입들, 반환 타입, 가변 인자 여부)
+pub fn function_signature(ty: &TypeSpecifier) -> Option<(Vec<TypeSpecifier>, TypeSpecifier, bool)> {
+    match ty {
+        TypeSpecifier::Pointer(inner) => match inner.as_ref() {
+            TypeSpecifier::Function {
+                ret,
+                params,
+                is_variadic,
+            } => Some((params.clone(), *ret.clone(), *is_variadic)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// 스칼라 타입 (산술 + 포인터): 조건식, &&, ||, ! 의 피연산자
+pub fn is_scalar(ty: &TypeSpecifier) -> bool {
+    is_arithmetic(ty) || is_pointer(ty)
+}
+
+/// 포인터 타입
+pub fn is_pointer(ty: &TypeSpecifier) -> bool {
+    matches!(ty, TypeSpecifier::Pointer(_))
+}
+
+/// 리터럴과 산술 연산으로만 이루어진 식 (1.5 * 2 처럼 실수가 섞인 상수식 포함)
+pub fn is_arithmetic_constant(expr: &Expr) -> bool {
+    match expr {
+        Expr::IntLiteral(_)
+        | Expr::CharLiteral(_)
+        | Expr::FloatLiteral(_)
+        | Expr::DoubleLiteral(_) => true,
+        Expr::UnaryPrefixOp { op, rhs } => {
+            matches!(op, PrefixOp::Neg | PrefixOp::Not) && is_arithmetic_constant(rhs)
+        }
+        Expr::BinaryOp { lhs, rhs, .. } => {
+            is_arithmetic_constant(lhs) && is_arithmetic_constant(rhs)
+        }
+        _ => false,
+    }
+}
+
+/// 크기를 모르는 배열 (int a[])
+pub fn is_incomplete_array(ty: &TypeSpecifier) -> bool {
+    matches!(ty.unqualified(), TypeSpecifier::Array(_, None))
+}
+
+/// 함수 타입 선언자인지 (int h(int);): 함수 선언에는 초기화식이 올 수 없음
+pub fn check_function_declarator(declarator: &Declarator) -> AnalyzeResult<bool> {
+    if !matches!(declarator.ty, TypeSpecifier::Function { .. }) {
+        return Ok(false);
+    }
+    if declarator.init.is_some() {
+        return Err(SemanticError::FunctionInitializer {
+            name: declarator.name.clone(),
+        });
+    }
+    check_derived_type(&declarator.name, &declarator.ty)?;
+    Ok(true)
+}
+
+/// void 객체 또는 void 원소 배열 (값을 담을 수 없는 타입)
+pub fn is_void_object(ty: &TypeSpecifier) -> bool {
+    match ty.unqualified() {
+        TypeSpecifier::Void => true,
+        TypeSpecifier::Array(elem, _) => is_void_object(elem),
+        _ => false,
+    }
+}
+
+/// 변수 선언의 타입 검사: void x; void a[3]; 은 에러
+pub fn check_object_type(name: &str, ty: &TypeSpecifier) -> AnalyzeResult<()> {
+    if is_void_object(ty) {
+        return Err(SemanticError::VoidObject {
+            name: name.to_string(),
+        });
+    }
+    check_derived_type(name, ty)
+}
+
+/// 함수 원소 배열, 배열이나 함수를 반환하는 함수가 타입 어디에든 있으면 에러
+/// (포인터를 거치면 허용: int (*fp[3])(int), int (*f(void))[3])
+pub fn check_derived_type(name: &str, ty: &TypeSpecifier) -> AnalyzeResult<()> {
+    if is_invalid_derived(ty) {
+        return Err(SemanticError::InvalidDerivedType {
+            name: name.to_string(),
+            ty: ty.clone(),
+        });
+    }
+    Ok(())
+}
+
+fn is_invalid_derived(ty: &TypeSpecifier) -> bool {
+    match ty.unqualified() {
+        TypeSpecifier::Pointer(inner) => is_invalid_derived(inner),
+        TypeSpecifier::Array(elem, _) => {
+            matches!(elem.unqualified(), TypeSpecifier::Function { .. }) || is_invalid_derived(elem)
+        }
+        TypeSpecifier::Function { ret, params, .. } => {
+            matches!(
+                ret.unqualified(),
+                TypeSpecifier::Array(..) | TypeSpecifier::Function { .. }
+            ) || is_invalid_derived(ret)
+                || params.iter().any(is_invalid_derived)
+        }
+        _ => false,
+    }
+}
+
+/// 경고 메시지용 C 표기 (const char *, char *const, int (*)(int), int [3])
+pub fn type_name(ty: &TypeSpecifier) -> String {
+    match ty {
+        TypeSpecifier::Int => "int".to_string(),
+        TypeSpecifier::Char => "char".to_string(),
+        TypeSpecifier::Void => "void".to_string(),
+        TypeSpecifier::Float => "float".to_string(),
+        TypeSpecifier::Double => "double".to_string(),
+        TypeSpecifier::Qualified(quals, inner) => {
+            let mut names = Vec::new();
+            if quals.is_const {
+                names.push("const");
+            }
+            if quals.is_volatile {
+                names.push("volatile");
+            }
+            let quals = names.join(" ");
+            // 포인터 자체의 한정자는 * 뒤에 붙음
+            match inner.as_ref() {
+                TypeSpecifier::Pointer(_) => format!("{}{quals}", type_name(inner)),
+                _ => format!("{quals} {}", type_name(inner)),
+            }
+        }
+        TypeSpecifier::Pointer(inner) => match inner.as_ref() {
+            TypeSpecifier::Function { .. } | TypeSpecifier::Array(..) => derived_name(inner, "(*)"),
+            _ => format!("{} *", type_name(inner)),
+        },
+        TypeSpecifier::Array(..) | TypeSpecifier::Function { .. } => derived_name(ty, ""),
+    }
+}
+
+/// 배열/함수 타입 표기: 원소·반환 타입 뒤에 선언자 자리(inner, 포인터면 "(*)")와 [n] 또는 (매개변수)
+fn derived_name(ty: &TypeSpecifier, inner: &str) -> String {
+    match ty {
+        TypeSpecifier::Array(elem, length) => {
+            let length = length.map_or(String::new(), |n| n.to_string());
+            format!("{} {inner}[{length}]", type_name(elem))
+        }
+        TypeSpecifier::Function {
+            ret,
+            params,
+            is_variadic,
+        } => {
+            let mut params: Vec<_> = params.iter().map(type_name).collect();
+            if *is_variadic {
+                params.push("...".to_string());
+            } else if params.is_empty() {
+                params.push("void".to_string());
+            }
+            format!("{} {inner}({})", type_name(ret), params.join(", "))
+        }
+        other => type_name(other),
+    }
+}
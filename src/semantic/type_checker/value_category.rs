@@ -0,0 +1,41 @@
+use crate::ast::expr::PrefixOp;
+use crate::ast::{Expr, TypeSpecifier};
+
+/// 식의 값 범주
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueCategory {
+    Lvalue,             // 객체를 가리키는 식: 대입, &, ++/-- 의 대상
+    ArrayLvalue,        // 배열 객체: & 는 가능하지만 대입할 수 없음
+    FunctionDesignator, // 함수 이름, *fp: & 만 가능
+    Rvalue,             // 계산 결과 값
+}
+
+impl ValueCategory {
+    /// & 의 피연산자가 될 수 있는지
+    pub fn is_addressable(self) -> bool {
+        self != ValueCategory::Rvalue
+    }
+}
+
+/// 식과 그 (decay 전) 타입으로 값 범주 결정
+/// ex) x, *p, a[i], "abc" 는 lvalue / x + 1, f(), x = 1, ++x 는 rvalue
+pub fn value_category(expr: &Expr, ty: &TypeSpecifier) -> ValueCategory {
+    let designates_object = matches!(
+        expr,
+        Expr::Ident(_)
+            | Expr::ArrayIndex { .. }
+            | Expr::StringLiteral(_)
+            | Expr::UnaryPrefixOp {
+                op: PrefixOp::Deref,
+                ..
+            }
+    );
+    if !designates_object {
+        return ValueCategory::Rvalue;
+    }
+    match ty.unqualified() {
+        TypeSpecifier::Array(..) => ValueCategory::ArrayLvalue,
+        TypeSpecifier::Function { .. } => ValueCategory::FunctionDesignator,
+        _ => ValueCategory::Lvalue,
+    }
+}
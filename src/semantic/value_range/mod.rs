@@ -0,0 +1,3 @@
+pub mod value_range;
+
+pub use value_range::ValueRange;
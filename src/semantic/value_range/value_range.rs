@@ -0,0 +1,821 @@
+use crate::ast::expr::{AssignOp, BinaryOp, PostfixOp, PrefixOp};
+use crate::ast::{DeclId, Expr, ExprId, Function, Stmt, TypeSpecifier};
+use crate::consteval::char_value;
+use crate::semantic::analyzer::SemanticWarning;
+use crate::semantic::definite_init::definite_init::escaped_variables;
+use crate::semantic::model::SemanticModel;
+use crate::semantic::symbol::symbol::{Linkage, SymbolId};
+use std::collections::{HashMap, HashSet};
+
+/// 위젠닝 없이 반복문 머리 상태를 합치는 횟수 (짧은 루프는 정확한 범위로 수렴)
+const WIDEN_DELAY: usize = 3;
+
+/// 정수 값이 가질 수 있는 범위 [lo, hi] (None 은 그쪽 한계를 모름)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Interval {
+    lo: Option<i64>,
+    hi: Option<i64>,
+}
+
+const UNKNOWN: Interval = Interval { lo: None, hi: None };
+const BOOL: Interval = Interval {
+    lo: Some(0),
+    hi: Some(1),
+};
+const INT: Interval = Interval {
+    lo: Some(i32::MIN as i64),
+    hi: Some(i32::MAX as i64),
+};
+const CHAR: Interval = Interval {
+    lo: Some(i8::MIN as i64),
+    hi: Some(i8::MAX as i64),
+};
+
+impl Interval {
+    fn constant(value: i64) -> Self {
+        Interval {
+            lo: Some(value),
+            hi: Some(value),
+        }
+    }
+
+    /// 양쪽 한계를 모두 알 때만 (lo, hi)
+    fn bounds(self) -> Option<(i64, i64)> {
+        Some((self.lo?, self.hi?))
+    }
+
+    fn contains_zero(self) -> bool {
+        self.lo.is_none_or(|lo| lo <= 0) && self.hi.is_none_or(|hi| hi >= 0)
+    }
+
+    /// 범위가 target 안에 들어가는지 (모르는 한계는 넘지 않는 것으로 봄)
+    fn fits(self, target: Interval) -> bool {
+        self.lo
+            .is_none_or(|lo| target.lo.is_none_or(|min| lo >= min))
+            && self
+                .hi
+                .is_none_or(|hi| target.hi.is_none_or(|max| hi <= max))
+    }
+
+    /// 분기 합류점: 두 범위를 모두 덮는 범위
+    fn join(self, other: Interval) -> Self {
+        Interval {
+            lo: self.lo.zip(other.lo).map(|(a, b)| a.min(b)),
+            hi: self.hi.zip(other.hi).map(|(a, b)| a.max(b)),
+        }
+    }
+
+    /// 반복문 머리의 위젠닝: 넓어지는 쪽의 한계는 버림
+    fn widen(self, next: Interval) -> Self {
+        Interval {
+            lo: self.lo.zip(next.lo).filter(|(a, b)| b >= a).map(|(a, _)| a),
+            hi: self.hi.zip(next.hi).filter(|(a, b)| b <= a).map(|(a, _)| a),
+        }
+    }
+
+    /// 공통 부분 (비어 있으면 None)
+    fn intersect(self, other: Interval) -> Option<Self> {
+        let lo = match (self.lo, other.lo) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        let hi = match (self.hi, other.hi) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        match lo.zip(hi) {
+            Some((lo, hi)) if lo > hi => None,
+            _ => Some(Interval { lo, hi }),
+        }
+    }
+
+    fn neg(self) -> Self {
+        Interval {
+            lo: self.hi.map(|hi| hi.saturating_neg()),
+            hi: self.lo.map(|lo| lo.saturating_neg()),
+        }
+    }
+
+    fn add(self, other: Interval) -> Self {
+        Interval {
+            lo: self.lo.zip(other.lo).map(|(a, b)| a.saturating_add(b)),
+            hi: self.hi.zip(other.hi).map(|(a, b)| a.saturating_add(b)),
+        }
+    }
+
+    fn sub(self, other: Interval) -> Self {
+        self.add(other.neg())
+    }
+
+    /// i64 로도 넘치는 곱은 포화시킴 (어차피 int 범위 밖이라 오버플로로 보고됨)
+    fn mul(self, other: Interval) -> Self {
+        let (Some((a, b)), Some((c, d))) = (self.bounds(), other.bounds()) else {
+            return UNKNOWN;
+        };
+        corners([
+            a.saturating_mul(c),
+            a.saturating_mul(d),
+            b.saturating_mul(c),
+            b.saturating_mul(d),
+        ])
+    }
+
+    /// 나누는 수가 0 을 포함하지 않을 때만 범위를 계산 (C 의 0 방향 절삭)
+    fn div(self, other: Interval) -> Self {
+        let (Some((a, b)), Some((c, d))) = (self.bounds(), other.bounds()) else {
+            return UNKNOWN;
+        };
+        if other.contains_zero() {
+            return UNKNOWN;
+        }
+        corners([
+            a.saturating_div(c),
+            a.saturating_div(d),
+            b.saturating_div(c),
+            b.saturating_div(d),
+        ])
+    }
+
+    /// 나머지의 절댓값은 나누는 수보다 작고 부호는 나뉘는 수를 따름
+    fn rem(self, other: Interval) -> Self {
+        let Some((c, d)) = other.bounds() else {
+            return UNKNOWN;
+        };
+        if other.contains_zero() {
+            return UNKNOWN;
+        }
+        let m = c.saturating_abs().max(d.saturating_abs()) - 1;
+        match (self.lo, self.hi) {
+            (Some(lo), hi) if lo >= 0 => Interval {
+                lo: Some(0),
+                hi: Some(hi.map_or(m, |hi| hi.min(m))),
+            },
+            (lo, Some(hi)) if hi <= 0 => Interval {
+                lo: Some(lo.map_or(-m, |lo| lo.max(-m))),
+                hi: Some(0),
+            },
+            _ => Interval {
+                lo: Some(-m),
+                hi: Some(m),
+            },
+        }
+    }
+}
+
+/// 네 모서리 값의 범위 (i64 를 넘는 값은 포화시킨 상태)
+fn corners(values: [i64; 4]) -> Interval {
+    Interval {
+        lo: values.into_iter().min(),
+        hi: values.into_iter().max(),
+    }
+}
+
+/// 추적하는 정수 타입의 값 범위
+fn type_range(ty: &TypeSpecifier) -> Option<Interval> {
+    match ty.unqualified() {
+        TypeSpecifier::Int => Some(INT),
+        TypeSpecifier::Char => Some(CHAR),
+        _ => None,
+    }
+}
+
+/// 프로그램 한 지점에서 추적 변수들의 범위 (없는 변수는 범위를 모름)
+/// 기본값은 도달할 수 없는 상태
+#[derive(Debug, Clone, Default, PartialEq)]
+struct State {
+    reachable: bool,
+    ranges: HashMap<SymbolId, Interval>,
+}
+
+impl State {
+    fn entry() -> Self {
+        State {
+            reachable: true,
+            ranges: HashMap::new(),
+        }
+    }
+
+    fn get(&self, id: SymbolId) -> Interval {
+        self.ranges.get(&id).copied().unwrap_or(UNKNOWN)
+    }
+
+    fn set(&mut self, id: SymbolId, value: Interval) {
+        if value == UNKNOWN {
+            self.ranges.remove(&id);
+        } else {
+            self.ranges.insert(id, value);
+        }
+    }
+
+    fn join(&mut self, other: State) {
+        if !other.reachable {
+            return;
+        }
+        if !self.reachable {
+            *self = other;
+            return;
+        }
+        self.ranges.retain(|id, value| match other.ranges.get(id) {
+            Some(&other) => {
+                *value = value.join(other);
+                *value != UNKNOWN
+            }
+            None => false,
+        });
+    }
+
+    fn widen(&self, next: &State) -> State {
+        if !self.reachable {
+            return next.clone();
+        }
+        let mut widened = State::entry();
+        for (&id, &value) in &next.ranges {
+            widened.set(id, self.get(id).widen(value));
+        }
+        widened
+    }
+}
+
+/// 반복문을 빠져나가거나(break) 다음 반복으로 가는(continue) 경로의 상태
+#[derive(Default)]
+struct LoopExits {
+    breaks: State,
+    continues: State,
+}
+
+/// 함수 하나의 정수 값 범위 분석 (구간 추상 해석)
+///
+/// 자동 지역 int/char 변수와 매개변수의 범위를 분기 조건으로 좁히고 합류점에서 합치며,
+/// 반복문은 몇 바퀴 뒤 위젠닝으로 고정점을 구한 뒤 한 번 좁혀서 경고
+/// - 0 을 포함하는 나누는 수, int 범위를 넘는 덧셈·곱셈, 배열 길이를 벗어나는 인덱스
+/// - 양쪽 한계를 모르는 값(매개변수, 호출 결과 등)으로는 경고하지 않음
+/// - 주소가 취해진 변수와 static 지역 변수는 추적하지 않음
+pub struct ValueRange<'a> {
+    model: &'a SemanticModel<'a>,
+    locals: HashMap<DeclId, SymbolId>, // 추적 후보: 링크 없는 변수 선언
+    escaped: HashSet<SymbolId>,
+    tracked: HashSet<SymbolId>,
+    loops: Vec<LoopExits>,
+    reporting: bool, // 반복문 고정점 계산 중에는 경고하지 않음
+    reported: HashSet<ExprId>,
+    pub warnings: Vec<SemanticWarning>,
+}
+
+impl<'a> ValueRange<'a> {
+    pub fn new(model: &'a SemanticModel<'a>) -> Self {
+        let locals = model
+            .symbols
+            .symbols
+            .iter()
+            .enumerate()
+            .filter(|(_, symbol)| symbol.linkage == Linkage::None)
+            .map(|(index, symbol)| (symbol.decl, SymbolId(index)))
+            .collect();
+        ValueRange {
+            model,
+            locals,
+            escaped: HashSet::new(),
+            tracked: HashSet::new(),
+            loops: Vec::new(),
+            reporting: true,
+            reported: HashSet::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// 함수 하나를 분석해 경고를 warnings 에 추가
+    pub fn check(&mut self, function: &Function) {
+        let Some(body) = &function.body else {
+            return;
+        };
+        self.escaped = escaped_variables(self.model, &body.statements);
+        self.tracked.clear();
+        for param in &function.params {
+            if let Some(&id) = self.locals.get(&param.id()) {
+                self.track(id);
+            }
+        }
+        let mut state = State::entry();
+        for stmt in &body.statements {
+            self.check_stmt(stmt, &mut state);
+        }
+    }
+
+    fn track(&mut self, id: SymbolId) {
+        if !self.escaped.contains(&id) && type_range(&self.model.symbol(id).ty).is_some() {
+            self.tracked.insert(id);
+        }
+    }
+
+    fn tracked_ident(&self, expr: &Expr) -> Option<SymbolId> {
+        if !matches!(expr, Expr::Ident(_)) {
+            return None;
+        }
+        self.model
+            .symbol_of(expr)
+            .filter(|id| self.tracked.contains(id))
+    }
+
+    fn check_stmt(&mut self, stmt: &Stmt, state: &mut State) {
+        match stmt {
+            Stmt::Declaration {
+                storage,
+                declarators,
+                ..
+            } => {
+                for d in declarators {
+                    let value = match &d.init {
+                        Some(init) => self.eval(init, state),
+                        None => UNKNOWN,
+                    };
+                    if storage.is_some() {
+                        continue;
+                    }
+                    if let Some(&id) = self.locals.get(&d.id()) {
+                        self.track(id);
+                        if self.tracked.contains(&id) {
+                            let value = self.convert(id, value);
+                            state.set(id, value);
+                        }
+                    }
+                }
+            }
+            Stmt::ExprStmt(expr) => {
+                if let Some(expr) = expr {
+                    self.eval(expr, state);
+                }
+            }
+            Stmt::Return(expr) => {
+                if let Some(expr) = expr {
+                    self.eval(expr, state);
+                }
+                *state = State::default();
+            }
+            Stmt::Break => {
+                let current = std::mem::take(state);
+                if let Some(exits) = self.loops.last_mut() {
+                    exits.breaks.join(current);
+                }
+            }
+            Stmt::Continue => {
+                let current = std::mem::take(state);
+                if let Some(exits) = self.loops.last_mut() {
+                    exits.continues.join(current);
+                }
+            }
+            Stmt::Block(block) => {
+                for stmt in &block.statements {
+                    self.check_stmt(stmt, state);
+                }
+            }
+            Stmt::If {
+                cond,
+                then_branch,
+                else_branch,
+            } => {
+                self.eval(cond, state);
+                let (mut then_state, mut else_state) = self.refine(cond, state);
+                self.check_stmt(then_branch, &mut then_state);
+                if let Some(else_branch) = else_branch {
+                    self.check_stmt(else_branch, &mut else_state);
+                }
+                then_state.join(else_state);
+                *state = then_state;
+            }
+            Stmt::While { cond, body } => self.check_loop(Some(cond), None, body, state),
+            Stmt::For {
+                init,
+                cond,
+                step,
+                body,
+            } => {
+                if let Some(init) = init {
+                    self.check_stmt(init, state);
+                }
+                self.check_loop(cond.as_ref(), step.as_ref(), body, state);
+            }
+        }
+    }
+
+    /// 반복문 머리의 상태 = 진입 상태 ⊔ 본문 끝(continue 포함)에서 돌아오는 상태
+    /// WIDEN_DELAY 바퀴 뒤부터 위젠닝하고, 고정점에서 한 번 더 돌려 범위를 좁힘
+    fn check_loop(
+        &mut self,
+        cond: Option<&Expr>,
+        step: Option<&Expr>,
+        body: &Stmt,
+        state: &mut State,
+    ) {
+        let reporting = self.reporting;
+        self.reporting = false;
+        let entry = state.clone();
+        let mut head = entry.clone();
+        let mut rounds = 0;
+        loop {
+            let mut current = head.clone();
+            self.check_iteration(cond, step, body, &mut current);
+            let mut next = entry.clone();
+            next.join(current);
+            if rounds >= WIDEN_DELAY {
+                next = head.widen(&next);
+            }
+            if next == head {
+                break;
+            }
+            head = next;
+            rounds += 1;
+        }
+        let mut current = head;
+        self.check_iteration(cond, step, body, &mut current);
+        let mut head = entry;
+        head.join(current);
+
+        self.reporting = reporting;
+        let mut current = head;
+        *state = self.check_iteration(cond, step, body, &mut current);
+    }
+
+    /// 조건 -> 본문 -> 증감식 한 바퀴. state 는 다음 반복 머리로 가는 상태가 되고
+    /// 반복문을 빠져나가는 상태(조건 거짓, break)를 반환
+    fn check_iteration(
+        &mut self,
+        cond: Option<&Expr>,
+        step: Option<&Expr>,
+        body: &Stmt,
+        state: &mut State,
+    ) -> State {
+        let mut exit = match cond {
+            Some(cond) => {
+                self.eval(cond, state);
+                let (taken, exit) = self.refine(cond, state);
+                *state = taken;
+                exit
+            }
+            None => State::default(),
+        };
+        self.loops.push(LoopExits::default());
+        self.check_stmt(body, state);
+        let exits = self.loops.pop().unwrap_or_default();
+        state.join(exits.continues);
+        if let Some(step) = step {
+            self.eval(step, state);
+        }
+        exit.join(exits.breaks);
+        exit
+    }
+
+    /// 조건이 참인 경로와 거짓인 경로의 상태
+    /// (x < e 꼴 비교는 x 의 범위를 좁히고, 불가능한 경로는 도달 불가로)
+    fn refine(&mut self, cond: &Expr, state: &State) -> (State, State) {
+        if !state.reachable {
+            return (State::default(), State::default());
+        }
+        match cond {
+            Expr::UnaryPrefixOp {
+                op: PrefixOp::Not,
+                rhs,
+            } => {
+                let (taken, not_taken) = self.refine(rhs, state);
+                (not_taken, taken)
+            }
+            Expr::BinaryOp {
+                lhs,
+                op: BinaryOp::And,
+                rhs,
+            } => {
+                let (lhs_true, mut lhs_false) = self.refine(lhs, state);
+                let (rhs_true, rhs_false) = self.refine(rhs, &lhs_true);
+                lhs_false.join(rhs_false);
+                (rhs_true, lhs_false)
+            }
+            Expr::BinaryOp {
+                lhs,
+                op: BinaryOp::Or,
+                rhs,
+            } => {
+                let (mut lhs_true, lhs_false) = self.refine(lhs, state);
+                let (rhs_true, rhs_false) = self.refine(rhs, &lhs_false);
+                lhs_true.join(rhs_true);
+                (lhs_true, rhs_false)
+            }
+            Expr::BinaryOp { lhs, op, rhs } if is_comparison(op) => {
+                let left = self.peek(lhs, state);
+                let right = self.peek(rhs, state);
+                let mut taken = state.clone();
+                let mut not_taken = state.clone();
+                self.narrow(lhs, op, right, &mut taken);
+                self.narrow(lhs, &negate(op), right, &mut not_taken);
+                self.narrow(rhs, &flip(op), left, &mut taken);
+                self.narrow(rhs, &flip(&negate(op)), left, &mut not_taken);
+                (taken, not_taken)
+            }
+            _ => {
+                let zero = Interval::constant(0);
+                let mut taken = state.clone();
+                let mut not_taken = state.clone();
+                self.narrow(cond, &BinaryOp::Ne, zero, &mut taken);
+                self.narrow(cond, &BinaryOp::Eq, zero, &mut not_taken);
+                (taken, not_taken)
+            }
+        }
+    }
+
+    /// expr op bound 가 성립하도록 expr 의 범위를 좁힘 (불가능하면 도달 불가)
+    fn narrow(&mut self, expr: &Expr, op: &BinaryOp, bound: Interval, state: &mut State) {
+        if !state.reachable {
+            return;
+        }
+        let value = self.peek(expr, state);
+        let limit = |lo: Option<i64>, hi: Option<i64>| value.intersect(Interval { lo, hi });
+        let narrowed = match op {
+            BinaryOp::Lt => limit(None, bound.hi.map(|hi| hi.saturating_sub(1))),
+            BinaryOp::Le => limit(None, bound.hi),
+            BinaryOp::Gt => limit(bound.lo.map(|lo| lo.saturating_add(1)), None),
+            BinaryOp::Ge => limit(bound.lo, None),
+            BinaryOp::Eq => value.intersect(bound),
+            BinaryOp::Ne => match bound.bounds() {
+                Some((lo, hi)) if lo == hi => {
+                    let lo = if value.lo == Some(lo) {
+                        Some(lo.saturating_add(1))
+                    } else {
+                        value.lo
+                    };
+                    let hi = if value.hi == Some(hi) {
+                        Some(hi.saturating_sub(1))
+                    } else {
+                        value.hi
+                    };
+                    value.intersect(Interval { lo, hi })
+                }
+                _ => Some(value),
+            },
+            _ => Some(value),
+        };
+        match narrowed {
+            None => *state = State::default(),
+            Some(narrowed) => {
+                if let Some(id) = self.tracked_ident(expr) {
+                    state.set(id, narrowed);
+                }
+            }
+        }
+    }
+
+    /// 상태를 바꾸거나 경고하지 않고 식의 범위만 구함
+    fn peek(&mut self, expr: &Expr, state: &State) -> Interval {
+        let reporting = std::mem::replace(&mut self.reporting, false);
+        let value = self.eval(expr, &mut state.clone());
+        self.reporting = reporting;
+        value
+    }
+
+    /// 식을 평가 순서대로 따라가며 범위를 구하고, 대입과 증감을 상태에 반영
+    fn eval(&mut self, expr: &Expr, state: &mut State) -> Interval {
+        match expr {
+            // int 범위를 넘는 리터럴은 int 한계로 자름 (±1 조정이 i64 를 넘지 않도록)
+            Expr::IntLiteral(value) => {
+                Interval::constant((*value).clamp(i32::MIN as i64, i32::MAX as i64))
+            }
+            Expr::CharLiteral(c) => Interval::constant(char_value(*c) as i64),
+            Expr::FloatLiteral(_) | Expr::DoubleLiteral(_) | Expr::StringLiteral(_) => UNKNOWN,
+            Expr::Ident(_) => match self.tracked_ident(expr) {
+                Some(id) => state.get(id),
+                None => UNKNOWN,
+            },
+            Expr::UnaryPrefixOp { op, rhs } => match op {
+                PrefixOp::Neg => self.eval(rhs, state).neg(),
+                PrefixOp::Not => {
+                    self.eval(rhs, state);
+                    BOOL
+                }
+                PrefixOp::PreInc => self.step(rhs, 1, true, state),
+                PrefixOp::PreDec => self.step(rhs, -1, true, state),
+                // &a[n] 은 끝 바로 다음 위치까지 허용
+                PrefixOp::Address => {
+                    match rhs.as_ref() {
+                        Expr::ArrayIndex { array, index } => {
+                            self.eval_index(rhs, array, index, true, state);
+                        }
+                        _ => {
+                            self.eval(rhs, state);
+                        }
+                    }
+                    UNKNOWN
+                }
+                PrefixOp::Deref => {
+                    self.eval(rhs, state);
+                    UNKNOWN
+                }
+            },
+            Expr::UnaryPostfixOp { lhs, op } => match op {
+                PostfixOp::PostInc => self.step(lhs, 1, false, state),
+                PostfixOp::PostDec => self.step(lhs, -1, false, state),
+            },
+            Expr::BinaryOp {
+                lhs,
+                op: op @ (BinaryOp::And | BinaryOp::Or),
+                rhs,
+            } => {
+                // 오른쪽은 왼쪽 결과에 따라 조건부로 평가됨
+                self.eval(lhs, state);
+                let (lhs_true, lhs_false) = self.refine(lhs, state);
+                let (mut evaluated, mut skipped) = match op {
+                    BinaryOp::And => (lhs_true, lhs_false),
+                    _ => (lhs_false, lhs_true),
+                };
+                self.eval(rhs, &mut evaluated);
+                skipped.join(evaluated);
+                *state = skipped;
+                BOOL
+            }
+            Expr::BinaryOp { lhs, op, rhs } => {
+                let left = self.eval(lhs, state);
+                let right = self.eval(rhs, state);
+                if is_comparison(op) {
+                    BOOL
+                } else {
+                    self.arithmetic(expr, op, left, right, state)
+                }
+            }
+            Expr::Call { func, args } => {
+                self.eval(func, state);
+                for arg in args {
+                    self.eval(arg, state);
+                }
+                UNKNOWN
+            }
+            Expr::ArrayIndex { array, index } => {
+                self.eval_index(expr, array, index, false, state);
+                UNKNOWN
+            }
+            Expr::InitializerList(items) => {
+                for item in items {
+                    self.eval(item, state);
+                }
+                UNKNOWN
+            }
+            Expr::Assignment {
+                left, op, right, ..
+            } => {
+                let value = self.eval(right, state);
+                let current = self.eval(left, state);
+                let value = match op {
+                    AssignOp::Assign => value,
+                    _ => match op.binary_op() {
+                        Some(bin) => self.arithmetic(expr, &bin, current, value, state),
+                        None => UNKNOWN,
+                    },
+                };
+                match self.tracked_ident(left) {
+                    Some(id) => {
+                        let value = self.convert(id, value);
+                        state.set(id, value);
+                        value
+                    }
+                    None => value,
+                }
+            }
+        }
+    }
+
+    /// 정수 산술 연산의 범위 (0 나누기와 int 오버플로 검사)
+    /// 포인터 산술과 실수 연산은 범위를 모름
+    fn arithmetic(
+        &mut self,
+        expr: &Expr,
+        op: &BinaryOp,
+        left: Interval,
+        right: Interval,
+        state: &State,
+    ) -> Interval {
+        if self.model.type_of(expr).and_then(type_range).is_none() {
+            return UNKNOWN;
+        }
+        let value = match op {
+            BinaryOp::Add => left.add(right),
+            BinaryOp::Sub => left.sub(right),
+            BinaryOp::Mul => left.mul(right),
+            BinaryOp::Div | BinaryOp::Rem => {
+                if right.bounds().is_some() && right.contains_zero() {
+                    self.report(
+                        state,
+                        SemanticWarning::MaybeDivisionByZero { expr: expr.id() },
+                    );
+                }
+                if *op == BinaryOp::Div {
+                    left.div(right)
+                } else {
+                    left.rem(right)
+                }
+            }
+            _ => UNKNOWN,
+        };
+        if value.fits(INT) {
+            return value;
+        }
+        if matches!(op, BinaryOp::Add | BinaryOp::Mul) {
+            self.report(
+                state,
+                SemanticWarning::SignedOverflow {
+                    expr: expr.id(),
+                    op: op.clone(),
+                    min: value.lo,
+                    max: value.hi,
+                },
+            );
+        }
+        UNKNOWN
+    }
+
+    /// ++x, x--: 변수를 갱신하고 (전위면 새 값, 후위면 이전 값)을 반환
+    fn step(&mut self, target: &Expr, delta: i64, prefix: bool, state: &mut State) -> Interval {
+        let old = self.eval(target, state);
+        let Some(id) = self.tracked_ident(target) else {
+            return if prefix { UNKNOWN } else { old };
+        };
+        let new = self.convert(id, old.add(Interval::constant(delta)));
+        state.set(id, new);
+        if prefix { new } else { old }
+    }
+
+    /// 길이를 아는 배열의 인덱스 범위가 [0, 길이) 를 벗어날 수 있으면 경고
+    fn eval_index(
+        &mut self,
+        expr: &Expr,
+        array: &Expr,
+        index: &Expr,
+        address: bool,
+        state: &mut State,
+    ) {
+        self.eval(array, state);
+        let value = self.eval(index, state);
+        let Some(TypeSpecifier::Array(_, Some(length))) =
+            self.model.type_of(array).map(|ty| ty.unqualified())
+        else {
+            return;
+        };
+        let end = if address { *length } else { *length - 1 } as i64;
+        if value.lo.is_some_and(|lo| lo < 0) || value.hi.is_some_and(|hi| hi > end) {
+            self.report(
+                state,
+                SemanticWarning::IndexOutOfRange {
+                    expr: expr.id(),
+                    length: *length,
+                    min: value.lo,
+                    max: value.hi,
+                },
+            );
+        }
+    }
+
+    /// 변수 타입으로 변환: 범위를 넘으면 잘린 값을 알 수 없음
+    fn convert(&self, id: SymbolId, value: Interval) -> Interval {
+        match type_range(&self.model.symbol(id).ty) {
+            Some(range) if value.fits(range) => value,
+            _ => UNKNOWN,
+        }
+    }
+
+    fn report(&mut self, state: &State, warning: SemanticWarning) {
+        let expr = match &warning {
+            SemanticWarning::MaybeDivisionByZero { expr }
+            | SemanticWarning::SignedOverflow { expr, .. }
+            | SemanticWarning::IndexOutOfRange { expr, .. } => *expr,
+            _ => return,
+        };
+        if self.reporting && state.reachable && self.reported.insert(expr) {
+            self.warnings.push(warning);
+        }
+    }
+}
+
+fn is_comparison(op: &BinaryOp) -> bool {
+    matches!(
+        op,
+        BinaryOp::Eq | BinaryOp::Ne | BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge
+    )
+}
+
+/// 조건이 거짓일 때 성립하는 비교
+fn negate(op: &BinaryOp) -> BinaryOp {
+    match op {
+        BinaryOp::Lt => BinaryOp::Ge,
+        BinaryOp::Le => BinaryOp::Gt,
+        BinaryOp::Gt => BinaryOp::Le,
+        BinaryOp::Ge => BinaryOp::Lt,
+        BinaryOp::Eq => BinaryOp::Ne,
+        BinaryOp::Ne => BinaryOp::Eq,
+        other => other.clone(),
+    }
+}
+
+/// 피연산자를 맞바꾼 비교 (a < b 는 b > a)
+fn flip(op: &BinaryOp) -> BinaryOp {
+    match op {
+        BinaryOp::Lt => BinaryOp::Gt,
+        BinaryOp::Le => BinaryOp::Ge,
+        BinaryOp::Gt => BinaryOp::Lt,
+        BinaryOp::Ge => BinaryOp::Le,
+        other => other.clone(),
+    }
+}
@@ -0,0 +1,64 @@
+use crate::utils::parse_expression;
+use rustc_tape4::ast::expr::BinaryOp;
+use rustc_tape4::consteval::{ConstError, eval_int};
+
+fn eval(input: &str) -> Result<i32, ConstError> {
+    eval_int(&parse_expression(input))
+}
+
+#[test]
+fn test_integer_constant_expressions() {
+    assert_eq!(eval("3 * 4 + 1"), Ok(13));
+    assert_eq!(eval("(1 + 2) * -3"), Ok(-9));
+    assert_eq!(eval("'a' + 1"), Ok(98));
+    // 나눗셈과 나머지는 0 방향 절삭
+    assert_eq!(eval("-7 / 2"), Ok(-3));
+    assert_eq!(eval("-7 % 2"), Ok(-1));
+    assert_eq!(eval("6 & 3 | 8 ^ 1"), Ok(11));
+    assert_eq!(eval("2 < 3 == 1"), Ok(1));
+    assert_eq!(eval("!5 || 0"), Ok(0));
+    // 단락 평가: 평가되지 않는 쪽의 0 나누기는 오류가 아님
+    assert_eq!(eval("0 && 1 / 0"), Ok(0));
+    assert_eq!(eval("1 || 1 / 0"), Ok(1));
+    assert_eq!(eval("-2147483647 - 1"), Ok(i32::MIN));
+}
+
+#[test]
+fn test_overflow_and_division_by_zero() {
+    assert_eq!(
+        eval("2147483647 + 1"),
+        Err(ConstError::Overflow {
+            op: Some(BinaryOp::Add),
+            wrapped: i32::MIN,
+        })
+    );
+    assert_eq!(
+        eval("65536 * 65536"),
+        Err(ConstError::Overflow {
+            op: Some(BinaryOp::Mul),
+            wrapped: 0,
+        })
+    );
+    assert_eq!(
+        eval("(-2147483647 - 1) / -1"),
+        Err(ConstError::Overflow {
+            op: Some(BinaryOp::Div),
+            wrapped: i32::MIN,
+        })
+    );
+    // int 범위를 넘는 리터럴
+    assert!(matches!(
+        eval("2147483648"),
+        Err(ConstError::Overflow { op: None, .. })
+    ));
+    assert_eq!(eval("1 / 0"), Err(ConstError::DivisionByZero));
+    assert_eq!(eval("5 % (2 - 2)"), Err(ConstError::DivisionByZero));
+}
+
+#[test]
+fn test_non_constant_expressions() {
+    assert_eq!(eval("n + 1"), Err(ConstError::NotConstant));
+    assert_eq!(eval("f(1)"), Err(ConstError::NotConstant));
+    assert_eq!(eval("1.5 * 2"), Err(ConstError::NotInteger));
+    assert_eq!(eval("\"abc\""), Err(ConstError::NotInteger));
+}
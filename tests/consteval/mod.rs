@@ -0,0 +1 @@
+mod consteval_tests;
@@ -1,5 +1,5 @@
 use crate::utils::collect_tokens;
-use rustc_tape4::lexer::{LexError, Token};
+use rustc_tape4::lexer::{LexError, Lexer, Token};
 
 #[test]
 fn simple_tokens() {
@@ -75,3 +75,63 @@ fn comments_and_whitespace() {
     ];
     assert_eq!(collect_tokens(input), expected);
 }
+
+#[test]
+fn float_literals() {
+    let input = "1.5 .5 1e-3 2.0f 3E2F float double";
+    let expected = vec![
+        Token::DoubleLiteral(1.5),
+        Token::DoubleLiteral(0.5),
+        Token::DoubleLiteral(1e-3),
+        Token::FloatLiteral(2.0),
+        Token::FloatLiteral(300.0),
+        Token::Float,
+        Token::Double,
+        Token::EOF,
+    ];
+    assert_eq!(collect_tokens(input), expected);
+}
+
+#[test]
+fn float_literal_missing_exponent() {
+    let input = "1e+";
+    let tokens = collect_tokens(input);
+    assert_eq!(
+        tokens[0],
+        Token::Error(LexError::InvalidNumericLiteral("1e+".into()))
+    );
+}
+
+#[test]
+fn string_literals_and_ellipsis() {
+    let input = r#""hi\n" "a\"b" ... "open"#;
+    let expected = vec![
+        Token::StringLiteral("hi\n".into()),
+        Token::StringLiteral("a\"b".into()),
+        Token::Ellipsis,
+        Token::Error(LexError::UnterminatedStringLiteral),
+        Token::EOF,
+    ];
+    assert_eq!(collect_tokens(input), expected);
+}
+
+#[test]
+fn token_positions() {
+    // 토큰의 위치는 첫 글자의 줄/열 (1 부터)
+    let tokens = Lexer::new("a += b;\n  (x)++").collect_spanned_tokens();
+    let positions: Vec<_> = tokens.iter().map(|t| (t.line, t.column)).collect();
+    assert_eq!(
+        positions,
+        vec![
+            (1, 1),
+            (1, 3),
+            (1, 6),
+            (1, 7),
+            (2, 3),
+            (2, 4),
+            (2, 5),
+            (2, 6),
+            (2, 8)
+        ]
+    );
+}
@@ -0,0 +1,122 @@
+use crate::utils::link_units;
+use rustc_tape4::linker::LinkError;
+
+#[test]
+fn test_link_two_units() {
+    let main_c = r#"
+    int add(int a, int b);
+    extern int total;
+    int main() { total = add(1, 2); return total; }
+    "#;
+    let math_c = r#"
+    int total = 0;
+    static int twice(int x) { return x + x; }
+    int add(int a, int b) { return twice(a) + b - a; }
+    "#;
+    let table = link_units(&[("main.c", main_c), ("math.c", math_c)]).unwrap();
+    assert!(table.lookup("add").unwrap().is_defined);
+    assert!(table.lookup("total").unwrap().is_defined);
+    // static 함수는 외부로 보이지 않음
+    assert!(table.lookup("twice").is_none());
+}
+
+#[test]
+fn test_static_names_do_not_clash() {
+    let a_c = "static int helper(void) { return 1; } int main() { return helper(); }";
+    let b_c = "static int helper(void) { return 2; } int other(void) { return helper(); }";
+    assert!(link_units(&[("a.c", a_c), ("b.c", b_c)]).is_ok());
+}
+
+#[test]
+fn test_multiple_definition() {
+    let a_c = "int shared(void) { return 1; } int main() { return shared(); }";
+    let b_c = "int shared(void) { return 2; }";
+    assert!(matches!(
+        link_units(&[("a.c", a_c), ("b.c", b_c)]),
+        Err(LinkError::MultipleDefinition { name, first, second })
+            if name == "shared" && first == "a.c" && second == "b.c"
+    ));
+
+    // 초기화식 없는 전역 변수도 정의
+    let a_c = "int counter; int main() { return counter; }";
+    let b_c = "int counter = 1;";
+    assert!(matches!(
+        link_units(&[("a.c", a_c), ("b.c", b_c)]),
+        Err(LinkError::MultipleDefinition { .. })
+    ));
+}
+
+#[test]
+fn test_signature_mismatch() {
+    let a_c = "int f(int x); int main() { return f(1); }";
+    let b_c = "int f(char *s) { return 0; }";
+    assert!(matches!(
+        link_units(&[("a.c", a_c), ("b.c", b_c)]),
+        Err(LinkError::SignatureMismatch { name, .. }) if name == "f"
+    ));
+
+    let a_c = "extern char limit; int main() { return 0; }";
+    let b_c = "int limit = 3;";
+    assert!(matches!(
+        link_units(&[("a.c", a_c), ("b.c", b_c)]),
+        Err(LinkError::SignatureMismatch { .. })
+    ));
+
+    // 크기 없는 배열 선언은 다른 단위의 크기 있는 정의와 호환
+    let a_c = "extern int table[]; int main() { return table[0]; }";
+    let b_c = "int table[10];";
+    assert!(link_units(&[("a.c", a_c), ("b.c", b_c)]).is_ok());
+    assert!(link_units(&[("b.c", b_c), ("a.c", a_c)]).is_ok());
+    let c_c = "extern int table[8];";
+    assert!(matches!(
+        link_units(&[("a.c", a_c), ("b.c", b_c), ("c.c", c_c)]),
+        Err(LinkError::SignatureMismatch { name, first, second })
+            if name == "table" && first == "a.c" && second == "c.c"
+    ));
+}
+
+#[test]
+fn test_unresolved_reference() {
+    let a_c = "int missing(void); int main() { return missing(); }";
+    let b_c = "int other(void) { return 0; }";
+    assert!(matches!(
+        link_units(&[("a.c", a_c), ("b.c", b_c)]),
+        Err(LinkError::UnresolvedSymbol { name, unit }) if name == "missing" && unit == "a.c"
+    ));
+
+    // 블록 안 extern 선언으로만 알려진 변수
+    let a_c = "int main() { extern int hidden; return hidden; }";
+    assert!(matches!(
+        link_units(&[("a.c", a_c)]),
+        Err(LinkError::UnresolvedSymbol { .. })
+    ));
+
+    // 블록 안 함수 선언도 다른 단위의 정의를 가리킴
+    let a_c = "int main() { int helper(int); return helper(1); }";
+    let b_c = "int helper(int v) { return v; }";
+    assert!(link_units(&[("a.c", a_c), ("b.c", b_c)]).is_ok());
+    assert!(matches!(
+        link_units(&[("a.c", a_c)]),
+        Err(LinkError::UnresolvedSymbol { name, .. }) if name == "helper"
+    ));
+
+    // printf/scanf 계열은 런타임이 정의
+    let a_c = r#"
+    int main() {
+        char buf[16];
+        int n;
+        void *stream = 0;
+        scanf("%d", &n);
+        sscanf("1", "%d", &n);
+        fscanf(stream, "%d", &n);
+        sprintf(buf, "%d", n);
+        snprintf(buf, 16, "%d", n);
+        return fprintf(stream, "%s", buf);
+    }
+    "#;
+    assert!(link_units(&[("a.c", a_c)]).is_ok());
+
+    // 선언만 하고 쓰지 않은 함수는 문제 없음
+    let a_c = "int unused(void); int main() { return 0; }";
+    assert!(link_units(&[("a.c", a_c)]).is_ok());
+}
@@ -0,0 +1 @@
+mod linker_tests;
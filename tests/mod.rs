@@ -1,3 +1,12 @@
+#![allow(
+    clippy::collapsible_if,
+    clippy::collapsible_match,
+    clippy::single_match
+)]
+
+pub mod consteval;
 pub mod lexer;
+pub mod linker;
 pub mod parser;
+pub mod semantic;
 pub mod utils;
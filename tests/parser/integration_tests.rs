@@ -2,7 +2,7 @@ use crate::utils::parse_program;
 use rustc_tape4::ast::Expr;
 use rustc_tape4::ast::Stmt;
 use rustc_tape4::ast::expr::{BinaryOp, PrefixOp};
-use rustc_tape4::ast::{Function, Program, TypeSpecifier};
+use rustc_tape4::ast::{Function, Pos, Program, SourceMap, TypeSpecifier};
 use std::fs;
 
 /// fixture 파일의 내용을 읽어오는 함수
@@ -92,7 +92,7 @@ fn test_expressions_fixture() {
     assert_eq!(program.functions[0].name, "test_expressions");
 
     // 함수 본문 내 문장 개수 확인
-    assert!(program.functions[0].body.statements.len() > 10);
+    assert!(program.functions[0].body.as_ref().unwrap().statements.len() > 10);
 }
 
 #[test]
@@ -389,13 +389,78 @@ fn test_complex_program_fixture() {
     assert!(has_nested_loops, "중첩된 반복문을 찾을 수 없음");
 }
 
+#[test]
+fn test_source_positions() {
+    // 선언은 이름, 이항/대입 식은 연산자, 나머지 식과 문장은 첫 토큰의 위치
+    let source = "int g[2 + 2] = {1};\nint f(int a, char *b) {\n  int x = a * (a - 1);\n  for (x = 0; x < 3; x++) g[x] = f(x, 0);\n  return x;\n}\nint h(char *);\n";
+    let program = parse_program(source).unwrap();
+    let map = SourceMap::new(&program);
+    let pos = |line, column| Some(Pos::new(line, column));
+
+    let Stmt::Declaration { declarators, .. } = &program.globals[0] else {
+        panic!("Expected declaration");
+    };
+    assert_eq!(map.decl(declarators[0].id()), pos(1, 5));
+    assert_eq!(map.stmt(program.globals[0].id()), pos(1, 1));
+    // 배열 크기 식은 버려지므로 초기화식이 첫 기록
+    assert_eq!(
+        map.expr(declarators[0].init.as_ref().unwrap().id()),
+        pos(1, 16)
+    );
+
+    let func = &program.functions[0];
+    assert_eq!(map.decl(func.id()), pos(2, 5));
+    assert_eq!(map.decl(func.params[0].id()), pos(2, 11));
+    assert_eq!(map.decl(func.params[1].id()), pos(2, 20));
+    // 이름이 생략된 매개변수는 타입의 위치
+    assert_eq!(map.decl(program.functions[1].params[0].id()), pos(7, 7));
+
+    let body = &func.body.as_ref().unwrap().statements;
+    let Stmt::Declaration { declarators, .. } = &body[0] else {
+        panic!("Expected declaration");
+    };
+    let Some(Expr::BinaryOp { lhs, rhs, .. }) = &declarators[0].init else {
+        panic!("Expected multiplication");
+    };
+    assert_eq!(map.decl(declarators[0].id()), pos(3, 7));
+    assert_eq!(
+        map.expr(declarators[0].init.as_ref().unwrap().id()),
+        pos(3, 13)
+    );
+    assert_eq!(map.expr(lhs.id()), pos(3, 11));
+    assert_eq!(map.expr(rhs.id()), pos(3, 18));
+
+    let Stmt::For {
+        init,
+        step,
+        body: for_body,
+        ..
+    } = &body[1]
+    else {
+        panic!("Expected for");
+    };
+    assert_eq!(map.stmt(body[1].id()), pos(4, 3));
+    assert_eq!(map.stmt(init.as_ref().unwrap().id()), pos(4, 8));
+    assert_eq!(map.expr(step.as_ref().unwrap().id()), pos(4, 22));
+    let Stmt::ExprStmt(Some(Expr::Assignment { left, right, .. })) = for_body.as_ref() else {
+        panic!("Expected assignment");
+    };
+    let Expr::Call { args, .. } = right.as_ref() else {
+        panic!("Expected call");
+    };
+    assert_eq!(map.expr(left.id()), pos(4, 27));
+    assert_eq!(map.expr(right.id()), pos(4, 34));
+    assert_eq!(map.expr(args[1].id()), pos(4, 39));
+    assert_eq!(map.stmt(body[2].id()), pos(5, 3));
+}
+
 /// 프로그램에서 주어진 조건을 만족하는 문장이 있는지 검사
 fn contains_stmt_type<F>(program: &Program, predicate: F) -> bool
 where
     F: Fn(&Stmt) -> bool,
 {
     for function in &program.functions {
-        if contains_stmt_in_block(&function.body.statements, &predicate) {
+        if contains_stmt_in_block(&function.body.as_ref().unwrap().statements, &predicate) {
             return true;
         }
     }
@@ -471,7 +536,7 @@ fn contains_expr_type<F>(function: &Function, predicate: F) -> bool
 where
     F: Fn(&Expr) -> bool,
 {
-    contains_expr_in_block(&function.body.statements, &predicate)
+    contains_expr_in_block(&function.body.as_ref().unwrap().statements, &predicate)
 }
 
 /// 블록 내 문장 중 조건을 만족하는 표현식이 있는지 재귀적으로 검사
@@ -645,14 +710,15 @@ fn contains_recursive_call(program: &Program) -> bool {
     for function in &program.functions {
         let func_name = &function.name;
 
-        let has_recursive = contains_expr_in_block(&function.body.statements, &|expr| {
-            if let Expr::Call { func, .. } = expr {
-                if let Expr::Ident(name) = &**func {
-                    return name == func_name;
+        let has_recursive =
+            contains_expr_in_block(&function.body.as_ref().unwrap().statements, &|expr| {
+                if let Expr::Call { func, .. } = expr {
+                    if let Expr::Ident(name) = &**func {
+                        return name == func_name;
+                    }
                 }
-            }
-            false
-        });
+                false
+            });
 
         if has_recursive {
             return true;
@@ -665,21 +731,22 @@ fn contains_recursive_call(program: &Program) -> bool {
 fn contains_nested_loops(program: &Program) -> bool {
     for function in &program.functions {
         // 반복문 내에 반복문이 있는지 검사
-        let has_nested = contains_stmt_in_block(&function.body.statements, &|stmt| {
-            match stmt {
-                Stmt::While { body, .. } | Stmt::For { body, .. } => {
-                    // 반복문 본문에 또 다른 반복문이 있는지 확인
-                    if let Stmt::Block(block) = &**body {
-                        contains_stmt_in_block(&block.statements, &|inner_stmt| {
-                            matches!(inner_stmt, Stmt::While { .. } | Stmt::For { .. })
-                        })
-                    } else {
-                        matches!(**body, Stmt::While { .. } | Stmt::For { .. })
+        let has_nested =
+            contains_stmt_in_block(&function.body.as_ref().unwrap().statements, &|stmt| {
+                match stmt {
+                    Stmt::While { body, .. } | Stmt::For { body, .. } => {
+                        // 반복문 본문에 또 다른 반복문이 있는지 확인
+                        if let Stmt::Block(block) = &**body {
+                            contains_stmt_in_block(&block.statements, &|inner_stmt| {
+                                matches!(inner_stmt, Stmt::While { .. } | Stmt::For { .. })
+                            })
+                        } else {
+                            matches!(**body, Stmt::While { .. } | Stmt::For { .. })
+                        }
                     }
+                    _ => false,
                 }
-                _ => false,
-            }
-        });
+            });
 
         if has_nested {
             return true;
@@ -284,6 +284,29 @@ fn test_assignment_ops() {
     ));
 }
 
+#[test]
+fn test_parenthesized_assignment() {
+    // 괄호로 감싼 대입만 표시됨
+    for (input, expected) in [("x = 5", false), ("(x = 5)", true), ("((x += 5))", true)] {
+        let expr = parse_expression(input);
+        assert!(matches!(
+            expr,
+            Expr::Assignment { parenthesized, .. } if parenthesized == expected
+        ));
+    }
+    let expr = parse_expression("(a = b) + 1");
+    let Expr::BinaryOp { lhs, .. } = expr else {
+        panic!("Expected binary op");
+    };
+    assert!(matches!(
+        *lhs,
+        Expr::Assignment {
+            parenthesized: true,
+            ..
+        }
+    ));
+}
+
 #[test]
 fn test_increment_decrement() {
     // 증감 연산자 테스트
@@ -407,7 +430,10 @@ fn test_array_indexing_and_assignment() {
     // 배열 인덱싱과 대입 표현식 테스트
     let expr = parse_expression("arr[5] = b[2]");
 
-    if let Expr::Assignment { left, op, right } = expr {
+    if let Expr::Assignment {
+        left, op, right, ..
+    } = expr
+    {
         assert_eq!(op, AssignOp::Assign);
         // arr[5]
         match *left {
@@ -532,6 +558,7 @@ fn test_multiple_assignment() {
         left: left1,
         op: op1,
         right: right1,
+        ..
     } = expr
     {
         assert_eq!(*left1, Expr::Ident("a".to_string()));
@@ -541,6 +568,7 @@ fn test_multiple_assignment() {
             left: left2,
             op: op2,
             right: right2,
+            ..
         } = *right1
         {
             assert_eq!(*left2, Expr::Ident("b".to_string()));
@@ -550,6 +578,7 @@ fn test_multiple_assignment() {
                 left: left3,
                 op: op3,
                 right: right3,
+                ..
             } = *right2
             {
                 assert_eq!(*left3, Expr::Ident("c".to_string()));
@@ -695,10 +724,19 @@ fn test_consecutive_operators() {
 }
 
 #[test]
-#[should_panic]
-fn test_invalid_assignment_target() {
-    // 유효하지 않은 대입 대상 (좌변에는 lvalue가 와야 함)
-    parse_expression("a + b = c");
+fn test_non_lvalue_assignment_target() {
+    // 좌변이 lvalue 인지는 타입 검사에서 확인하므로 파서는 그대로 받음
+    let expr = parse_expression("a + b = c");
+    assert!(matches!(
+        expr,
+        Expr::Assignment { left, op: AssignOp::Assign, .. }
+            if matches!(*left, Expr::BinaryOp { op: BinaryOp::Add, .. })
+    ));
+    let expr = parse_expression("3 = x");
+    assert!(matches!(
+        expr,
+        Expr::Assignment { left, .. } if *left == Expr::IntLiteral(3)
+    ));
 }
 
 #[test]
@@ -1,6 +1,7 @@
 use crate::utils::parse_program;
-use rustc_tape4::ast::expr::{AssignOp, BinaryOp};
-use rustc_tape4::ast::{Expr, Stmt, TypeSpecifier};
+use rustc_tape4::ast::expr::BinaryOp;
+use rustc_tape4::ast::{Expr, ExternalDecl, Stmt, StorageClass, TypeSpecifier};
+use rustc_tape4::parser::ParserError;
 
 #[test]
 fn test_empty_program() {
@@ -14,7 +15,14 @@ fn test_empty_program() {
 fn test_empty_body() {
     // 문장 없는 함수
     let program = parse_program("int f() {}").unwrap();
-    assert!(program.functions[0].body.statements.is_empty());
+    assert!(
+        program.functions[0]
+            .body
+            .as_ref()
+            .unwrap()
+            .statements
+            .is_empty()
+    );
 }
 
 #[test]
@@ -27,7 +35,7 @@ fn test_void_return_type() {
     assert_eq!(func.name, "do_nothing");
     assert_eq!(func.return_ty, TypeSpecifier::Void);
     assert_eq!(func.params.len(), 0);
-    assert_eq!(func.body.statements.len(), 0);
+    assert_eq!(func.body.as_ref().unwrap().statements.len(), 0);
 }
 
 #[test]
@@ -47,7 +55,7 @@ fn test_simple_function() {
     assert_eq!(func.params.len(), 0);
 
     // 함수 본문이 한 개의 문장을 가지고 있는지 검증
-    assert_eq!(func.body.statements.len(), 1);
+    assert_eq!(func.body.as_ref().unwrap().statements.len(), 1);
 }
 
 #[test]
@@ -66,14 +74,14 @@ fn test_function_with_parameters() {
     // 매개변수 검증
     assert_eq!(func.params.len(), 2);
 
-    assert_eq!(func.params[0].name, "a");
+    assert_eq!(func.params[0].name.as_deref(), Some("a"));
     assert_eq!(func.params[0].ty, TypeSpecifier::Int);
 
-    assert_eq!(func.params[1].name, "b");
+    assert_eq!(func.params[1].name.as_deref(), Some("b"));
     assert_eq!(func.params[1].ty, TypeSpecifier::Int);
 
     // 함수 본문이 한 개의 문장을 가지고 있는지 검증
-    assert_eq!(func.body.statements.len(), 1);
+    assert_eq!(func.body.as_ref().unwrap().statements.len(), 1);
 }
 
 #[test]
@@ -101,7 +109,7 @@ fn test_function_with_pointer_type() {
 
     // 매개변수 검증
     assert_eq!(func.params.len(), 1);
-    assert_eq!(func.params[0].name, "size");
+    assert_eq!(func.params[0].name.as_deref(), Some("size"));
     assert_eq!(func.params[0].ty, TypeSpecifier::Int);
 }
 
@@ -119,7 +127,7 @@ fn test_function_with_pointer_parameter() {
 
     // 포인터 매개변수 검증
     assert_eq!(func.params.len(), 1);
-    assert_eq!(func.params[0].name, "ptr");
+    assert_eq!(func.params[0].name.as_deref(), Some("ptr"));
 
     match &func.params[0].ty {
         TypeSpecifier::Pointer(inner_ty) => {
@@ -166,7 +174,7 @@ fn test_char_return_type() {
     assert_eq!(func.params.len(), 0);
 
     // 본문에 하나의 return 'a'; 문장이 있는지
-    let stmts = &func.body.statements;
+    let stmts = &func.body.as_ref().unwrap().statements;
     assert_eq!(stmts.len(), 1);
     match &stmts[0] {
         Stmt::Return(Some(Expr::CharLiteral(c))) if *c == 'a' => {}
@@ -231,18 +239,18 @@ fn test_recursive_function() {
     // 함수 내부에서 자기 자신을 호출하는지 확인
     if let Stmt::If {
         cond: _,
-        then_branch,
+        then_branch: _,
         else_branch,
-    } = &program.functions[0].body.statements[0]
+    } = &program.functions[0].body.as_ref().unwrap().statements[0]
     {
         if let Some(else_branch) = else_branch {
             if let Stmt::Return(Some(Expr::BinaryOp {
                 op: BinaryOp::Mul,
-                lhs,
+                lhs: _,
                 rhs,
             })) = &**else_branch
             {
-                if let Expr::Call { func, args } = &**rhs {
+                if let Expr::Call { func, args: _ } = &**rhs {
                     if let Expr::Ident(name) = &**func {
                         assert_eq!(name, "factorial");
                     } else {
@@ -267,9 +275,33 @@ fn test_function_prototype() {
     let input = "int prototype(int a, char b);";
     let program = parse_program(input).unwrap();
     assert_eq!(program.functions.len(), 1);
-    assert!(program.functions[0].body.statements.is_empty());
+    assert!(program.functions[0].body.is_none());
     assert_eq!(program.functions[0].name, "prototype");
     assert_eq!(program.functions[0].params.len(), 2);
+
+    // 빈 본문 정의는 프로토타입과 구분됨
+    let program = parse_program("int f(int a) {}").unwrap();
+    assert!(program.functions[0].is_definition());
+}
+
+#[test]
+fn test_prototype_without_parameter_names() {
+    let input = "int apply(int (*)(int), int [], char *);";
+    let program = parse_program(input).unwrap();
+    let params = &program.functions[0].params;
+    assert_eq!(params.len(), 3);
+    assert!(params.iter().all(|param| param.name.is_none()));
+    assert_eq!(
+        params[1].ty,
+        TypeSpecifier::Pointer(Box::new(TypeSpecifier::Int))
+    );
+
+    // 정의에서는 매개변수 이름이 필요
+    let result = parse_program("int f(int) { return 0; }");
+    assert!(matches!(
+        result,
+        Err(ParserError::MissingParameterName { .. })
+    ));
 }
 
 #[test]
@@ -279,3 +311,85 @@ fn test_function_with_void_parameter() {
     assert_eq!(program.functions.len(), 1);
     assert_eq!(program.functions[0].params.len(), 0);
 }
+
+#[test]
+fn test_variadic_function_declaration() {
+    // 가변 인자 함수 선언과 문자열 리터럴 인자
+    let input = r#"
+    int printf(char *fmt, ...);
+    int main() { printf("%d %c\n" "done", 1, 'a'); return 0; }
+    "#;
+    let program = parse_program(input).unwrap();
+
+    let printf = &program.functions[0];
+    assert!(printf.is_variadic);
+    assert_eq!(printf.params.len(), 1);
+    assert!(!program.functions[1].is_variadic);
+
+    if let Stmt::ExprStmt(Some(Expr::Call { args, .. })) =
+        &program.functions[1].body.as_ref().unwrap().statements[0]
+    {
+        assert_eq!(args[0], Expr::StringLiteral("%d %c\ndone".into()));
+        assert_eq!(args.len(), 3);
+    } else {
+        panic!("Expected printf call");
+    }
+}
+
+#[test]
+fn test_storage_classes_and_globals() {
+    // 전역 변수 선언과 함수가 섞인 프로그램
+    let input = r#"
+    static int counter = 0;
+    extern int limit;
+    int *table[4], (*handler)(int);
+    static int helper(void) { return counter; }
+    extern int run(int n);
+    int main() { static int calls; extern int limit; return helper(); }
+    "#;
+    let program = parse_program(input).unwrap();
+    assert_eq!(program.globals.len(), 3);
+    assert_eq!(program.functions.len(), 3);
+    // 전역 변수와 함수가 섞인 원본 순서를 유지
+    assert_eq!(
+        program.order,
+        vec![
+            ExternalDecl::Global(0),
+            ExternalDecl::Global(1),
+            ExternalDecl::Global(2),
+            ExternalDecl::Function(0),
+            ExternalDecl::Function(1),
+            ExternalDecl::Function(2),
+        ]
+    );
+
+    if let Stmt::Declaration {
+        storage,
+        declarators,
+        ..
+    } = &program.globals[0]
+    {
+        assert_eq!(*storage, Some(StorageClass::Static));
+        assert_eq!(declarators[0].name, "counter");
+        assert_eq!(declarators[0].init, Some(Expr::IntLiteral(0)));
+    } else {
+        panic!("Expected global declaration");
+    }
+    if let Stmt::Declaration { declarators, .. } = &program.globals[2] {
+        assert_eq!(declarators[1].name, "handler");
+    } else {
+        panic!("Expected global declaration");
+    }
+
+    assert_eq!(program.functions[0].storage, Some(StorageClass::Static));
+    assert_eq!(program.functions[1].storage, Some(StorageClass::Extern));
+    assert_eq!(program.functions[2].storage, None);
+
+    if let Stmt::Declaration { storage, .. } =
+        &program.functions[2].body.as_ref().unwrap().statements[0]
+    {
+        assert_eq!(*storage, Some(StorageClass::Static));
+    } else {
+        panic!("Expected local declaration");
+    }
+}
@@ -1,6 +1,9 @@
 use crate::utils::parse_statement;
 use rustc_tape4::ast::expr::BinaryOp;
-use rustc_tape4::ast::{Expr, Stmt, TypeSpecifier};
+use rustc_tape4::ast::{Expr, Qualifiers, Stmt, TypeSpecifier};
+use rustc_tape4::consteval::ConstError;
+use rustc_tape4::parser::ParserError;
+use rustc_tape4::{Lexer, Parser};
 
 #[test]
 fn test_empty_statement() {
@@ -35,7 +38,10 @@ fn test_declaration_statement() {
     // 기본 변수 선언
     let stmt = parse_statement("int x = 10;");
 
-    if let Stmt::Declaration { ty, declarators } = stmt {
+    if let Stmt::Declaration {
+        ty, declarators, ..
+    } = stmt
+    {
         assert_eq!(ty, TypeSpecifier::Int);
         assert_eq!(declarators.len(), 1);
         assert_eq!(declarators[0].name, "x");
@@ -47,7 +53,10 @@ fn test_declaration_statement() {
     // 초기화 없는 선언
     let stmt = parse_statement("char c;");
 
-    if let Stmt::Declaration { ty, declarators } = stmt {
+    if let Stmt::Declaration {
+        ty, declarators, ..
+    } = stmt
+    {
         assert_eq!(ty, TypeSpecifier::Char);
         assert_eq!(declarators[0].name, "c");
         assert!(declarators[0].init.is_none());
@@ -55,12 +64,16 @@ fn test_declaration_statement() {
         panic!("Expected declaration statement");
     }
 
-    // 포인터 변수 선언
+    // 포인터 변수 선언: '*' 는 선언자에 속함
     let stmt = parse_statement("int* ptr;");
 
-    if let Stmt::Declaration { ty, .. } = stmt {
-        if let TypeSpecifier::Pointer(inner) = ty {
-            assert_eq!(*inner, TypeSpecifier::Int);
+    if let Stmt::Declaration {
+        ty, declarators, ..
+    } = stmt
+    {
+        assert_eq!(ty, TypeSpecifier::Int);
+        if let TypeSpecifier::Pointer(inner) = &declarators[0].ty {
+            assert_eq!(**inner, TypeSpecifier::Int);
         } else {
             panic!("Expected pointer type");
         }
@@ -74,7 +87,10 @@ fn test_multiple_declarators_in_declaration() {
     // 한 선언문에 여러 변수 선언 테스트
     let stmt = parse_statement("int x = 1, y, z = 3;");
 
-    if let Stmt::Declaration { ty, declarators } = stmt {
+    if let Stmt::Declaration {
+        ty, declarators, ..
+    } = stmt
+    {
         assert_eq!(ty, TypeSpecifier::Int);
         assert_eq!(declarators.len(), 3);
 
@@ -99,10 +115,16 @@ fn test_array_declaration() {
     // 배열 선언 테스트
     let stmt = parse_statement("int arr[5];");
 
-    if let Stmt::Declaration { ty, declarators } = stmt {
+    if let Stmt::Declaration {
+        ty, declarators, ..
+    } = stmt
+    {
         assert_eq!(ty, TypeSpecifier::Int);
         assert_eq!(declarators[0].name, "arr");
-        assert_eq!(declarators[0].array_size, Some(5));
+        assert_eq!(
+            declarators[0].ty,
+            TypeSpecifier::Array(Box::new(TypeSpecifier::Int), Some(5))
+        );
     } else {
         panic!("Expected array declaration");
     }
@@ -110,10 +132,16 @@ fn test_array_declaration() {
     // 배열 초기화 테스트
     let stmt = parse_statement("int nums[3] = {1, 2, 3};");
 
-    if let Stmt::Declaration { ty, declarators } = stmt {
+    if let Stmt::Declaration {
+        ty, declarators, ..
+    } = stmt
+    {
         assert_eq!(ty, TypeSpecifier::Int);
         assert_eq!(declarators[0].name, "nums");
-        assert_eq!(declarators[0].array_size, Some(3));
+        assert_eq!(
+            declarators[0].ty,
+            TypeSpecifier::Array(Box::new(TypeSpecifier::Int), Some(3))
+        );
 
         if let Some(Expr::InitializerList(items)) = &declarators[0].init {
             assert_eq!(items.len(), 3);
@@ -128,6 +156,42 @@ fn test_array_declaration() {
     }
 }
 
+#[test]
+fn test_constant_array_sizes() {
+    // 배열 크기는 정수 상수식
+    let stmt = parse_statement("int m[2 * 3][4 + 'a' - 'a'];");
+    if let Stmt::Declaration { declarators, .. } = stmt {
+        assert_eq!(
+            declarators[0].ty,
+            TypeSpecifier::Array(
+                Box::new(TypeSpecifier::Array(Box::new(TypeSpecifier::Int), Some(4))),
+                Some(6)
+            )
+        );
+    } else {
+        panic!("Expected array declaration");
+    }
+
+    // 0 이하의 크기, 상수가 아닌 크기, 0 으로 나누기
+    let cases = [
+        ("int a[0];", None),
+        ("int a[1 - 2];", None),
+        ("int a[n];", Some(ConstError::NotConstant)),
+        ("int a[4 / 0];", Some(ConstError::DivisionByZero)),
+    ];
+    for (input, expected) in cases {
+        let tokens = Lexer::new(input).collect_spanned_tokens();
+        let err = Parser::new(tokens).parse_statement().unwrap_err();
+        match (err, expected) {
+            (ParserError::InvalidArraySize { size, .. }, None) => assert!(size <= 0),
+            (ParserError::ConstantExpression { error, .. }, Some(expected)) => {
+                assert_eq!(error, expected)
+            }
+            (err, _) => panic!("Unexpected error for {input}: {:?}", err),
+        }
+    }
+}
+
 #[test]
 fn test_initializer_list_trailing_comma() {
     // 배열 초기화 리스트 후행 콤마 테스트
@@ -136,7 +200,10 @@ fn test_initializer_list_trailing_comma() {
     if let Stmt::Declaration { declarators, .. } = stmt {
         let d = &declarators[0];
         assert_eq!(d.name, "a");
-        assert_eq!(d.array_size, Some(3));
+        assert_eq!(
+            d.ty,
+            TypeSpecifier::Array(Box::new(TypeSpecifier::Int), Some(3))
+        );
 
         if let Some(Expr::InitializerList(v)) = &d.init {
             assert_eq!(
@@ -489,3 +556,152 @@ fn test_complex_nested_statements() {
         panic!("Expected complex nested block");
     }
 }
+
+#[test]
+fn test_float_declaration() {
+    // 실수 타입 선언 테스트
+    let stmt = parse_statement("double d = 1.5, e = .25;");
+
+    if let Stmt::Declaration {
+        ty, declarators, ..
+    } = stmt
+    {
+        assert_eq!(ty, TypeSpecifier::Double);
+        assert!(matches!(&declarators[0].init, Some(Expr::DoubleLiteral(v)) if *v == 1.5));
+        assert!(matches!(&declarators[1].init, Some(Expr::DoubleLiteral(v)) if *v == 0.25));
+    } else {
+        panic!("Expected declaration statement");
+    }
+
+    let stmt = parse_statement("float f = 2.0f;");
+
+    if let Stmt::Declaration {
+        ty, declarators, ..
+    } = stmt
+    {
+        assert_eq!(ty, TypeSpecifier::Float);
+        assert!(matches!(&declarators[0].init, Some(Expr::FloatLiteral(v)) if *v == 2.0));
+    } else {
+        panic!("Expected declaration statement");
+    }
+}
+
+#[test]
+fn test_complex_declarators() {
+    use TypeSpecifier::{Array, Int, Pointer};
+
+    // 다차원 배열, 포인터 배열, 배열 포인터, 포인터/비포인터 혼합
+    let stmt = parse_statement("int m[3][4], *a[4], (*p)[4], *q, r;");
+
+    if let Stmt::Declaration {
+        ty, declarators, ..
+    } = stmt
+    {
+        assert_eq!(ty, Int);
+        assert_eq!(
+            declarators[0].ty,
+            Array(Box::new(Array(Box::new(Int), Some(4))), Some(3))
+        );
+        assert_eq!(
+            declarators[1].ty,
+            Array(Box::new(Pointer(Box::new(Int))), Some(4))
+        );
+        assert_eq!(
+            declarators[2].ty,
+            Pointer(Box::new(Array(Box::new(Int), Some(4))))
+        );
+        assert_eq!(declarators[3].ty, Pointer(Box::new(Int)));
+        assert_eq!(declarators[4].ty, Int);
+    } else {
+        panic!("Expected declaration statement");
+    }
+}
+
+#[test]
+fn test_function_pointer_declarators() {
+    use TypeSpecifier::{Array, Char, Function, Int, Pointer};
+
+    let cb_ty = Pointer(Box::new(Function {
+        ret: Box::new(Int),
+        params: vec![Int, Char],
+        is_variadic: false,
+    }));
+
+    // 함수 포인터와 함수 포인터 배열
+    let stmt = parse_statement("int (*cb)(int, char), (*table[2])(int x, char);");
+
+    if let Stmt::Declaration { declarators, .. } = stmt {
+        assert_eq!(declarators[0].name, "cb");
+        assert_eq!(declarators[0].ty, cb_ty);
+        assert_eq!(declarators[1].name, "table");
+        assert_eq!(declarators[1].ty, Array(Box::new(cb_ty.clone()), Some(2)));
+    } else {
+        panic!("Expected declaration statement");
+    }
+
+    // 매개변수로 받은 함수 타입은 함수 포인터로 조정
+    let stmt = parse_statement("void (*apply)(int (*)(int, char), int f(int, char));");
+
+    if let Stmt::Declaration { declarators, .. } = stmt {
+        assert_eq!(
+            declarators[0].ty,
+            Pointer(Box::new(Function {
+                ret: Box::new(TypeSpecifier::Void),
+                params: vec![cb_ty.clone(), cb_ty],
+                is_variadic: false,
+            }))
+        );
+    } else {
+        panic!("Expected declaration statement");
+    }
+}
+
+#[test]
+fn test_qualified_declarators() {
+    use TypeSpecifier::{Char, Int, Pointer, Qualified};
+    let const_q = Qualifiers {
+        is_const: true,
+        is_volatile: false,
+    };
+
+    // 포인터 레벨마다 한정자
+    let stmt = parse_statement("const char * const p = 0, *q;");
+
+    if let Stmt::Declaration {
+        ty, declarators, ..
+    } = stmt
+    {
+        assert_eq!(ty, Qualified(const_q, Box::new(Char)));
+        assert_eq!(
+            declarators[0].ty,
+            Qualified(
+                const_q,
+                Box::new(Pointer(Box::new(Qualified(const_q, Box::new(Char)))))
+            )
+        );
+        assert_eq!(
+            declarators[1].ty,
+            Pointer(Box::new(Qualified(const_q, Box::new(Char))))
+        );
+    } else {
+        panic!("Expected declaration statement");
+    }
+
+    // 기본 타입 뒤의 한정자와 volatile
+    let stmt = parse_statement("int const volatile x = 1;");
+
+    if let Stmt::Declaration { ty, .. } = stmt {
+        assert_eq!(
+            ty,
+            Qualified(
+                Qualifiers {
+                    is_const: true,
+                    is_volatile: true,
+                },
+                Box::new(Int)
+            )
+        );
+    } else {
+        panic!("Expected declaration statement");
+    }
+}
@@ -0,0 +1,90 @@
+use crate::utils::{analyze_program, analyze_warnings};
+use rustc_tape4::semantic::analyzer::{SemanticError, SemanticWarning};
+
+#[test]
+fn test_constant_index_out_of_bounds() {
+    for (input, index) in [
+        ("int main() { int a[10]; a[10] = 1; return 0; }", 10),
+        ("int main() { int a[10]; return a[-1]; }", -1),
+        ("int a[10]; int main() { return a[2 * 5 + 1]; }", 11),
+        ("int main() { int m[2][3]; return m[1][3]; }", 3),
+        ("int main() { int a[] = {1, 2}; return a[2]; }", 2),
+    ] {
+        let result = analyze_program(input);
+        assert!(
+            matches!(result, Err(SemanticError::ArrayIndexOutOfBounds { index: i, .. }) if i == index),
+            "{input}: {result:?}"
+        );
+    }
+}
+
+#[test]
+fn test_constant_index_in_bounds() {
+    let input = r#"
+    int a[10];
+    int main() {
+        int m[2][3];
+        int *end = &a[10];
+        int *p = a;
+        m[1][2] = a[9] + a[3 * 3];
+        return p[20] + (end - p) + m[1][2];
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+}
+
+#[test]
+fn test_loop_array_bounds() {
+    let warned = |input: &str| {
+        analyze_warnings(input)
+            .iter()
+            .filter_map(|w| match w {
+                SemanticWarning::ArrayBounds { min, max, .. } => Some((*min, *max)),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        warned("int main() { int a[10]; int i; for (i = 0; i <= 10; i++) a[i] = i; return a[0]; }"),
+        vec![(0, 10)]
+    );
+    assert_eq!(
+        warned("int main() { int a[10]; for (int i = 9; i >= -1; i--) a[i] = 0; return a[0]; }"),
+        vec![(-1, 9)]
+    );
+    // 범위 안의 루프, 본문에서 루프 변수를 바꾸는 루프는 경고하지 않음
+    assert!(
+        warned("int main() { int a[10]; for (int i = 0; i < 10; ++i) a[i] = 0; return a[0]; }")
+            .is_empty()
+    );
+    assert!(
+        warned(
+            "int main() { int a[10]; for (int i = 0; i <= 10; i++) { a[i] = 0; i++; } return a[0]; }"
+        )
+        .is_empty()
+    );
+    // 루프 변수를 검사하는 조건 아래의 인덱싱은 경고하지 않음 (다른 변수 조건은 그대로)
+    let input = r#"
+    int main(int argc, char **argv) {
+        int a[10];
+        int i;
+        for (i = 0; i <= 10; i++) { if (i < 10) a[i] = 0; }
+        for (i = 0; i <= 10; i++) { if (i != 10 && argc) a[i] = 0; }
+        for (i = 0; i <= 10; i++) a[0] = i < 10 && a[i];
+        for (i = 0; i <= 10; i++) { if (argc) a[i] = 0; }
+        return a[0];
+    }
+    "#;
+    let warnings = analyze_warnings(input);
+    let bounds: Vec<_> = warnings
+        .iter()
+        .filter(|w| matches!(w, SemanticWarning::ArrayBounds { .. }))
+        .collect();
+    assert_eq!(bounds.len(), 1);
+    assert!(
+        !warnings
+            .iter()
+            .any(|w| matches!(w, SemanticWarning::IndexOutOfRange { .. }))
+    );
+}
@@ -0,0 +1,99 @@
+use crate::utils::parse_program;
+use rustc_tape4::SemanticAnalyzer;
+use rustc_tape4::semantic::call_graph::{CALL_OVERHEAD, CallGraph};
+
+const PROGRAM: &str = r#"
+int fact(int n) { if (n <= 1) return 1; return n * fact(n - 1); }
+int is_odd(int n);
+int is_even(int n) { if (n == 0) return 1; return is_odd(n - 1); }
+int is_odd(int n) { if (n == 0) return 0; return is_even(n - 1); }
+int leaf(int x) { int buf[16]; buf[0] = x; return buf[0]; }
+int mid(int x) { return leaf(x) + 1; }
+int twice(int x) { return x + x; }
+int apply(int (*op)(int), int x) { return op(x); }
+int main() { putchar(65); return mid(1) + apply(twice, 2); }
+"#;
+
+#[test]
+fn test_call_graph_and_recursion() {
+    let program = parse_program(PROGRAM).unwrap();
+    let model = SemanticAnalyzer::new(&program).analyze().unwrap();
+    let graph = CallGraph::new(&program, &model);
+
+    assert_eq!(graph.callees("main"), vec!["mid", "apply", "putchar"]);
+    // 함수 포인터 호출은 주소가 쓰인 함수로 이어짐
+    assert_eq!(graph.callees("apply"), vec!["twice"]);
+    assert!(graph.calls.iter().any(|edge| edge.indirect));
+    assert_eq!(graph.function("putchar").unwrap().frame_size, None);
+
+    assert_eq!(
+        graph.recursion(),
+        vec![
+            vec!["fact".to_string()],
+            vec!["is_even".to_string(), "is_odd".to_string()]
+        ]
+    );
+    assert!(!graph.function("mid").unwrap().recursive);
+}
+
+#[test]
+fn test_max_stack_depth() {
+    let program = parse_program(PROGRAM).unwrap();
+    let model = SemanticAnalyzer::new(&program).analyze().unwrap();
+    let graph = CallGraph::new(&program, &model);
+
+    // main(0) -> mid(x: 8) -> leaf(x + buf[16]: 72)
+    let depth = graph.max_stack_depth("main").unwrap();
+    assert_eq!(depth.path, vec!["main", "mid", "leaf"]);
+    assert_eq!(depth.bytes, 8 + 72 + 3 * CALL_OVERHEAD);
+    assert!(!depth.unbounded);
+
+    // 재귀에 도달하면 상한 없음 (재귀 묶음은 구성원마다 한 번씩만 셈)
+    let depth = graph.max_stack_depth("is_even").unwrap();
+    assert!(depth.unbounded);
+    assert_eq!(depth.path, vec!["is_even", "is_odd"]);
+    assert_eq!(depth.bytes, 8 + 8 + 2 * CALL_OVERHEAD);
+    assert!(graph.max_stack_depth("missing").is_none());
+}
+
+#[test]
+fn test_stack_depth_through_recursion_group() {
+    // main -> a <-> b -> big: 묶음에 들어온 a 가 아니라 b 에서 나가는 호출도 셈
+    let input = r#"
+    int big(int x) { int buf[100]; buf[0] = x; return buf[0]; }
+    int b(int n);
+    int a(int n) { if (n == 0) return 0; return b(n - 1); }
+    int b(int n) { if (n == 0) return big(n); return a(n - 1); }
+    int main() { return a(3); }
+    "#;
+    let program = parse_program(input).unwrap();
+    let model = SemanticAnalyzer::new(&program).analyze().unwrap();
+    let graph = CallGraph::new(&program, &model);
+
+    // big: x + buf[100] = 408 바이트
+    assert_eq!(graph.function("big").unwrap().frame_size, Some(408));
+    let depth = graph.max_stack_depth("main").unwrap();
+    assert_eq!(depth.path, vec!["main", "a", "b", "big"]);
+    assert_eq!(depth.bytes, 8 + 8 + 408 + 4 * CALL_OVERHEAD);
+    assert!(depth.unbounded);
+}
+
+#[test]
+fn test_call_graph_output() {
+    let program = parse_program(PROGRAM).unwrap();
+    let model = SemanticAnalyzer::new(&program).analyze().unwrap();
+    let graph = CallGraph::new(&program, &model);
+
+    let text = graph.to_text("main");
+    assert!(text.contains("  leaf: frame 72 bytes\n"));
+    assert!(text.contains("  apply: frame 16 bytes, calls twice (indirect)\n"));
+    assert!(text.contains("  is_even <-> is_odd (mutual)\n"));
+    assert!(text.contains("stack depth from main: 128 bytes (main -> mid -> leaf)\n"));
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph call_graph {\n"));
+    assert!(dot.contains("    \"fact\" [label=\"fact\\n8 B\", color=red];\n"));
+    assert!(dot.contains("    \"putchar\" [label=\"putchar\", shape=box, style=dashed];\n"));
+    assert!(dot.contains("    \"apply\" -> \"twice\" [style=dashed];\n"));
+    assert!(dot.ends_with("}\n"));
+}
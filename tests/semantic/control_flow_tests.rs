@@ -0,0 +1,156 @@
+use crate::utils::{analyze_program, analyze_warnings, parse_program};
+use rustc_tape4::SemanticAnalyzer;
+use rustc_tape4::ast::{Pos, SourceMap, Stmt};
+use rustc_tape4::semantic::analyzer::{SemanticError, SemanticWarning};
+
+fn missing_returns(input: &str) -> Vec<String> {
+    analyze_warnings(input)
+        .into_iter()
+        .filter_map(|w| match w {
+            SemanticWarning::MissingReturn { function, .. } => Some(function),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_missing_return() {
+    let input = r#"
+    int sign(int x) {
+        if (x > 0) {
+            return 1;
+        } else if (x < 0) {
+            return -1;
+        }
+    }
+    int abs(int x) {
+        if (x < 0) return -x;
+        else return x;
+    }
+    void log(int x) {}
+    int main() {}
+    "#;
+    // main 은 끝에 도달하면 0 을 반환
+    assert_eq!(missing_returns(input), vec!["sign".to_string()]);
+
+    // 경고는 함수 이름을 가리킴
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.analyze().unwrap();
+    let map = SourceMap::new(&program);
+    let positions: Vec<_> = analyzer
+        .warnings
+        .iter()
+        .filter(|w| matches!(w, SemanticWarning::MissingReturn { .. }))
+        .map(|w| w.pos(&map))
+        .collect();
+    assert_eq!(positions, vec![Some(Pos::new(2, 9))]);
+}
+
+#[test]
+fn test_infinite_loops() {
+    let input = r#"
+    int spin(int x) {
+        while (1) {
+            x++;
+        }
+    }
+    int forever(int x) {
+        for (;;) {
+            if (x > 10) return x;
+            x++;
+        }
+    }
+    int escapes(int x) {
+        while (1) {
+            if (x > 10) break;
+            x++;
+        }
+    }
+    int counted(int x) {
+        for (int i = 0; i < 3; i++) {
+            return i;
+        }
+    }
+    int main() { return 0; }
+    "#;
+    assert_eq!(
+        missing_returns(input),
+        vec!["escapes".to_string(), "counted".to_string()]
+    );
+}
+
+#[test]
+fn test_unreachable_code() {
+    let input = r#"
+    int main() {
+        int x = 0;
+        while (x < 10) {
+            x++;
+            continue;
+            x--;
+        }
+        for (;;) {
+            break;
+            x = 1;
+            x = 2;
+        }
+        return x;
+        x = 3;
+        {
+            x = 4;
+        }
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.analyze().unwrap();
+
+    // 도달할 수 없는 구간마다 첫 문장만 경고
+    let body = &program.functions[0].body.as_ref().unwrap().statements;
+    let loop_body = |index: usize| match &body[index] {
+        Stmt::While { body, .. } | Stmt::For { body, .. } => match body.as_ref() {
+            Stmt::Block(block) => &block.statements,
+            _ => panic!("Expected block"),
+        },
+        _ => panic!("Expected loop"),
+    };
+    let expected = [loop_body(1)[2].id(), loop_body(2)[1].id(), body[4].id()];
+    let found: Vec<_> = analyzer
+        .warnings
+        .iter()
+        .map(|w| match w {
+            SemanticWarning::UnreachableCode { function, stmt } => {
+                assert_eq!(function, "main");
+                *stmt
+            }
+            other => panic!("Unexpected warning: {:?}", other),
+        })
+        .collect();
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn test_return_without_value() {
+    let input = r#"
+    int f(int x) {
+        if (x) return;
+        return 1;
+    }
+    int main() { return f(0); }
+    "#;
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::MissingReturnValue { .. })
+    ));
+
+    // void 함수의 'return;' 은 허용
+    let input = r#"
+    void f(int x) {
+        if (x) return;
+        x = 1;
+    }
+    int main() { f(0); return 0; }
+    "#;
+    assert!(analyze_warnings(input).is_empty());
+}
@@ -0,0 +1,195 @@
+use crate::utils::{analyze_warnings, parse_program};
+use rustc_tape4::SemanticAnalyzer;
+use rustc_tape4::ast::{Expr, Pos, SourceMap, Stmt};
+use rustc_tape4::semantic::analyzer::SemanticWarning;
+
+/// 초기화되지 않았을 수 있는 변수 이름 (경고 순서대로)
+fn uninitialized(input: &str) -> Vec<String> {
+    analyze_warnings(input)
+        .into_iter()
+        .filter_map(|w| match w {
+            SemanticWarning::MaybeUninitialized { name, .. } => Some(name),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_use_before_assignment() {
+    let input = r#"
+    int main() {
+        int x;
+        return x + 1;
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.analyze().unwrap();
+
+    // 경고는 선언과 읽은 식을 가리킴
+    let body = &program.functions[0].body.as_ref().unwrap().statements;
+    let Stmt::Declaration { declarators, .. } = &body[0] else {
+        panic!("Expected declaration");
+    };
+    let Stmt::Return(Some(Expr::BinaryOp { lhs, .. })) = &body[1] else {
+        panic!("Expected return");
+    };
+    assert_eq!(
+        analyzer.warnings,
+        vec![SemanticWarning::MaybeUninitialized {
+            name: "x".to_string(),
+            decl: declarators[0].id(),
+            expr: lhs.id(),
+        }]
+    );
+
+    // 출력할 때는 주소 대신 원본 위치로 읽은 식과 선언을 가리킴
+    let map = SourceMap::new(&program);
+    let warning = &analyzer.warnings[0];
+    assert_eq!(warning.pos(&map), Some(Pos::new(4, 16)));
+    assert_eq!(
+        warning.message(&map),
+        "'x' may be used uninitialized (declared at 3:13)"
+    );
+}
+
+#[test]
+fn test_branches() {
+    // 모든 분기에서 대입
+    let input = r#"
+    int f(int c) {
+        int x;
+        if (c) x = 1;
+        else x = 2;
+        return x;
+    }
+    int main() { return f(1); }
+    "#;
+    assert!(uninitialized(input).is_empty());
+
+    // 한쪽 분기, && 의 오른쪽, 복합 대입
+    let input = r#"
+    int f(int c) {
+        int x;
+        int y;
+        int z;
+        if (c) x = 1;
+        if (c && (y = 2)) c = 0;
+        z += 1;
+        return x + y + z + x;
+    }
+    int main() { return f(1); }
+    "#;
+    assert_eq!(uninitialized(input), vec!["z", "x", "y"]);
+
+    // 대입 없이 반환하는 분기는 합류하지 않음
+    let input = r#"
+    int f(int c) {
+        int x;
+        if (c) return 0;
+        else x = 1;
+        return x;
+    }
+    int main() { return f(1); }
+    "#;
+    assert!(uninitialized(input).is_empty());
+
+    // &&, || 의 오른쪽 대입은 조건이 참(&&) 또는 거짓(||)인 경로에서만 보장됨
+    let input = r#"
+    int f(int c) {
+        int x;
+        int y;
+        int z;
+        if (c && (x = 1)) c = x;
+        if (c || (y = 2)) c = 0;
+        else c = y;
+        while (c > 0 && (z = c)) c = c - z;
+        if (!(c || (x = 3))) return x;
+        return 0;
+    }
+    int main() { return f(1); }
+    "#;
+    assert!(uninitialized(input).is_empty());
+
+    let input = r#"
+    int f(int c) {
+        int x;
+        int y;
+        if (c && (x = 1)) c = 0;
+        else c = x;
+        if (c || (y = 2)) return y;
+        return 0;
+    }
+    int main() { return f(1); }
+    "#;
+    assert_eq!(uninitialized(input), vec!["x", "y"]);
+}
+
+#[test]
+fn test_loops() {
+    // 한 번도 실행되지 않을 수 있는 반복문, 이전 반복의 값을 읽는 반복문
+    let input = r#"
+    int main() {
+        int n = 3;
+        int last;
+        int sum;
+        while (n > 0) {
+            last = n;
+            n--;
+        }
+        for (int i = 0; i < 3; i++) {
+            sum = sum + i;
+        }
+        return last;
+    }
+    "#;
+    assert_eq!(uninitialized(input), vec!["sum", "last"]);
+
+    // 무한 루프는 break 로만 빠져나감
+    let input = r#"
+    int main() {
+        int x;
+        int n = 0;
+        while (1) {
+            n++;
+            if (n > 3) {
+                x = n;
+                break;
+            }
+        }
+        for (;;) {
+            if (n > 0) break;
+        }
+        return x;
+    }
+    "#;
+    assert!(uninitialized(input).is_empty());
+}
+
+#[test]
+fn test_conservative_cases() {
+    // 주소가 취해진 변수, 배열, static 지역 변수는 경고하지 않음
+    let input = r#"
+    void init(int *p);
+    int main() {
+        int x;
+        int a[3];
+        static int calls;
+        init(&x);
+        a[0] = 1;
+        calls++;
+        return x + a[0] + a[1] + calls;
+    }
+    "#;
+    assert!(uninitialized(input).is_empty());
+
+    // 같은 변수는 한 번만 경고
+    let input = r#"
+    int main() {
+        int x;
+        x++;
+        return x * x;
+    }
+    "#;
+    assert_eq!(uninitialized(input), vec!["x"]);
+}
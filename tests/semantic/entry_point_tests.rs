@@ -0,0 +1,114 @@
+use crate::utils::{analyze_program, link_units, parse_program};
+use rustc_tape4::SemanticAnalyzer;
+use rustc_tape4::linker::{LinkError, Linker};
+use rustc_tape4::semantic::analyzer::SemanticError;
+use rustc_tape4::semantic::entry_point::EntryPoint;
+
+fn analyze_with(input: &str, entry: EntryPoint, prelude: bool) -> Result<(), SemanticError> {
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.entry = entry;
+    analyzer.prelude = prelude;
+    analyzer.analyze().map(|_| ())
+}
+
+#[test]
+fn test_main_signatures() {
+    for input in [
+        "int main() { return 0; }",
+        "int main(void) { return 0; }",
+        "int main(int argc, char **argv) { return argc; }",
+        "int main(int argc, char *argv[]) { return argc; }",
+        "int main(int argc, char **argv, char **envp) { return argc; }",
+    ] {
+        assert!(analyze_program(input).is_ok(), "{input}");
+    }
+
+    // strict 는 표준의 두 형태만 허용
+    let envp = "int main(int argc, char **argv, char **envp) { return argc; }";
+    assert!(matches!(
+        analyze_with(envp, EntryPoint::Strict, true),
+        Err(SemanticError::InvalidMainSignature { .. })
+    ));
+
+    for input in [
+        "void main() {}",
+        "int main(int argc) { return argc; }",
+        "int main(char **argv, int argc) { return argc; }",
+        "int main(int argc, char **argv, ...) { return argc; }",
+    ] {
+        assert!(
+            matches!(
+                analyze_program(input),
+                Err(SemanticError::InvalidMainSignature { .. })
+            ),
+            "{input}"
+        );
+        assert!(
+            analyze_with(input, EntryPoint::None, true).is_ok(),
+            "{input}"
+        );
+    }
+    assert!(matches!(
+        analyze_program("static int main() { return 0; }"),
+        Err(SemanticError::StaticMain)
+    ));
+    assert!(matches!(
+        analyze_program("int main = 0;"),
+        Err(SemanticError::MainNotFunction)
+    ));
+}
+
+#[test]
+fn test_missing_entry_point() {
+    let lib_c = "int add(int a, int b) { return a + b; }";
+    assert!(matches!(
+        link_units(&[("lib.c", lib_c)]),
+        Err(LinkError::MissingEntryPoint)
+    ));
+
+    let program = parse_program(lib_c).unwrap();
+    let model = SemanticAnalyzer::new(&program).analyze().unwrap();
+    let mut linker = Linker::new();
+    linker.entry = EntryPoint::None;
+    linker.add_unit("lib.c", &model);
+    assert!(linker.link().is_ok());
+}
+
+#[test]
+fn test_prelude_functions() {
+    // 프로토타입 없이 호출하고 링크 단계에서 런타임이 제공
+    let input = r#"
+    int main() {
+        char *buf = malloc(16);
+        int c = getchar();
+        putchar(c);
+        printf("%d\n", c);
+        free(buf);
+        exit(0);
+        return 0;
+    }
+    "#;
+    assert!(link_units(&[("main.c", input)]).is_ok());
+
+    // 같은 시그니처의 재선언, 다른 시그니처의 사용자 선언은 허용
+    assert!(analyze_program("int putchar(int c); int main() { return putchar(65); }").is_ok());
+    assert!(
+        analyze_program("int printf(char *f, ...); int main() { return printf(\"x\"); }").is_ok()
+    );
+
+    // 같은 이름의 전역 변수, static 함수 정의도 프렐류드 선언을 대체
+    for input in [
+        "int free = 3; int main() { return free; }",
+        "static int getchar(void) { return 1; } int main() { return getchar(); }",
+    ] {
+        assert!(analyze_program(input).is_ok(), "{input}");
+        assert!(link_units(&[("main.c", input)]).is_ok(), "{input}");
+    }
+
+    // 끄면 선언이 필요
+    assert!(matches!(
+        analyze_with(input, EntryPoint::Hosted, false),
+        Err(SemanticError::UndefinedSymbol { name }) if name == "malloc"
+    ));
+}
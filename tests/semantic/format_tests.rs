@@ -0,0 +1,91 @@
+use crate::utils::{analyze_warnings, parse_program};
+use rustc_tape4::SemanticAnalyzer;
+use rustc_tape4::ast::{Pos, SourceMap, TypeSpecifier};
+use rustc_tape4::semantic::analyzer::SemanticWarning;
+
+/// 형식 관련 경고만 (지정, 기대 타입) / 개수 / 잘못된 지정으로 요약
+fn format_warnings(body: &str) -> Vec<String> {
+    let input = format!(
+        "int main() {{ int n = 1; char c = 'a'; float f = 1.0f; double d = 2.0; char *s = \"x\"; {body} return 0; }}"
+    );
+    analyze_warnings(&input)
+        .into_iter()
+        .filter_map(|w| match w {
+            SemanticWarning::FormatMismatch {
+                directive,
+                expected,
+                ..
+            } => Some(format!("{directive}: {expected}")),
+            SemanticWarning::FormatArgumentCount {
+                expected, found, ..
+            } => Some(format!("count {expected} != {found}")),
+            SemanticWarning::InvalidFormat { directive, .. } => {
+                Some(format!("invalid {directive}"))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_printf_formats() {
+    // char 는 int, float 는 double 로 승격되어 맞음
+    assert!(
+        format_warnings(
+            r#"printf("%d %i %c %x %5.2f %e %s %p %% %-*d\n", n, c, c, n, f, d, s, &n, 4, n);"#
+        )
+        .is_empty()
+    );
+    assert_eq!(
+        format_warnings(r#"printf("%d %s %p\n", d, n, n);"#),
+        vec!["%d: int", "%s: char *", "%p: pointer"]
+    );
+    // long 은 이 언어에 없으므로 int 인자는 항상 불일치
+    assert_eq!(format_warnings(r#"printf("%ld", n);"#), vec!["%ld: long"]);
+    assert_eq!(
+        format_warnings(r#"printf("%d %d\n", n);"#),
+        vec!["count 2 != 1"]
+    );
+    assert_eq!(
+        format_warnings(r#"printf("%d\n", n, n);"#),
+        vec!["count 1 != 2"]
+    );
+    assert_eq!(format_warnings(r#"printf("%y", n);"#), vec!["invalid %y"]);
+}
+
+#[test]
+fn test_scanf_formats() {
+    assert!(
+        format_warnings(r#"scanf("%d %c %f %lf %s %*d %[^,]", &n, &c, &f, &d, s, s);"#).is_empty()
+    );
+    assert_eq!(
+        format_warnings(r#"scanf("%d %f", n, &d);"#),
+        vec!["%d: int *", "%f: float *"]
+    );
+}
+
+#[test]
+fn test_format_warning_location() {
+    // 경고는 맞지 않는 인자를, 개수 경고는 호출을 가리킴
+    let input =
+        "int main() {\n  char buf[8];\n  sprintf(buf, \"%s\", 1);\n  return printf(\"%d\");\n}";
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.analyze().unwrap();
+    assert!(matches!(
+        analyzer.warnings.as_slice(),
+        [
+            SemanticWarning::FormatMismatch {
+                found: TypeSpecifier::Int,
+                ..
+            },
+            SemanticWarning::FormatArgumentCount { .. }
+        ]
+    ));
+    let map = SourceMap::new(&program);
+    let positions: Vec<_> = analyzer.warnings.iter().map(|w| w.pos(&map)).collect();
+    assert_eq!(
+        positions,
+        vec![Some(Pos::new(3, 22)), Some(Pos::new(4, 10))]
+    );
+}
@@ -0,0 +1,159 @@
+use crate::utils::{analyze_program, parse_program};
+use rustc_tape4::SemanticAnalyzer;
+use rustc_tape4::ast::{Stmt, TypeSpecifier};
+use rustc_tape4::semantic::analyzer::SemanticError;
+use rustc_tape4::semantic::model::InitElement;
+
+fn int_array(size: usize) -> TypeSpecifier {
+    TypeSpecifier::Array(Box::new(TypeSpecifier::Int), Some(size))
+}
+
+#[test]
+fn test_initializer_expansion() {
+    let input = r#"
+    int a[] = {1, 2, 3};
+    int b[4] = {1, 2};
+    int m[2][3] = {{1}, {4, 5}};
+    int n[2][2] = {1, 2, 3};
+    char s[] = "hi";
+    char t[4] = "ab";
+    int main() { return a[0] + b[0] + m[0][0] + n[0][0] + s[0] + t[0]; }
+    "#;
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    let model = analyzer.analyze().unwrap();
+    let init = |index: usize| match &program.globals[index] {
+        Stmt::Declaration { declarators, .. } => model.initializer(declarators[0].id()).unwrap(),
+        _ => panic!("Expected declaration"),
+    };
+
+    // 크기 추론: 심볼 타입도 완성됨
+    assert_eq!(init(0).ty, int_array(3));
+    assert_eq!(
+        model.symbols.lookup_global("a").map(|s| &s.ty),
+        Some(&int_array(3))
+    );
+    assert!(matches!(
+        init(0).elements.as_slice(),
+        [
+            InitElement::Value { offset: 0, .. },
+            InitElement::Value { offset: 4, .. },
+            InitElement::Value { offset: 8, .. }
+        ]
+    ));
+
+    // 빠진 원소는 0 으로 채움
+    assert!(matches!(
+        init(1).elements.as_slice(),
+        [
+            InitElement::Value { offset: 0, .. },
+            InitElement::Value { offset: 4, .. },
+            InitElement::Zero { offset: 8, size: 8 }
+        ]
+    ));
+
+    // 하위 배열 중괄호: 각 행의 나머지를 채움
+    assert!(matches!(
+        init(2).elements.as_slice(),
+        [
+            InitElement::Value { offset: 0, .. },
+            InitElement::Zero { offset: 4, size: 8 },
+            InitElement::Value { offset: 12, .. },
+            InitElement::Value { offset: 16, .. },
+            InitElement::Zero {
+                offset: 20,
+                size: 4
+            }
+        ]
+    ));
+
+    // 중괄호 생략: 평평한 목록이 행을 차례로 채움
+    assert!(matches!(
+        init(3).elements.as_slice(),
+        [
+            InitElement::Value { offset: 0, .. },
+            InitElement::Value { offset: 4, .. },
+            InitElement::Value { offset: 8, .. },
+            InitElement::Zero {
+                offset: 12,
+                size: 4
+            }
+        ]
+    ));
+
+    // 문자열 리터럴은 '\0' 까지 바이트로 펼침
+    assert_eq!(
+        init(4).ty,
+        TypeSpecifier::Array(Box::new(TypeSpecifier::Char), Some(3))
+    );
+    assert_eq!(
+        init(4).elements,
+        vec![
+            InitElement::Byte {
+                offset: 0,
+                value: b'h'
+            },
+            InitElement::Byte {
+                offset: 1,
+                value: b'i'
+            },
+            InitElement::Byte {
+                offset: 2,
+                value: 0
+            },
+        ]
+    );
+    assert!(matches!(
+        init(5).elements.last(),
+        Some(InitElement::Zero { offset: 3, size: 1 })
+    ));
+}
+
+#[test]
+fn test_local_initializer_completes_frame() {
+    let input = "int main() { int a[] = {1, 2, 3, 4, 5}; return a[4]; }";
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    let model = analyzer.analyze().unwrap();
+    let frame = model.frame("main").unwrap();
+    assert_eq!(frame.slots[0].ty, int_array(5));
+    assert_eq!(frame.size, 24);
+}
+
+#[test]
+fn test_invalid_initializers() {
+    for (input, expected) in [
+        (
+            "int a[2] = {1, 2, 3}; int main() { return a[0]; }",
+            "too many",
+        ),
+        (
+            "int m[2][2] = {{1, 2, 3}}; int main() { return m[0][0]; }",
+            "too many",
+        ),
+        (
+            "char s[2] = \"abc\"; int main() { return s[0]; }",
+            "too many",
+        ),
+        ("int x = {1}; int main() { return x; }", "scalar brace"),
+        (
+            "int m[2] = {{1}, 2}; int main() { return m[0]; }",
+            "scalar brace",
+        ),
+        ("int main() { int a[]; return 0; }", "incomplete"),
+        ("int main() { int a[] = {}; return 0; }", "incomplete"),
+        (
+            "int main() { int x; x = {1}; return x; }",
+            "unexpected list",
+        ),
+    ] {
+        let result = analyze_program(input);
+        let ok = match expected {
+            "too many" => matches!(result, Err(SemanticError::TooManyInitializers { .. })),
+            "scalar brace" => matches!(result, Err(SemanticError::ScalarBraceInitializer { .. })),
+            "incomplete" => matches!(result, Err(SemanticError::IncompleteArray { .. })),
+            _ => matches!(result, Err(SemanticError::UnexpectedInitializerList)),
+        };
+        assert!(ok, "{input}: {result:?}");
+    }
+}
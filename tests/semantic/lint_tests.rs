@@ -0,0 +1,156 @@
+use crate::utils::{analyze_warnings, parse_program};
+use rustc_tape4::SemanticAnalyzer;
+use rustc_tape4::ast::{Pos, SourceMap};
+use rustc_tape4::semantic::analyzer::{SemanticError, SemanticWarning};
+use rustc_tape4::semantic::lint::{Lint, LintConfig, LintError};
+
+/// 경고 옵션을 적용해 분석한 뒤 (성공 여부, 남은 경고의 lint 이름) 반환
+fn lint_names(input: &str, flags: &[&str]) -> (bool, Vec<&'static str>) {
+    let program = parse_program(input).expect("파싱 실패");
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    for flag in flags {
+        analyzer.lints.apply_flag(flag).unwrap();
+    }
+    let ok = analyzer.analyze().is_ok();
+    let names = analyzer.warnings.iter().map(|w| w.lint().name()).collect();
+    (ok, names)
+}
+
+#[test]
+fn test_unused_lints() {
+    let input = r#"
+    static int helper(int unused) { return 0; }
+    static int used(int n) { return n; }
+    int main() {
+        int x;
+        int y = 1;
+        static int counter;
+        return used(y);
+    }
+    "#;
+    let warnings = analyze_warnings(input);
+    let unused: Vec<_> = warnings
+        .iter()
+        .filter_map(|w| match w {
+            SemanticWarning::UnusedVariable { name, .. } => Some(name.as_str()),
+            SemanticWarning::UnusedFunction { name, .. } => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(unused, vec!["helper", "x", "counter"]);
+
+    // unused-parameter 는 기본으로 꺼져 있음
+    assert!(
+        !warnings
+            .iter()
+            .any(|w| matches!(w, SemanticWarning::UnusedParameter { .. }))
+    );
+    let (_, names) = lint_names(input, &["-Wunused-parameter", "-Wno-unused-variable"]);
+    assert_eq!(names, vec!["unused-function", "unused-parameter"]);
+
+    // 쓰이지 않는 static 함수는 (프로토타입이 앞에 있어도) 정의 위치를 가리킴
+    let input = r#"
+    static int helper(void);
+    static int helper(void) { return 0; }
+    int main() { return 0; }
+    "#;
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.analyze().unwrap();
+    let map = SourceMap::new(&program);
+    let positions: Vec<_> = analyzer.warnings.iter().map(|w| w.pos(&map)).collect();
+    assert_eq!(positions, vec![Some(Pos::new(3, 16))]);
+}
+
+#[test]
+fn test_shadow_lint() {
+    let input = r#"
+    int count;
+    int f(int count) { return count; }
+    int main() {
+        int x = 1;
+        {
+            int x = 2;
+            x++;
+        }
+        int y = 0;
+        for (int y = 0; y < 3; y++) {}
+        { int later = 0; later++; }
+        int later = x + y;
+        return later + f(1);
+    }
+    "#;
+    // 매개변수 count, 블록의 x, for 의 y (나중에 선언된 바깥 later 는 가리지 않음)
+    let (_, names) = lint_names(input, &["-Wshadow"]);
+    assert_eq!(names, vec!["shadow", "shadow", "shadow"]);
+    let (_, names) = lint_names(input, &[]);
+    assert!(names.is_empty());
+}
+
+#[test]
+fn test_assignment_in_condition() {
+    let input = r#"
+    int main() {
+        int x = 0;
+        int y = 1;
+        if (x = y) {
+            x++;
+        }
+        if (x == y) {
+            y++;
+        }
+        // 괄호를 한 번 더 감싼 대입은 의도한 것으로 봄
+        if ((y = x)) {
+            y++;
+        }
+        return x;
+    }
+    "#;
+    let (_, names) = lint_names(input, &[]);
+    assert_eq!(names, vec!["parentheses"]);
+    let (_, names) = lint_names(input, &["-Wno-parentheses"]);
+    assert!(names.is_empty());
+}
+
+#[test]
+fn test_werror_and_flags() {
+    let input = r#"
+    int main() {
+        int unused;
+        return 0;
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.lints.apply_flag("-Werror").unwrap();
+    assert!(matches!(
+        analyzer.analyze(),
+        Err(SemanticError::WarningsAsErrors { count: 1 })
+    ));
+    assert_eq!(analyzer.warnings.len(), 1);
+
+    // 꺼진 경고는 -Werror 에서도 실패 원인이 아님
+    let (ok, names) = lint_names(input, &["-Werror", "-Wno-unused-variable"]);
+    assert!(ok && names.is_empty());
+
+    // gcc 처럼 -Wall 은 기본 묶음, -Wextra 는 unused-parameter 를 더하고 shadow 는 -Wshadow 로만
+    let mut config = LintConfig::default();
+    config.apply_flag("-Wno-format").unwrap();
+    config.apply_flag("-Wall").unwrap();
+    assert_eq!(config, LintConfig::default());
+    assert!(!config.is_enabled(Lint::UnusedParameter) && !config.is_enabled(Lint::Shadow));
+    config.apply_flag("-Wextra").unwrap();
+    assert!(config.is_enabled(Lint::UnusedParameter) && !config.is_enabled(Lint::Shadow));
+    config.apply_flag("-Wshadow").unwrap();
+    assert!(Lint::ALL.into_iter().all(|lint| config.is_enabled(lint)));
+    assert_eq!(
+        config.apply_flag("-Wunknown"),
+        Err(LintError::UnknownLint {
+            name: "unknown".to_string()
+        })
+    );
+    assert_eq!(
+        Lint::from_name("maybe-uninitialized"),
+        Some(Lint::MaybeUninitialized)
+    );
+}
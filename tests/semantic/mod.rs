@@ -0,0 +1,12 @@
+mod bounds_tests;
+mod call_graph_tests;
+mod control_flow_tests;
+mod definite_init_tests;
+mod entry_point_tests;
+mod format_tests;
+mod initializer_tests;
+mod lint_tests;
+mod model_tests;
+mod type_checker_tests;
+mod value_range_tests;
+mod void_tests;
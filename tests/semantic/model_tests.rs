@@ -0,0 +1,202 @@
+use crate::utils::parse_program;
+use rustc_tape4::SemanticAnalyzer;
+use rustc_tape4::ast::{Expr, Stmt, TypeSpecifier};
+use rustc_tape4::semantic::symbol::symbol::ScopeId;
+use rustc_tape4::semantic::type_checker::conversion::ConversionKind;
+use rustc_tape4::semantic::type_checker::value_category::ValueCategory;
+
+/// 함수 본문의 n 번째 문장
+fn statement(program: &rustc_tape4::ast::Program, func: usize, index: usize) -> &Stmt {
+    &program.functions[func].body.as_ref().unwrap().statements[index]
+}
+
+#[test]
+fn test_expression_types_and_categories() {
+    let input = r#"
+    int main() {
+        char c = 'a';
+        int a[3];
+        c + 1;
+        a;
+        return 0;
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let model = SemanticAnalyzer::new(&program).analyze().unwrap();
+
+    // c + 1: rvalue int, c 는 char lvalue 이며 int 로 승격
+    let Stmt::ExprStmt(Some(sum)) = statement(&program, 0, 2) else {
+        panic!("Expected expression statement");
+    };
+    let info = model.expr(sum).unwrap();
+    assert_eq!(info.ty, TypeSpecifier::Int);
+    assert_eq!(info.category, ValueCategory::Rvalue);
+    let Expr::BinaryOp { lhs, .. } = sum else {
+        panic!("Expected binary op");
+    };
+    assert_eq!(model.type_of(lhs), Some(&TypeSpecifier::Char));
+    assert_eq!(model.expr(lhs).unwrap().category, ValueCategory::Lvalue);
+    let kinds: Vec<_> = model.conversions_of(lhs).map(|c| c.kind).collect();
+    assert_eq!(kinds, vec![ConversionKind::IntegerPromotion]);
+
+    // a: decay 전 배열 타입
+    let Stmt::ExprStmt(Some(array)) = statement(&program, 0, 3) else {
+        panic!("Expected expression statement");
+    };
+    assert_eq!(
+        model.expr(array).unwrap().category,
+        ValueCategory::ArrayLvalue
+    );
+    assert!(matches!(
+        model.type_of(array),
+        Some(TypeSpecifier::Array(_, Some(3)))
+    ));
+}
+
+#[test]
+fn test_identifier_references() {
+    let input = r#"
+    int x = 1;
+    int f(int x) { return x; }
+    int main() {
+        int y = x;
+        { int x = 2; y = x; }
+        return f(y);
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let model = SemanticAnalyzer::new(&program).analyze().unwrap();
+
+    let Stmt::Declaration { declarators, .. } = &program.globals[0] else {
+        panic!("Expected global declaration");
+    };
+    let global_x = declarators[0].id();
+
+    // 매개변수 x 는 전역 x 를 가림
+    let Stmt::Return(Some(param_use)) = statement(&program, 0, 0) else {
+        panic!("Expected return");
+    };
+    assert_eq!(
+        model.declaration_of(param_use),
+        Some(program.functions[0].params[0].id())
+    );
+
+    // int y = x; 는 전역 x
+    let Stmt::Declaration { declarators, .. } = statement(&program, 1, 0) else {
+        panic!("Expected declaration");
+    };
+    assert_eq!(
+        model.declaration_of(declarators[0].init.as_ref().unwrap()),
+        Some(global_x)
+    );
+
+    // 블록 안의 x 는 지역 x
+    let Stmt::Block(block) = statement(&program, 1, 1) else {
+        panic!("Expected block");
+    };
+    let Stmt::Declaration {
+        declarators: inner, ..
+    } = &block.statements[0]
+    else {
+        panic!("Expected declaration");
+    };
+    let Stmt::ExprStmt(Some(Expr::Assignment { right, .. })) = &block.statements[1] else {
+        panic!("Expected assignment");
+    };
+    assert_eq!(model.declaration_of(right), Some(inner[0].id()));
+
+    // 함수 이름은 함수 선언
+    let Stmt::Return(Some(Expr::Call { func, .. })) = statement(&program, 1, 2) else {
+        panic!("Expected call");
+    };
+    assert_eq!(model.declaration_of(func), Some(program.functions[0].id()));
+}
+
+#[test]
+fn test_symbol_ids_and_scope_tree() {
+    let input = r#"
+    int n;
+    int main() {
+        { int i = 1; n = i; }
+        { int i = 2; n = i + i; }
+        extern int n;
+        return n;
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let model = SemanticAnalyzer::new(&program).analyze().unwrap();
+
+    // 형제 블록의 같은 이름은 서로 다른 심볼
+    let assigned = |index: usize| {
+        let Stmt::Block(block) = statement(&program, 0, index) else {
+            panic!("Expected block");
+        };
+        let Stmt::ExprStmt(Some(Expr::Assignment { left, right, .. })) = &block.statements[1]
+        else {
+            panic!("Expected assignment");
+        };
+        (left.as_ref(), right.as_ref())
+    };
+    let (n_use, first_i) = assigned(0);
+    let (_, sum) = assigned(1);
+    let Expr::BinaryOp { lhs: second_i, .. } = sum else {
+        panic!("Expected binary op");
+    };
+    let first = model.symbol_of(first_i).unwrap();
+    let second = model.symbol_of(second_i).unwrap();
+    assert_ne!(first, second);
+    assert_eq!(model.symbol(first).name, "i");
+    assert_eq!(model.references_to(first).len(), 1);
+    assert_eq!(model.references_to(second).len(), 2);
+
+    // 스코프는 분석 후에도 남아 있고 블록 스코프의 부모는 함수 스코프
+    let table = &model.symbols;
+    let block_scope = model.symbol(first).scope;
+    let function_scope = table.scopes[block_scope.0].parent.unwrap();
+    assert_eq!(table.scopes[function_scope.0].children.len(), 2);
+    assert_eq!(table.current, ScopeId::GLOBAL);
+
+    // 블록 안 extern 선언은 전역 심볼과 같은 SymbolId
+    let global_n = table.resolve_global("n").unwrap();
+    assert_eq!(model.symbol_of(n_use), Some(global_n));
+    let Stmt::Return(Some(ret)) = statement(&program, 0, 3) else {
+        panic!("Expected return");
+    };
+    assert_eq!(model.symbol_of(ret), Some(global_n));
+    assert_eq!(model.references_to(global_n).len(), 3);
+}
+
+#[test]
+fn test_frame_layout() {
+    let input = r#"
+    int f(char c, int n) {
+        static int calls;
+        char buf[5];
+        double d = 0;
+        { int *p = 0; }
+        return n;
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let model = SemanticAnalyzer::new(&program).analyze().unwrap();
+    let frame = model.frame("f").unwrap();
+
+    // static 지역 변수는 프레임에 없음, 정렬에 맞춘 오프셋
+    let layout: Vec<_> = frame
+        .slots
+        .iter()
+        .map(|slot| (slot.name.as_str(), slot.offset))
+        .collect();
+    assert_eq!(
+        layout,
+        vec![("c", 0), ("n", 4), ("buf", 8), ("d", 16), ("p", 24)]
+    );
+    assert_eq!(frame.size, 32);
+    assert_eq!(
+        frame
+            .slot(program.functions[0].params[1].id())
+            .unwrap()
+            .name,
+        "n"
+    );
+}
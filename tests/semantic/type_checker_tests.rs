@@ -0,0 +1,766 @@
+use crate::utils::{analyze_conversions, analyze_program, analyze_warnings, parse_program};
+use rustc_tape4::SemanticAnalyzer;
+use rustc_tape4::ast::{Expr, Pos, SourceMap, Stmt, TypeSpecifier};
+use rustc_tape4::consteval::ConstError;
+use rustc_tape4::semantic::analyzer::{SemanticError, SemanticWarning};
+use rustc_tape4::semantic::type_checker::conversion::ConversionKind;
+
+#[test]
+fn test_sample_fixture_passes() {
+    let source = std::fs::read_to_string("tests/fixtures/sample.c").unwrap();
+    assert!(analyze_program(&source).is_ok());
+}
+
+#[test]
+fn test_float_arithmetic() {
+    // int <-> float 암시적 변환과 산술 연산 결과 타입
+    let input = r#"
+    double scale(double x, int n) {
+        float f = 2.0f;
+        double d = x * n + f;
+        int i = d;
+        d += 1;
+        if (d > 0) {
+            return -d / 2;
+        }
+        return i;
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+}
+
+#[test]
+fn test_float_remainder_rejected() {
+    let input = "int f() { double d = 1.5; int r = d % 2; return r; }";
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::TypeMismatch {
+            found: TypeSpecifier::Double,
+            ..
+        })
+    ));
+
+    let input = "int f() { float x = 1.0f; x &= 1; return 0; }";
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::TypeMismatch {
+            found: TypeSpecifier::Float,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_multi_dimensional_arrays() {
+    // 행 우선 인덱싱, 배열 -> 포인터 decay, 배열 포인터
+    let input = r#"
+    int sum(int rows[][4], int n) {
+        int total = 0;
+        for (int i = 0; i < n; i++) {
+            total += rows[i][0] + *rows[i];
+        }
+        return total;
+    }
+
+    int main() {
+        int m[3][4];
+        int (*p)[4] = m;
+        int *row = m[1];
+        int *a[2];
+        a[0] = row;
+        m[2][3] = *a[0] + p[1][2];
+        return sum(m, 3);
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+}
+
+#[test]
+fn test_array_row_type_mismatch() {
+    // int[3][4] 는 int* 로 decay 되지 않음 (int (*)[4])
+    let input = "int main() { int m[3][4]; int *p = m; return 0; }";
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_indirect_calls() {
+    let input = r#"
+    int add(int a, int b) { return a + b; }
+    int sub(int a, int b) { return a - b; }
+
+    int apply(int (*op)(int, int), int x) {
+        return op(x, 1) + (*op)(x, 2);
+    }
+
+    int main() {
+        int (*table[2])(int, int);
+        table[0] = add;
+        table[1] = &sub;
+        int (*fp)(int, int) = table[1];
+        return table[0](1, 2) + (*fp)(3, 4) + apply(add, 5);
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+}
+
+#[test]
+fn test_indirect_call_errors() {
+    // 함수 포인터 시그니처 불일치
+    let input = r#"
+    char id(char c) { return c; }
+    int main() { int (*fp)(int, int) = id; return 0; }
+    "#;
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::TypeMismatch { .. })
+    ));
+
+    // 호출할 수 없는 식
+    let input = "int main() { int a[2]; return a[0](1); }";
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::NotCallable {
+            found: TypeSpecifier::Int
+        })
+    ));
+
+    // 간접 호출 인자 갯수
+    let input = "int main() { int (*fp)(int); return fp(1, 2); }";
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::ArgumentCountMismatch {
+            expected: 1,
+            found: 2
+        })
+    ));
+}
+
+#[test]
+fn test_variadic_calls() {
+    let input = r#"
+    int printf(char *fmt, ...);
+    int main() {
+        char c = 'a';
+        float f = 1.5f;
+        int (*log)(char *, ...) = printf;
+        printf("plain\n");
+        printf("%d %c %f %s\n", 1, c, f, "str");
+        return log("%p\n", &c);
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+}
+
+#[test]
+fn test_variadic_call_errors() {
+    // 고정 매개변수보다 적은 인자
+    let input = "int printf(char *fmt, ...); int main() { return printf(); }";
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::ArgumentCountMismatch {
+            expected: 1,
+            found: 0
+        })
+    ));
+
+    // void 값은 가변 인자로 넘길 수 없음
+    let input = r#"
+    int printf(char *fmt, ...);
+    void nothing() {}
+    int main() { return printf("%d", nothing()); }
+    "#;
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::VoidValueUsed)
+    ));
+}
+
+#[test]
+fn test_const_writes_rejected() {
+    let cases = [
+        "int main() { const int x = 3; x = 4; return 0; }",
+        "int main() { const int x = 3; x++; return 0; }",
+        "int main() { int y = 0; int * const p = &y; p = 0; return 0; }",
+        "int main() { const int a[2] = {1, 2}; a[0] = 5; return 0; }",
+        "void f(const char *s) { *s = 'a'; }",
+        "void f(const int n) { --n; }",
+    ];
+    for input in cases {
+        assert!(
+            matches!(
+                analyze_program(input),
+                Err(SemanticError::AssignToConst { .. })
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_const_pointer_conversions() {
+    // 한정자 추가는 허용, const 포인터 자체가 가리키는 값은 수정 가능
+    let input = r#"
+    int length(const char *s);
+    int main() {
+        char buf[4] = "abc";
+        const char *view = buf;
+        int y = 0;
+        int * const p = &y;
+        *p = 1;
+        return length(buf) + length(view);
+    }
+    "#;
+    assert!(analyze_warnings(input).is_empty());
+
+    // 가리키는 타입의 const 를 떨어뜨리면 경고
+    let input = r#"
+    void take(char *s);
+    int main() {
+        const char *s = "abc";
+        char *t = s;
+        take(s);
+        return t[0];
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.analyze().unwrap();
+    assert_eq!(analyzer.warnings.len(), 2);
+    assert!(matches!(
+        analyzer.warnings[0],
+        SemanticWarning::DiscardedQualifiers { .. }
+    ));
+    // 경고는 변환되는 식을 가리키고 타입은 C 표기로
+    let map = SourceMap::new(&program);
+    let rendered: Vec<_> = analyzer
+        .warnings
+        .iter()
+        .map(|w| (w.pos(&map), w.message(&map)))
+        .collect();
+    let message = "conversion from 'const char *' to 'char *' discards qualifiers".to_string();
+    assert_eq!(
+        rendered,
+        vec![
+            (Some(Pos::new(5, 19)), message.clone()),
+            (Some(Pos::new(6, 14)), message)
+        ]
+    );
+}
+
+#[test]
+fn test_globals_and_linkage() {
+    // 같은 개체에 대한 일관된 재선언은 허용
+    let input = r#"
+    extern int count;
+    int count = 3;
+    static int hidden;
+    extern int hidden;
+    static int helper(void);
+    int helper(void) { return hidden; }
+    int main() {
+        extern int count;
+        static int calls = 0;
+        calls = calls + 1;
+        return count + helper();
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+
+    // 크기 없는 배열은 크기 있는 배열과 호환되고 합친 타입은 크기를 가짐
+    let input = r#"
+    extern int a[];
+    int first(void) { return a[0]; }
+    int a[10];
+    int m[2][3];
+    extern int m[][3];
+    int g(int p[]);
+    int g(int p[4]) { return p[0]; }
+    int main() {
+        extern int a[];
+        return first() + a[9] + m[1][2] + g(a);
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+    assert!(matches!(
+        analyze_program("extern int a[]; int a[10]; int main() { return a[10]; }"),
+        Err(SemanticError::ArrayIndexOutOfBounds { index: 10, .. })
+    ));
+
+    // 링크가 어긋나는 재선언
+    for input in [
+        "int x; static int x; int main() { return 0; }",
+        "static int x; int x; int main() { return 0; }",
+        "int f(void); static int f(void) { return 0; }",
+    ] {
+        assert!(
+            matches!(
+                analyze_program(input),
+                Err(SemanticError::LinkageConflict { .. })
+            ),
+            "{input}"
+        );
+    }
+
+    // 타입 불일치, 중복 정의, 함수와 변수 이름 충돌
+    for input in [
+        "int x; char x; int main() { return 0; }",
+        "int x = 1; int x = 2; int main() { return 0; }",
+        "int main; int main() { return 0; }",
+        "int x; int main() { extern char x; return 0; }",
+        "int a[10]; int a[5]; int main() { return 0; }",
+        "extern int a[]; char a[4]; int main() { return 0; }",
+        "int m[2][3]; extern int m[][4]; int main() { return 0; }",
+    ] {
+        assert!(
+            matches!(
+                analyze_program(input),
+                Err(SemanticError::DuplicateDeclaration { .. })
+            ),
+            "{input}"
+        );
+    }
+
+    // 블록 안 extern 선언은 초기화할 수 없음
+    assert!(matches!(
+        analyze_program("int main() { extern int x = 1; return x; }"),
+        Err(SemanticError::ExternInitializer { .. })
+    ));
+
+    // 전역 초기화식도 타입 검사
+    assert!(matches!(
+        analyze_program("int *p = 1.5; int main() { return 0; }"),
+        Err(SemanticError::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_external_declarations_in_source_order() {
+    // 앞에 정의된 함수로 전역 함수 포인터를 초기화
+    let input = r#"
+    int f(int x) { return x; }
+    int (*fp)(int) = f;
+    int main() { return fp(1); }
+    "#;
+    assert!(analyze_program(input).is_ok());
+
+    // 뒤에 선언되는 전역 변수와 함수는 쓸 수 없음
+    for input in [
+        "int f(void) { return g; } int g = 1; int main() { return f(); }",
+        "int main() { return f(); } int f(void) { return 0; }",
+    ] {
+        assert!(
+            matches!(
+                analyze_program(input),
+                Err(SemanticError::UndefinedSymbol { .. })
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_prototypes_and_definitions() {
+    // 프로토타입 뒤 정의, 이름 없는 매개변수 프로토타입
+    let input = r#"
+    int add(int, int);
+    int add(int a, int b) { return a + b; }
+    int main() { return add(1, 2); }
+    "#;
+    assert!(analyze_program(input).is_ok());
+
+    // 이미 정의된 함수를 다시 정의
+    let input = r#"
+    int f(void) { return 0; }
+    int f(void) { return 1; }
+    "#;
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::Redefinition { .. })
+    ));
+
+    // 정의 없는 static 함수 호출은 다른 단위에서 해결될 수 없음
+    let input = r#"
+    static int hidden(int);
+    int main() { return hidden(1); }
+    "#;
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::UndefinedFunction { .. })
+    ));
+}
+
+#[test]
+fn test_block_scope_function_declarations() {
+    // 블록 안 함수 선언은 변수가 아니라 전역 함수를 가리킴
+    let input = r#"
+    int twice(int v) { return v * 2; }
+    int main() {
+        int h(int), twice(int);
+        int x, k(char *), *p = &x;
+        x = 1;
+        return h(x) + k("a") + twice(*p);
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    let model = analyzer.analyze().unwrap();
+    assert!(analyzer.warnings.is_empty(), "{:?}", analyzer.warnings);
+    assert_eq!(model.externals, vec!["h".to_string(), "k".to_string()]);
+    // 지역 변수만 프레임에 놓임
+    let names: Vec<_> = model.frames["main"]
+        .slots
+        .iter()
+        .map(|slot| slot.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["x", "p"]);
+
+    // 파일 스코프에서 변수와 함께 쓴 프로토타입
+    let input = "int n, sq(int); int sq(int v) { return v * v; } int main() { return sq(n); }";
+    assert!(analyze_program(input).is_ok());
+
+    for (input, expected) in [
+        // 전역 선언과 맞지 않는 시그니처
+        (
+            "int twice(int v) { return v; } int main() { char *twice(int); return 0; }",
+            "duplicate",
+        ),
+        ("int main() { int h(int) = 0; return 0; }", "initializer"),
+        (
+            "int n, h(void), h(int); int main() { return 0; }",
+            "duplicate",
+        ),
+        ("int main() { static int h(int); return 0; }", "static"),
+    ] {
+        let result = analyze_program(input);
+        let ok = match expected {
+            "duplicate" => matches!(result, Err(SemanticError::DuplicateDeclaration { .. })),
+            "initializer" => matches!(result, Err(SemanticError::FunctionInitializer { .. })),
+            _ => matches!(result, Err(SemanticError::StaticLocalFunction { .. })),
+        };
+        assert!(ok, "{input}: {result:?}");
+    }
+}
+
+#[test]
+fn test_undefined_functions_are_external() {
+    let input = r#"
+    int putchar(int c);
+    int unused(void);
+    int local(void) { return 0; }
+    int main() { int (*fp)(void) = local; putchar(65); return fp(); }
+    "#;
+    let program = parse_program(input).unwrap();
+    let model = SemanticAnalyzer::new(&program).analyze().unwrap();
+    assert_eq!(model.externals, vec!["putchar".to_string()]);
+}
+
+#[test]
+fn test_lvalue_required() {
+    // 대입, &, ++/-- 대상이 lvalue 가 아님
+    for input in [
+        "int main() { int a = 1; int b = 2; int *p = &(a + b); return 0; }",
+        "int main() { int x = 0; (x + 1)++; return 0; }",
+        "int f(void) { return 1; } int main() { f()++; return 0; }",
+        "int main() { int x = 0; ++(x = 1); return 0; }",
+        "int main() { int *p = &3; return 0; }",
+        "int f(void) { return 1; } int main() { f = 0; return 0; }",
+    ] {
+        assert!(
+            matches!(
+                analyze_program(input),
+                Err(SemanticError::NotAnLvalue { .. })
+            ),
+            "{input}"
+        );
+    }
+
+    // 대입 대상은 파서가 아니라 타입 검사가 거름
+    assert!(matches!(
+        analyze_program("int main() { int x = 0; 3 = x; return 0; }"),
+        Err(SemanticError::NotAnLvalue {
+            found: TypeSpecifier::Int
+        })
+    ));
+    for input in [
+        "int f(void) { return 1; } int main() { f() += 2; return 0; }",
+        "int main() { int x = 0; int y = 0; x + y = 1; return 0; }",
+        "int main() { int x = 0; (x = 1) = 2; return 0; }",
+        "int main() { int x = 0; x++ -= 1; return 0; }",
+    ] {
+        assert!(
+            matches!(
+                analyze_program(input),
+                Err(SemanticError::NotAnLvalue { .. })
+            ),
+            "{input}"
+        );
+    }
+
+    // 배열은 lvalue 지만 대입할 수 없음
+    let input = "int main() { int a[2]; int b[2]; a = b; return 0; }";
+    assert!(matches!(
+        analyze_program(input),
+        Err(SemanticError::AssignToArray { .. })
+    ));
+
+    // 올바른 lvalue 사용
+    let input = r#"
+    int f(void) { return 1; }
+    int main() {
+        int x = 0;
+        int a[3];
+        int m[2][2];
+        int *p = &x;
+        int (*pa)[3] = &a;
+        int (*fp)(void) = &f;
+        *p = 1;
+        a[0]++;
+        ++m[1][0];
+        *(p) += 2;
+        x = a[0] = 3;
+        return x;
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+}
+
+#[test]
+fn test_implicit_conversions() {
+    use ConversionKind::*;
+
+    // 대입 변환: 좁아지는 정수 변환, 정수 -> 실수, 실수 -> 정수
+    assert_eq!(
+        analyze_conversions("int main() { char c = 65; double d = 1; int i = 2.5; return 0; }"),
+        vec![IntegerTruncation, IntToFloating, FloatingToInt]
+    );
+
+    // 산술 연산은 정수 승격 후 일반 산술 변환
+    assert_eq!(
+        analyze_conversions("int main() { char c = 'a'; float f = 1.0f; c + 1; f * 2; return 0; }"),
+        vec![IntegerPromotion, IntToFloating]
+    );
+
+    // return 과 복합 대입: 연산 결과를 대상 타입으로 되돌림
+    assert_eq!(
+        analyze_conversions("char up(char c) { c -= 32; return c - 1; }"),
+        vec![
+            IntegerPromotion,
+            IntegerTruncation,
+            IntegerPromotion,
+            IntegerTruncation
+        ]
+    );
+
+    // 가변 인자의 기본 인자 승격
+    assert_eq!(
+        analyze_conversions(
+            "int printf(char *fmt, ...); int main() { float f = 1.0f; printf(\"%f %c\", f, 'a'); return 0; }"
+        ),
+        vec![FloatingExtension, IntegerPromotion]
+    );
+
+    // 널 포인터 상수와 void* 변환
+    assert_eq!(
+        analyze_conversions(
+            "int main() { int x = 0; int *p = 0; void *v = &x; p = v; if (p == 0) { return 1; } return p != v; }"
+        ),
+        vec![NullPointer, PointerConversion, PointerConversion]
+    );
+
+    // 값이 0 인 정수 상수식도 널 포인터 상수 (변수, 실수는 아님)
+    assert_eq!(
+        analyze_conversions(
+            "int main() { int *q = 1 - 1; char *s = '\\0'; q = !1; return q == 2 * 0 && s != 0; }"
+        ),
+        vec![NullPointer, NullPointer, NullPointer]
+    );
+}
+
+#[test]
+fn test_invalid_conversions() {
+    for input in [
+        // 0 이 아닌 정수는 포인터로 암시적 변환 불가
+        "int main() { int *p = 1; return 0; }",
+        "int main() { int z = 0; int *p = z; return 0; }",
+        "int main() { int *p = 0.0; return 0; }",
+        // 포인터 -> 정수
+        "int main() { int x = 0; int y = &x; return y; }",
+        // 가리키는 타입이 다른 포인터
+        "int main() { char c = 0; int *p = &c; return 0; }",
+        // 서로 다른 포인터 비교, 포인터와 0 이 아닌 정수 비교
+        "int main() { char c = 0; int x = 0; return &c == &x; }",
+        "int main() { int x = 0; return &x == 1; }",
+        // 함수 포인터와 void*
+        "int f(void) { return 0; } int main() { void *v = f; return 0; }",
+    ] {
+        assert!(
+            matches!(
+                analyze_program(input),
+                Err(SemanticError::TypeMismatch { .. })
+            ),
+            "{input}"
+        );
+    }
+
+    // 조건식은 스칼라 타입이면 됨
+    let input = r#"
+    int main() {
+        char c = 'a';
+        int *p = 0;
+        while (c) { c = 0; }
+        if (p) { return 1; }
+        for (; !p && c;) {}
+        return 0;
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+}
+
+#[test]
+fn test_pointer_arithmetic() {
+    let input = r#"
+    int sum(int *begin, int *end) {
+        int total = 0;
+        for (int *p = begin; p < end; p++) {
+            total += *p;
+        }
+        return total;
+    }
+    int main() {
+        int arr[4] = {1, 2, 3, 4};
+        int *first = arr;
+        int *last = arr + 3;
+        int n = last - first;
+        int x = *(arr + 1) + *(2 + arr);
+        first += 1;
+        --last;
+        if (first != 0 && last >= first) {
+            return sum(arr, arr + n);
+        }
+        return x;
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+
+    // 원소 크기만큼 곱하는 위치와 포인터 차의 나눗셈 기록
+    use ConversionKind::*;
+    assert_eq!(
+        analyze_conversions(
+            "int main() { double d[2]; char c = 'a'; double *p = d + c; return p - d; }"
+        ),
+        vec![
+            IntegerPromotion,
+            ScaledIndex { element_size: 8 },
+            PointerDifference { element_size: 8 }
+        ]
+    );
+    assert_eq!(
+        analyze_conversions("int main() { int *p = 0; p++; return p[1]; }"),
+        vec![
+            NullPointer,
+            ScaledIndex { element_size: 4 },
+            ScaledIndex { element_size: 4 }
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_pointer_arithmetic() {
+    for input in [
+        // 포인터 + 포인터, 정수 - 포인터, 포인터 * 정수
+        "int main() { int a[2]; int *p = a; p + p; return 0; }",
+        "int main() { int a[2]; int *p = a; 1 - p; return 0; }",
+        "int main() { int a[2]; int *p = a; p * 2; return 0; }",
+        // 가리키는 타입이 다른 포인터 차, 실수 오프셋
+        "int main() { int x = 0; char c = 0; &x - &c; return 0; }",
+        "int main() { int a[2]; a + 1.5; return 0; }",
+        // 다른 타입을 가리키는 포인터의 관계 비교, 포인터와 0 의 관계 비교
+        "int main() { int x = 0; char c = 0; return &x < &c; }",
+        "int main() { int x = 0; return &x > 0; }",
+    ] {
+        assert!(
+            matches!(
+                analyze_program(input),
+                Err(SemanticError::TypeMismatch { .. })
+            ),
+            "{input}"
+        );
+    }
+
+    // 크기가 없는 대상을 가리키는 포인터
+    for input in [
+        "int main() { int x = 0; void *v = &x; v + 1; return 0; }",
+        "int main() { int x = 0; void *v = &x; v++; return 0; }",
+        "int f(void) { return 0; } int main() { int (*fp)(void) = f; fp - fp; return 0; }",
+    ] {
+        assert!(
+            matches!(
+                analyze_program(input),
+                Err(SemanticError::InvalidPointerArithmetic { .. })
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_constant_initializers() {
+    let input = r#"
+    int g = 2 * 21;
+    char c = 'a' + 1;
+    int table[3] = {1, 1 + 1, 3 * 1};
+    double d = 1.5 * 2;
+    char *s = "abc";
+    int *p = &g;
+    int wrapped = 2147483647 + 1;
+    int main() {
+        static int calls = -1;
+        return g + c + table[0] + *p + calls + s[0] + wrapped;
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    let model = analyzer.analyze().unwrap();
+    let init = |index: usize| match &program.globals[index] {
+        Stmt::Declaration { declarators, .. } => declarators[0].init.as_ref().unwrap(),
+        _ => panic!("Expected declaration"),
+    };
+    assert_eq!(model.constant(init(0)), Some(42));
+    assert_eq!(model.constant(init(1)), Some(98));
+    let Expr::InitializerList(items) = init(2) else {
+        panic!("Expected initializer list");
+    };
+    assert_eq!(model.constant(&items[2]), Some(3));
+    assert_eq!(model.constant(init(6)), Some(i32::MIN));
+    assert!(matches!(
+        analyzer.warnings.as_slice(),
+        [SemanticWarning::ConstantOverflow {
+            wrapped: i32::MIN,
+            ..
+        }]
+    ));
+
+    // 상수식이 아닌 정적 초기화식
+    for (input, expected) in [
+        (
+            "int g = 1; int h = g + 1; int main() { return h; }",
+            ConstError::NotConstant,
+        ),
+        (
+            "int z = 1 / 0; int main() { return z; }",
+            ConstError::DivisionByZero,
+        ),
+        (
+            "int f(int n) { static int m = n; return m; } int main() { return f(1); }",
+            ConstError::NotConstant,
+        ),
+    ] {
+        match analyze_program(input) {
+            Err(SemanticError::ConstantExpression { error, .. }) => assert_eq!(error, expected),
+            other => panic!("Unexpected result for {input}: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use crate::utils::{analyze_warnings, parse_program};
+use rustc_tape4::SemanticAnalyzer;
+use rustc_tape4::ast::expr::BinaryOp;
+use rustc_tape4::ast::{Expr, Stmt};
+use rustc_tape4::consteval::eval_int;
+use rustc_tape4::semantic::analyzer::SemanticWarning;
+
+/// 값 범위 분석 경고만 (경고 순서대로)
+fn range_warnings(input: &str) -> Vec<SemanticWarning> {
+    analyze_warnings(input)
+        .into_iter()
+        .filter(|w| {
+            matches!(
+                w,
+                SemanticWarning::MaybeDivisionByZero { .. }
+                    | SemanticWarning::SignedOverflow { .. }
+                    | SemanticWarning::IndexOutOfRange { .. }
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_division_by_zero() {
+    let input = r#"
+    int main() {
+        int i;
+        int s = 0;
+        for (i = 0; i < 10; i++) {
+            s += 100 / (i - 5);
+        }
+        return s;
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.analyze().unwrap();
+
+    // 경고는 나눗셈 식을 가리킴
+    let body = &program.functions[0].body.as_ref().unwrap().statements;
+    let Stmt::For { body, .. } = &body[2] else {
+        panic!("Expected for");
+    };
+    let Stmt::Block(block) = body.as_ref() else {
+        panic!("Expected block");
+    };
+    let Stmt::ExprStmt(Some(Expr::Assignment { right, .. })) = &block.statements[0] else {
+        panic!("Expected assignment");
+    };
+    assert_eq!(
+        analyzer.warnings,
+        vec![SemanticWarning::MaybeDivisionByZero { expr: right.id() }]
+    );
+
+    // 나머지, 복합 대입, 0 이 빠지도록 좁혀진 분기
+    let input = r#"
+    int main() {
+        int i;
+        int x = 7;
+        for (i = 0; i < 4; i++) {
+            x = x % i;
+            x /= i;
+            if (i != 0) x = 10 / i;
+            if (i > 0) x = 10 % i;
+        }
+        return x;
+    }
+    "#;
+    assert_eq!(range_warnings(input).len(), 2);
+
+    // 범위를 모르는 나누는 수는 경고하지 않음
+    let input = r#"
+    int f(int a, int b) { return a / b + a % b; }
+    int main() { return f(1, 2); }
+    "#;
+    assert!(range_warnings(input).is_empty());
+}
+
+#[test]
+fn test_signed_overflow() {
+    let input = r#"
+    int main() {
+        int x = 100000;
+        int y = 2000000000;
+        int z = x * x;
+        int w = y + y;
+        int v = y - y;
+        return z + w + v;
+    }
+    "#;
+    let warnings = range_warnings(input);
+    assert_eq!(warnings.len(), 2);
+    let SemanticWarning::SignedOverflow { op, min, max, .. } = &warnings[0] else {
+        panic!("Expected overflow");
+    };
+    assert_eq!(
+        (op, *min, *max),
+        (&BinaryOp::Mul, Some(10_000_000_000), Some(10_000_000_000))
+    );
+    let SemanticWarning::SignedOverflow { op, .. } = &warnings[1] else {
+        panic!("Expected overflow");
+    };
+    assert_eq!(op, &BinaryOp::Add);
+
+    // i64 로도 넘치는 리터럴 피연산자: 분석기가 멈추지 않고 오버플로로 경고
+    let input = r#"
+    int main() {
+        int x = 3;
+        int y = x * 4000000000000000000;
+        int z = 4000000000000000000 * 4000000000000000000;
+        int w = -4000000000000000000 / -1 % -4000000000000000000;
+        return y + z + w;
+    }
+    "#;
+    let ops: Vec<_> = range_warnings(input)
+        .into_iter()
+        .map(|w| match w {
+            SemanticWarning::SignedOverflow { op, .. } => op,
+            other => panic!("Unexpected warning {other:?}"),
+        })
+        .collect();
+    assert_eq!(ops, vec![BinaryOp::Mul, BinaryOp::Mul]);
+
+    // 조건으로 좁혀진 범위 안에서는 넘치지 않음
+    let input = r#"
+    int f(int n) {
+        if (n >= 0 && n < 1000) return n * n + n;
+        return 0;
+    }
+    int main() { return f(3); }
+    "#;
+    assert!(range_warnings(input).is_empty());
+
+    // 위젠닝된 누적 변수는 한계를 모르므로 경고하지 않음
+    let input = r#"
+    int main() {
+        int s = 0;
+        int i;
+        for (i = 0; i < 100; i++) s = s + i * 2;
+        return s;
+    }
+    "#;
+    assert!(range_warnings(input).is_empty());
+
+    // int 범위를 넘는 리터럴과의 비교로 좁혀도 분석기가 멈추지 않음
+    let input = r#"
+    int f(int x) {
+        if (x > 9223372036854775807) return 1;
+        if (x < -9223372036854775807 - 1) return 2;
+        if (x != 9223372036854775807) return x;
+        return 0;
+    }
+    int main() { return f(1); }
+    "#;
+    assert!(range_warnings(input).is_empty());
+}
+
+#[test]
+fn test_index_out_of_range() {
+    // 반복문에서 좁혀진 범위 [0, 9] 와 분기로 합쳐진 범위
+    let input = r#"
+    int main() {
+        int a[8];
+        int i;
+        int j = 0;
+        int s = 0;
+        for (i = 0; i < 10; i += 2) {
+            a[i] = i;
+        }
+        if (a[0]) j = 5;
+        else j = 8;
+        s = a[j];
+        for (i = 0; i < 8; i++) s += a[i];
+        return s;
+    }
+    "#;
+    let warnings = range_warnings(input);
+    let ranges: Vec<_> = warnings
+        .iter()
+        .map(|w| match w {
+            SemanticWarning::IndexOutOfRange {
+                length, min, max, ..
+            } => (*length, *min, *max),
+            other => panic!("Unexpected warning {other:?}"),
+        })
+        .collect();
+    assert_eq!(ranges, vec![(8, Some(0), Some(9)), (8, Some(5), Some(8))]);
+
+    // while 루프와 감소, &a[n] 은 끝 다음 위치까지 허용
+    let input = r#"
+    int main() {
+        int a[4];
+        int *p;
+        int n = 4;
+        while (n > 0) {
+            p = &a[n];
+            n--;
+            a[n] = 0;
+        }
+        while (n >= -1) {
+            n--;
+            if (n < 0) break;
+        }
+        return a[n];
+    }
+    "#;
+    let warnings = range_warnings(input);
+    assert_eq!(warnings.len(), 1);
+    assert!(matches!(
+        warnings[0],
+        SemanticWarning::IndexOutOfRange {
+            min: Some(-1),
+            max: Some(-1),
+            ..
+        }
+    ));
+
+    // 주소가 취해진 변수, 매개변수 인덱스는 추적하지 않음
+    let input = r#"
+    void set(int *p) { *p = 100; }
+    int f(int k) {
+        int a[4];
+        int i = 0;
+        set(&i);
+        a[i] = 1;
+        return a[k];
+    }
+    int main() { return f(0); }
+    "#;
+    assert!(range_warnings(input).is_empty());
+
+    // 0x80 이상의 문자 상수는 상수 평가와 같이 음수 (char 는 부호 있음)
+    let input = r#"
+    int main() {
+        int a[300];
+        int i = 'é';
+        int j = 'é' + 24;
+        a[j] = 0;
+        return a[i];
+    }
+    "#;
+    let warnings = range_warnings(input);
+    assert_eq!(eval_int(&Expr::CharLiteral('é')), Ok(-23));
+    assert!(matches!(
+        warnings.as_slice(),
+        [SemanticWarning::IndexOutOfRange {
+            min: Some(-23),
+            max: Some(-23),
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn test_not_duplicated_and_lints() {
+    // for 루프 범위 검사가 이미 경고한 인덱싱은 한 번만
+    let input = r#"
+    int main() {
+        int a[10];
+        int i;
+        for (i = 0; i <= 10; i++) a[i] = 0;
+        return 0;
+    }
+    "#;
+    let warnings = analyze_warnings(input);
+    assert_eq!(warnings.len(), 1);
+    assert!(matches!(warnings[0], SemanticWarning::ArrayBounds { .. }));
+
+    // -Wno-div-by-zero 로 끄고 -Werror 로 에러
+    let input = r#"
+    int main() {
+        int z = 0;
+        return 1 / z;
+    }
+    "#;
+    let program = parse_program(input).unwrap();
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.lints.apply_flag("-Wno-div-by-zero").unwrap();
+    analyzer.analyze().unwrap();
+    assert!(analyzer.warnings.is_empty());
+
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.lints.apply_flag("-Werror").unwrap();
+    assert!(analyzer.analyze().is_err());
+}
@@ -0,0 +1,112 @@
+use crate::utils::analyze_program;
+use rustc_tape4::semantic::analyzer::SemanticError;
+
+#[test]
+fn test_void_objects_rejected() {
+    for input in [
+        "void x; int main() { return 0; }",
+        "int main() { void x; return 0; }",
+        "int main() { const void x; return 0; }",
+        "int main() { void a[3]; return 0; }",
+        "int main() { extern void x; return 0; }",
+    ] {
+        assert!(
+            matches!(analyze_program(input), Err(SemanticError::VoidObject { name }) if name == "x" || name == "a"),
+            "{input}"
+        );
+    }
+    assert!(matches!(
+        analyze_program("int f(int a, void); int main() { return 0; }"),
+        Err(SemanticError::VoidParameter { function }) if function == "f"
+    ));
+}
+
+#[test]
+fn test_invalid_derived_types_rejected() {
+    // 함수 배열, 배열/함수를 반환하는 함수 (선언자, 매개변수, 정의)
+    for (input, expected) in [
+        ("int a[3](int); int main() { return 0; }", "a"),
+        ("int main() { int a[3](int); return 0; }", "a"),
+        ("int n, f(void)[3]; int main() { return 0; }", "f"),
+        ("int main() { int g(int)(char); return 0; }", "g"),
+        ("int main() { int (*p)(int)[3]; return 0; }", "p"),
+        ("int h(int a[2](void)); int main() { return 0; }", "h"),
+    ] {
+        assert!(
+            matches!(analyze_program(input), Err(SemanticError::InvalidDerivedType { name, .. }) if name == expected),
+            "{input}"
+        );
+    }
+
+    // 포인터를 거치면 허용
+    let input = r#"
+    int one(int x) { return x; }
+    int (*table[2])(int);
+    int main() {
+        int (*fp)(int) = one;
+        int (*row)[3];
+        table[0] = fp;
+        return table[0](0);
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+}
+
+#[test]
+fn test_void_values() {
+    // 버려지는 자리의 void 호출은 허용
+    let input = r#"
+    void touch(int *p) { *p = 1; }
+    int main() {
+        int n;
+        touch(&n);
+        for (n = 0; n < 3; touch(&n)) {}
+        return n;
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+
+    for input in [
+        "void f() {} int main() { return 1 + f(); }",
+        "void f() {} int main() { int x = f(); return x; }",
+        "void f() {} int main() { if (f()) return 1; return 0; }",
+        "void f() {} int g(int x) { return x; } int main() { return g(f()); }",
+        "void f() {} int main() { int x; x = f(); return x; }",
+    ] {
+        assert!(
+            matches!(analyze_program(input), Err(SemanticError::VoidValueUsed)),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn test_void_pointers() {
+    // void* 는 객체 포인터와 암시적으로 오가고, 가리키는 void** 는 역참조 가능
+    let input = r#"
+    int main() {
+        int n = 1;
+        void *p = &n;
+        int *q = p;
+        char *s = p;
+        void **pp = &p;
+        p = s;
+        return *q + (*pp == p);
+    }
+    "#;
+    assert!(analyze_program(input).is_ok());
+
+    for input in [
+        "int main() { int n; void *p = &n; *p; return 0; }",
+        "int main() { int n; void *p = &n; return p[0]; }",
+        "int main() { int n; const void *p = &n; return *p; }",
+    ] {
+        assert!(
+            matches!(
+                analyze_program(input),
+                Err(SemanticError::VoidPointerDereference)
+            ),
+            "{input}"
+        );
+    }
+}
@@ -1,17 +1,19 @@
-use rustc_tape4::ast::{Expr, Program, Stmt};
+use rustc_tape4::SemanticAnalyzer;
+use rustc_tape4::ast::{Expr, Program, SourceMap, Stmt};
 use rustc_tape4::lexer::{Lexer, Token};
+use rustc_tape4::linker::{LinkResult, Linker};
 use rustc_tape4::parser::{ParseResult, Parser};
+use rustc_tape4::semantic::analyzer::{AnalyzeResult, SemanticWarning};
+use rustc_tape4::semantic::symbol::symbol::SymbolTable;
+use rustc_tape4::semantic::type_checker::conversion::ConversionKind;
 
 /// 입력 전체를 순환하며 토큰을 수집
 pub fn collect_tokens(input: &str) -> Vec<Token> {
     let mut l = Lexer::new(input);
-    let tokens = l
-        .collect_spanned_tokens()
+    l.collect_spanned_tokens()
         .iter()
         .map(|tok| tok.kind.clone())
-        .collect();
-
-    tokens
+        .collect()
 }
 
 pub fn parse_program(input: &str) -> ParseResult<Program> {
@@ -20,8 +22,12 @@ pub fn parse_program(input: &str) -> ParseResult<Program> {
     let mut parser = Parser::new(tokens);
     let result = parser.parse_program();
 
-    if result.is_err() {
-        println!("파싱 오류: {:?}", result.as_ref().err());
+    match &result {
+        // 위치 지도를 만들어 보며 파서의 위치 기록과 노드 순회가 어긋나지 않았는지 확인
+        Ok(program) => {
+            SourceMap::new(program);
+        }
+        Err(err) => println!("파싱 오류: {err:?}"),
     }
 
     result
@@ -61,3 +67,39 @@ pub fn parse_statement(input: &str) -> Stmt {
         }
     }
 }
+
+/// 프로그램 문자열을 파싱한 뒤 의미 분석 성공 여부 반환
+/// (의미 모델은 분석한 Program 을 빌리므로 함께 돌려줄 수 없음)
+pub fn analyze_program(input: &str) -> AnalyzeResult<()> {
+    let program = parse_program(input).expect("파싱 실패");
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.analyze().map(|_| ())
+}
+
+/// 의미 분석이 성공해야 하는 프로그램의 경고 목록 반환
+pub fn analyze_warnings(input: &str) -> Vec<SemanticWarning> {
+    let program = parse_program(input).expect("파싱 실패");
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    analyzer.analyze().expect("의미 분석 실패");
+    analyzer.warnings
+}
+
+/// 번역 단위 (이름, 소스) 목록을 각각 분석한 뒤 링크 결과 반환
+pub fn link_units(units: &[(&str, &str)]) -> LinkResult<SymbolTable> {
+    let mut linker = Linker::new();
+    for (name, source) in units {
+        let program = parse_program(source).expect("파싱 실패");
+        let mut analyzer = SemanticAnalyzer::new(&program);
+        let model = analyzer.analyze().expect("의미 분석 실패");
+        linker.add_unit(name, &model);
+    }
+    linker.link()
+}
+
+/// 의미 분석이 성공해야 하는 프로그램에 삽입된 암시적 변환 종류 목록 반환
+pub fn analyze_conversions(input: &str) -> Vec<ConversionKind> {
+    let program = parse_program(input).expect("파싱 실패");
+    let mut analyzer = SemanticAnalyzer::new(&program);
+    let model = analyzer.analyze().expect("의미 분석 실패");
+    model.conversions.iter().map(|c| c.kind).collect()
+}